@@ -40,6 +40,7 @@ __all__ = [
               'PhenotypeOscillation',
               'BoolClauseType',
               'BoolExpressionType',
+              'ExpressionSyntax',
               'Regulation',
               'IdRegulation',
               'NamedRegulation',
@@ -48,6 +49,13 @@ __all__ = [
               'SccConfig',
               'AttractorConfig',
               'BbmFilterConfig',
+              'BddStatistics',
+              'HctlExplanation',
+              'FairnessConfig',
+              'ModelCheckingStatistics',
+              'HctlDiagnostic',
+              'FunctionTableEntry',
+              'SynthesisResult',
           ] + [x for x in biodivine_aeon.__all__ if not x.startswith("_")]
 
 LOG_NOTHING: Literal[0] = 0
@@ -157,6 +165,16 @@ A `BooleanExpression` can be typically also substituted with its "raw" string re
 requires the expression to be repeatedly parsed whenever used and is thus slower and more error prone.
 """
 
+ExpressionSyntax = Literal["aeon", "python", "c", "sympy", "bnet"]
+"""
+The syntaxes supported by `BooleanExpression.parse` and `BooleanExpression.to_string`:
+ - `aeon`: The native syntax used by `BooleanExpression.__str__` (`!`, `&`, `|`, `^`, `=>`, `<=>`, `? :`).
+ - `python`: Python-style operators (`not`, `and`, `or`, `!=`, `==`).
+ - `c`: C-style operators (`!`, `&&`, `||`, `^`, `!=`, `==`, `? :`).
+ - `sympy`: SymPy-like operators (`~`, `&`, `|`, `^`, `Implies`, `Equivalent`, `ITE`).
+ - `bnet`: The syntax of `.bnet` files (`!`, `&`, `|`).
+"""
+
 VertexSetType = Union[VertexSet, ColoredVertexSet, VertexModel, SpaceModel, Mapping[VariableIdType, BoolType]]
 """
 A union of types that can be implicitly converted to a `VertexSet`.
@@ -285,6 +303,81 @@ class BbmFilterConfig(TypedDict, total=False):
     max_regulations: int
     keywords: Sequence[str]
 
+class BddStatistics(TypedDict):
+    """
+    A summary of the structural properties of a `Bdd` (see `Bdd.statistics`).
+    """
+    variable_count: int
+    support_size: int
+    node_count: int
+    decision_node_count: int
+    max_width: int
+    depth: int
+    path_count: int
+    cardinality: int
+
+class HctlExplanation(TypedDict):
+    """
+    A witness or counterexample trajectory of a HCTL property (see `ModelChecking.explain`).
+
+    The `path` contains the visited states together with the sub-formula that each state
+    satisfies. If `loop_start` is set, the trajectory continues from the last state back to
+    the state at this index.
+    """
+    holds: bool
+    kind: Literal["witness", "counterexample"]
+    path: list[tuple[VertexModel, HctlFormula]]
+    loop_start: Optional[int]
+
+class FairnessConfig(TypedDict, total=False):
+    """
+    Fairness assumptions used by `ModelChecking.verify`. Path quantifiers of temporal operators
+    then only consider paths that satisfy all the given assumptions.
+    """
+
+    # Variables that are eventually updated if they can be updated continuously.
+    weak: Sequence[VariableIdType]
+    # Variables that are updated infinitely often if they can be updated infinitely often.
+    strong: Sequence[VariableIdType]
+    # Sets of states that must be visited infinitely often.
+    infinitely_often: Sequence[ColoredVertexSet]
+
+class HctlDiagnostic(TypedDict):
+    """
+    A single problem found by `HctlFormula.validate`. The `formula` is the sub-formula where
+    the problem was found.
+    """
+    kind: Literal["unknown_variable", "unbound_state_variable", "shadowed_state_variable",
+                  "unresolved_proposition", "missing_extra_variables"]
+    severity: Literal["error", "warning"]
+    message: str
+    formula: HctlFormula
+
+class ModelCheckingStatistics(TypedDict):
+    """
+    Cache statistics of a `ModelCheckingSession`.
+    """
+    cached_formulas: int
+    hits: int
+    misses: int
+
+class FunctionTableEntry(TypedDict):
+    """
+    One entry of an uninterpreted function table in a `SynthesisResult`. The `status` is `fixed` if the entry
+    is `1` in all synthesized colors, `forbidden` if it is always `0`, and `free` if both values are possible.
+    """
+    inputs: list[bool]
+    status: Literal["fixed", "forbidden", "free"]
+
+class SynthesisResult(TypedDict):
+    """
+    The result of `ModelChecking.synthesize`: the `colors` that satisfy all properties, the status of every
+    function table entry (indexed by function name), and a human-readable `summary`.
+    """
+    colors: ColorSet
+    functions: dict[str, list[FunctionTableEntry]]
+    summary: str
+
 
 def _show_dot_graph(self, *args, **kwargs):
     """
@@ -1,3 +1,4 @@
+from fractions import Fraction
 from typing import (Callable, Iterator, Literal, Mapping, Optional, Sequence,
                     TypedDict, Union, overload, Any, Tuple)
 
@@ -224,6 +225,10 @@ class Bdd:
         ...
     def to_dot(self, zero_pruned: bool = True) -> str:
         ...
+    def to_graphml(self, zero_pruned: bool = True) -> str:
+        ...
+    def to_svg(self, zero_pruned: bool = True) -> str:
+        ...
     def show(self, zero_pruned: bool = True):
         ...
     def to_expression(self) -> BooleanExpression:
@@ -232,10 +237,18 @@ class Bdd:
         ...
     def to_cnf(self) -> list[BddPartialValuation]:
         ...
+    def prime_implicants(self, size_limit: Optional[int] = None) -> list[BddPartialValuation]:
+        ...
     def node_count(self) -> int:
         ...
     def node_count_per_variable(self) -> dict[BddVariable, int]:
         ...
+    def width_profile(self) -> list[int]:
+        ...
+    def node_path_counts(self) -> list[int]:
+        ...
+    def statistics(self) -> BddStatistics:
+        ...
     def structural_eq(self, other: Bdd) -> bool:
         ...
     def semantic_eq(self, other: Bdd) -> bool:
@@ -266,6 +279,10 @@ class Bdd:
         ...
     def cardinality(self, exact: bool = True) -> int:
         ...
+    @overload
+    def weighted_count(self, weights: Mapping[BddVariableType, Union[float, Fraction]], exact: Literal[False] = False) -> float: ...
+    @overload
+    def weighted_count(self, weights: Mapping[BddVariableType, Union[float, Fraction]], exact: Literal[True]) -> Fraction: ...
     def clause_cardinality(self) -> int:
         ...
     def l_not(self) -> Bdd:
@@ -371,6 +388,8 @@ class Bdd:
         ...
     def valuation_most_negative(self) -> BddValuation:
         ...
+    def valuation_max_weight(self, weights: Mapping[BddVariableType, float]) -> BddValuation:
+        ...
     def mk_uniform_valuation_sampler(self, seed: Optional[int] = None) -> UniformValuationSampler:
         ...
     def mk_naive_valuation_sampler(self, seed: Optional[int] = None) -> NaiveSampler:
@@ -406,6 +425,26 @@ class Bdd:
     def overapproximate_to_cardinality(self, target: int) -> Bdd: ...
     def underapproximate_to_cardinality(self, target: int) -> Bdd: ...
 
+class BddStore:
+    def __init__(self, ctx: BddVariableSet, bdds: Optional[Mapping[str, Bdd]] = None) -> None: ...
+    def __len__(self) -> int: ...
+    def __contains__(self, name: str) -> bool: ...
+    def __getitem__(self, name: str) -> Bdd: ...
+    def __setitem__(self, name: str, bdd: Bdd) -> None: ...
+    def __delitem__(self, name: str) -> None: ...
+    def __str__(self) -> str: ...
+    def __getnewargs__(self) -> tuple[BddVariableSet]: ...
+    def __getstate__(self) -> bytes: ...
+    def __setstate__(self, state: bytes) -> None: ...
+    def __ctx__(self) -> BddVariableSet: ...
+    def names(self) -> list[str]: ...
+    def node_count(self) -> int: ...
+    def unshared_node_count(self) -> int: ...
+    def to_dict(self) -> dict[str, Bdd]: ...
+    def to_bytes(self) -> bytes: ...
+    @staticmethod
+    def from_bytes(ctx: BddVariableSet, data: bytes) -> BddStore: ...
+
 class BddPartialValuation:
     @overload
     def __init__(self, valuation: Union[BddValuation, BddPartialValuation]): ...
@@ -519,6 +558,9 @@ class BooleanExpression:
     def __call__(self, **kwargs: BoolType) -> bool: ...
     def __call__(self) -> bool: ...
     @staticmethod
+    def parse(value: str, syntax: ExpressionSyntax = "aeon") -> BooleanExpression: ...
+    def to_string(self, syntax: ExpressionSyntax = "aeon", minimal_parentheses: bool = False, line_width: Optional[int] = None) -> str: ...
+    @staticmethod
     def mk_const(value: BoolType) -> BooleanExpression: ...
     @staticmethod
     def mk_var(name: str) -> BooleanExpression: ...
@@ -563,6 +605,11 @@ class BooleanExpression:
     def as_literal(self) -> Optional[tuple[str, bool]]: ...
     def as_binary(self) -> Optional[tuple[BinaryOperator, BooleanExpression, BooleanExpression]]: ...
     def support_set(self) -> set[str]: ...
+    def to_bdd(self, variable_set: Optional[BddVariableSet] = None) -> Bdd: ...
+    def is_equivalent(self, other: BoolExpressionType) -> bool: ...
+    def substitute(self, var: str, expression: BoolExpressionType) -> BooleanExpression: ...
+    def evaluate(self, valuation: Mapping[str, BoolType]) -> BooleanExpression: ...
+    def simplify(self) -> BooleanExpression: ...
 
 class VariableId:
     def __init__(self, value: int = 0) -> None:
@@ -691,6 +738,8 @@ class RegulatoryGraph:
         ...
     def drop(self, variables: Union[VariableIdType, Sequence[VariableIdType]]) -> RegulatoryGraph:
         ...
+    def reorder_variables(self, order: Sequence[VariableIdType]) -> RegulatoryGraph:
+        ...
     def remove_regulation_constraints(self) -> RegulatoryGraph:
         ...
     def inline_variable(self, variable: VariableIdType) -> RegulatoryGraph:
@@ -730,6 +779,8 @@ class RegulatoryGraph:
                        length: Optional[int] = None,
     ) -> Optional[list[VariableId]]:
         ...
+    def suggest_variable_order(self) -> list[VariableId]:
+        ...
 
 class BooleanNetwork(RegulatoryGraph):
     @overload
@@ -790,8 +841,31 @@ class BooleanNetwork(RegulatoryGraph):
         ...
     def drop(self, variables: Union[VariableIdType, Sequence[VariableIdType]]) -> BooleanNetwork:
         ...
+    def reorder_variables(self, order: Sequence[VariableIdType]) -> BooleanNetwork:
+        ...
+    def compose(
+            self,
+            other: BooleanNetwork,
+            mapping: Optional[Mapping[VariableIdType, str]] = None,
+            conflict: Literal["and", "or", "left", "parameter"] = "and",
+            repair_graph: bool = False,
+    ) -> BooleanNetwork: ...
     def inline_variable(self, variable: VariableIdType, repair_graph: bool = False) -> BooleanNetwork:
         ...
+    def reduce(
+            self,
+            strategy: Optional[Sequence[Literal["constants", "outputs", "inline"]]] = None,
+    ) -> tuple[BooleanNetwork, NetworkReduction]: ...
+    @overload
+    def to_expanded_network(
+            self,
+            include_dnf: Literal[False] = False,
+    ) -> tuple[BooleanNetwork, dict[str, dict[str, bool]]]: ...
+    @overload
+    def to_expanded_network(
+            self,
+            include_dnf: Literal[True],
+    ) -> tuple[BooleanNetwork, dict[str, dict[str, bool]], dict[str, list[dict[str, bool]]]]: ...
     def to_graph(self) -> RegulatoryGraph:
         ...
     @staticmethod
@@ -809,6 +883,8 @@ class BooleanNetwork(RegulatoryGraph):
         ...
     def to_sbml(self) -> str:
         ...
+    def to_smv(self, semantics: Literal["asynchronous", "synchronous"] = "asynchronous") -> str:
+        ...
     @staticmethod
     def from_bma_json(file_contents: str, binarize: bool = True) -> BooleanNetwork:
         ...
@@ -856,6 +932,11 @@ class BooleanNetwork(RegulatoryGraph):
     def prune_unused_parameters(self) -> BooleanNetwork: ...
     def assign_parameter_name(self, variable: VariableIdType, name: str | None = None) -> ParameterId: ...
     def name_implicit_parameters(self) -> BooleanNetwork: ...
+    def canonicalize_functions(
+            self,
+            mode: Literal["minimal", "blake", "dnf"] = "minimal",
+            skip_parametrized: bool = False,
+    ) -> BooleanNetwork: ...
     def is_variable_input(self, variable: VariableIdType, ctx: SymbolicContext | None = None) -> bool: ...
     def is_variable_constant(self, variable: VariableIdType, ctx: SymbolicContext | None = None) -> bool | None: ...
     def inputs(self, infer: bool = False) -> list[VariableId]: ...
@@ -863,6 +944,15 @@ class BooleanNetwork(RegulatoryGraph):
     def constants(self, infer: bool = False) -> dict[VariableId, bool]: ...
     def constant_names(self, infer: bool = False) -> dict[str, bool]: ...
 
+class NetworkReduction:
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+    def original_network(self) -> BooleanNetwork: ...
+    def reduced_network(self) -> BooleanNetwork: ...
+    def eliminated_variables(self) -> list[str]: ...
+    def eliminated_constants(self) -> dict[str, bool]: ...
+    def lift(self, graph: AsynchronousGraph, set: VertexSet, fixed_points: bool = False) -> VertexSet: ...
+
 class UpdateFunction:
     def __init__(self, ctx: BooleanNetwork, value: Union[str, UpdateFunction, BooleanExpression]):
         """
@@ -989,6 +1079,11 @@ class UpdateFunction:
         ...
     def to_and_or_normal_form(self) -> UpdateFunction:
         ...
+    def prime_implicants(
+            self,
+            network: BooleanNetwork,
+            size_limit: Optional[int] = None,
+    ) -> list[tuple[Bdd, list[BddPartialValuation]]]: ...
     def as_expression(self) -> BooleanExpression:
         ...
 
@@ -1085,6 +1180,7 @@ class SymbolicContext:
     def mk_function(self, function: Union[VariableId, ParameterId, str], arguments: Sequence[Union[UpdateFunction, Bdd, VariableId, str]]) -> Bdd: ...
     def mk_update_function(self, function: UpdateFunction) -> Bdd: ...
     def bdd_variable_set(self) -> BddVariableSet: ...
+    def fingerprint(self) -> int: ...
     def transfer_from(self, bdd: Bdd, old_ctx: SymbolicContext) -> Bdd: ...
     def to_canonical_context(self) -> SymbolicContext: ...
     def eliminate_network_variable(self, variable: Union[VariableIdType, BddVariable]) -> SymbolicContext: ...
@@ -1151,10 +1247,17 @@ class ColorSet:
     def __copy__(self) -> ColorSet: ...
     def __deepcopy__(self, _memo: dict) -> ColorSet: ...
     def __hash__(self) -> int: ...
-    def __getnewargs__(self) -> tuple[SymbolicContext, Bdd]: ...
+    def __reduce__(self) -> tuple[Callable[..., ColorSet], tuple[SymbolicContext, bytes]]: ...
+    def to_bytes(self) -> bytes: ...
+    @staticmethod
+    def from_bytes(ctx: SymbolicContext, data: bytes) -> ColorSet: ...
     def __iter__(self) -> Iterator[ColorModel]: ...
     def __ctx__(self) -> SymbolicContext: ...
     def cardinality(self) -> int: ...
+    @overload
+    def probability(self, prior: Optional[Mapping[BddVariableType, Union[float, Fraction]]] = None, exact: Literal[False] = False) -> float: ...
+    @overload
+    def probability(self, prior: Optional[Mapping[BddVariableType, Union[float, Fraction]]], exact: Literal[True]) -> Fraction: ...
     def intersect(self, other: ColorSet) -> ColorSet: ...
     def minus(self, other: ColorSet) -> ColorSet: ...
     def union(self, other: ColorSet) -> ColorSet: ...
@@ -1163,6 +1266,7 @@ class ColorSet:
     def is_singleton(self) -> bool: ...
     def is_subspace(self) -> bool: ...
     def pick_singleton(self) -> ColorSet: ...
+    def best_color(self, score: Mapping[BddVariableType, float]) -> Optional[ColorModel]: ...
     def symbolic_size(self) -> int: ...
     def to_bdd(self) -> Bdd: ...
     def extend_with_vertices(self, set: VertexSet) -> ColoredVertexSet: ...
@@ -1209,7 +1313,10 @@ class VertexSet:
     def __deepcopy__(self, _memo: dict) -> VertexSet: ...
     def __hash__(self) -> int: ...
     def __iter__(self) -> Iterator[VertexModel]: ...
-    def __getnewargs__(self) -> tuple[SymbolicContext, Bdd]: ...
+    def __reduce__(self) -> tuple[Callable[..., VertexSet], tuple[SymbolicContext, bytes]]: ...
+    def to_bytes(self) -> bytes: ...
+    @staticmethod
+    def from_bytes(ctx: SymbolicContext, data: bytes) -> VertexSet: ...
     def __ctx__(self) -> SymbolicContext: ...
     def cardinality(self) -> int: ...
     def intersect(self, other: VertexSet) -> VertexSet: ...
@@ -1220,6 +1327,8 @@ class VertexSet:
     def is_singleton(self) -> bool: ...
     def is_subspace(self) -> bool: ...
     def pick_singleton(self) -> VertexSet: ...
+    def project_out(self, variables: Sequence[VariableIdType], universal: bool = False) -> VertexSet: ...
+    def restrict_to(self, variables: Sequence[VariableIdType], universal: bool = False) -> VertexSet: ...
     def to_singleton_spaces(self, ctx: SymbolicSpaceContext) -> SpaceSet: ...
     def symbolic_size(self) -> int: ...
     def to_bdd(self) -> Bdd: ...
@@ -1262,7 +1371,10 @@ class SpaceSet:
     def __deepcopy__(self, _memo: dict) -> SpaceSet: ...
     def __hash__(self) -> int: ...
     def __iter__(self) -> Iterator[SpaceModel]: ...
-    def __getnewargs__(self) -> tuple[SymbolicSpaceContext, Bdd]: ...
+    def __reduce__(self) -> tuple[Callable[..., SpaceSet], tuple[SymbolicSpaceContext, bytes]]: ...
+    def to_bytes(self) -> bytes: ...
+    @staticmethod
+    def from_bytes(ctx: SymbolicSpaceContext, data: bytes) -> SpaceSet: ...
     def __ctx__(self) -> SymbolicSpaceContext: ...
     def cardinality(self) -> int: ...
     def intersect(self, other: SpaceSet) -> SpaceSet: ...
@@ -1312,7 +1424,10 @@ class ColoredVertexSet:
     def __deepcopy__(self, _memo: dict) -> ColoredVertexSet: ...
     def __hash__(self) -> int: ...
     def __iter__(self) -> Iterator[tuple[ColorModel, VertexModel]]: ...
-    def __getnewargs__(self) -> tuple[SymbolicContext, Bdd]: ...
+    def __reduce__(self) -> tuple[Callable[..., ColoredVertexSet], tuple[SymbolicContext, bytes]]: ...
+    def to_bytes(self) -> bytes: ...
+    @staticmethod
+    def from_bytes(ctx: SymbolicContext, data: bytes) -> ColoredVertexSet: ...
     def __ctx__(self) -> SymbolicContext: ...
     def cardinality(self) -> int: ...
     def intersect(self, other: ColoredVertexSet) -> ColoredVertexSet: ...
@@ -1332,6 +1447,8 @@ class ColoredVertexSet:
     def pick_color(self) -> ColoredVertexSet: ...
     def pick_vertex(self) -> ColoredVertexSet: ...
     def pick_singleton(self) -> ColoredVertexSet: ...
+    def project_out(self, variables: Sequence[VariableIdType], universal: bool = False) -> ColoredVertexSet: ...
+    def restrict_to(self, variables: Sequence[VariableIdType], universal: bool = False) -> ColoredVertexSet: ...
     def to_singleton_spaces(self, ctx: SymbolicSpaceContext) -> ColoredSpaceSet: ...
     def to_bdd(self) -> Bdd: ...
     def items(self,
@@ -1361,7 +1478,10 @@ class ColoredSpaceSet:
     def __deepcopy__(self, _memo: dict) -> ColoredSpaceSet: ...
     def __hash__(self) -> int: ...
     def __iter__(self) -> Iterator[tuple[ColorModel, SpaceModel]]: ...
-    def __getnewargs__(self) -> tuple[SymbolicSpaceContext, Bdd]: ...
+    def __reduce__(self) -> tuple[Callable[..., ColoredSpaceSet], tuple[SymbolicSpaceContext, bytes]]: ...
+    def to_bytes(self) -> bytes: ...
+    @staticmethod
+    def from_bytes(ctx: SymbolicSpaceContext, data: bytes) -> ColoredSpaceSet: ...
     def __ctx__(self) -> SymbolicSpaceContext: ...
     def cardinality(self) -> int: ...
     def intersect(self, other: ColoredSpaceSet) -> ColoredSpaceSet: ...
@@ -1551,12 +1671,14 @@ class HctlFormula:
     def mk_jump(state_variable: str, inner: HctlFormula) -> HctlFormula: ...
     @staticmethod
     @overload
-    def mk_temporal(op: TemporalUnaryOperator, a: HctlFormula) -> HctlFormula: ...
+    def mk_temporal(op: TemporalUnaryOperator, a: HctlFormula, *, bound: Optional[int] = None) -> HctlFormula: ...
     @staticmethod
     @overload
     def mk_temporal(op: TemporalBinaryOperator, a: HctlFormula, b: HctlFormula) -> HctlFormula: ...
     @staticmethod
-    def mk_temporal(op, a, b): ...
+    def mk_temporal(op, a, b, bound): ...
+    @staticmethod
+    def from_ltl(formula: str) -> HctlFormula: ...
     @staticmethod
     def mk_boolean(op: BinaryOperator, a: HctlFormula, b: HctlFormula) -> HctlFormula: ...
     @staticmethod
@@ -1665,6 +1787,8 @@ class HctlFormula:
     def as_exist_weak_until(self) -> Optional[tuple[HctlFormula, HctlFormula]]: ...
     def as_all_weak_until(self) -> Optional[tuple[HctlFormula, HctlFormula]]: ...
     def is_compatible_with(self, context: AsynchronousGraph) -> bool: ...
+    def validate(self, context: AsynchronousGraph | SymbolicContext, substitution: Optional[Mapping[str, ColoredVertexSet]] = None) -> list[HctlDiagnostic]: ...
+    def to_smv_ctl(self) -> str: ...
     def used_state_variables(self) -> set[str]: ...
     def used_extended_properties(self) -> set[str]: ...
     def children(self) -> list[HctlFormula]: ...
@@ -1673,12 +1797,40 @@ class HctlFormula:
 class ModelChecking:
     @staticmethod
     @overload
-    def verify(graph: AsynchronousGraph, property: str | HctlFormula, substitution: Optional[dict[str, ColoredVertexSet]] = None) -> ColoredVertexSet: ...
+    def verify(graph: AsynchronousGraph | BooleanNetwork, property: str | HctlFormula, substitution: Optional[dict[str, ColoredVertexSet]] = None, fairness: Optional[FairnessConfig] = None) -> ColoredVertexSet: ...
     @staticmethod
     @overload
-    def verify(graph: AsynchronousGraph, properties: Sequence[str | HctlFormula], substitution: Optional[dict[str, ColoredVertexSet]] = None) -> list[ColoredVertexSet]: ...
+    def verify(graph: AsynchronousGraph | BooleanNetwork, properties: Sequence[str | HctlFormula], substitution: Optional[dict[str, ColoredVertexSet]] = None, fairness: Optional[FairnessConfig] = None) -> list[ColoredVertexSet]: ...
+    @staticmethod
+    def verify(graph, property, substitution, fairness): ...
     @staticmethod
-    def verify(graph, property, substitution): ...
+    def explain(graph: AsynchronousGraph, formula: str | HctlFormula, state: Union[Mapping[VariableId, BoolType], Mapping[str, BoolType], VertexModel], color: ColorModel | ColorSet, substitution: Optional[dict[str, ColoredVertexSet]] = None) -> Optional[HctlExplanation]: ...
+    @staticmethod
+    def synthesize(graph: AsynchronousGraph | BooleanNetwork, properties: str | HctlFormula | Sequence[str | HctlFormula], mode: Literal["universal", "existential"] = "universal", initial_states: Optional[ColoredVertexSet] = None, substitution: Optional[dict[str, ColoredVertexSet]] = None) -> SynthesisResult: ...
+
+class ModelCheckingSession:
+    def __init__(self, graph: AsynchronousGraph) -> None: ...
+    def __str__(self) -> str: ...
+    def graph(self) -> AsynchronousGraph: ...
+    @overload
+    def verify(self, property: str | HctlFormula, substitution: Optional[dict[str, ColoredVertexSet]] = None) -> ColoredVertexSet: ...
+    @overload
+    def verify(self, properties: Sequence[str | HctlFormula], substitution: Optional[dict[str, ColoredVertexSet]] = None) -> list[ColoredVertexSet]: ...
+    def verify(self, property, substitution): ...
+    def statistics(self) -> ModelCheckingStatistics: ...
+    def clear(self) -> None: ...
+
+class PropertyTemplates:
+    @staticmethod
+    def reaches_stable_state(phenotype: Optional[Union[str, HctlFormula, Mapping[str, BoolType]]] = None) -> HctlFormula: ...
+    @staticmethod
+    def oscillates(variable: str) -> HctlFormula: ...
+    @staticmethod
+    def bistable(phenotype_a: Union[str, HctlFormula, Mapping[str, BoolType]], phenotype_b: Union[str, HctlFormula, Mapping[str, BoolType]]) -> HctlFormula: ...
+    @staticmethod
+    def irreversible(source: Union[str, HctlFormula, Mapping[str, BoolType]], target: Union[str, HctlFormula, Mapping[str, BoolType]]) -> HctlFormula: ...
+    @staticmethod
+    def multistable(count: int) -> HctlFormula: ...
 
 class Class:
     def __init__(self, items: str | list[str] | set[str]) -> None:
@@ -1718,7 +1870,7 @@ class Classification:
     @staticmethod
     def write_dynamic_properties(annotations: ModelAnnotation, properties: list[tuple[str, str]]): ...
     @staticmethod
-    def save_classification(path: str, network: BooleanNetwork, classification: dict[Class, ColorSet], annotations: Optional[ModelAnnotation] = None): ...
+    def save_classification(path: str, network: BooleanNetwork, classification: dict[Class, ColorSet], annotations: Optional[ModelAnnotation] = None, binary: bool = False, compress: bool = True): ...
     @staticmethod
     def load_classification(path: str) -> tuple[BooleanNetwork, dict[Class, ColorSet], ModelAnnotation]: ...
     @staticmethod
@@ -1933,6 +2085,48 @@ class BiodivineBooleanModels:
     @staticmethod
     def fetch_ids(config: Optional[BbmFilterConfig] = None) -> list[str]: ...
 
+class BddStatistics(TypedDict):
+    variable_count: int
+    support_size: int
+    node_count: int
+    decision_node_count: int
+    max_width: int
+    depth: int
+    path_count: int
+    cardinality: int
+
+class HctlExplanation(TypedDict):
+    holds: bool
+    kind: Literal["witness", "counterexample"]
+    path: list[tuple[VertexModel, HctlFormula]]
+    loop_start: Optional[int]
+
+class FairnessConfig(TypedDict, total=False):
+    weak: Sequence[VariableIdType]
+    strong: Sequence[VariableIdType]
+    infinitely_often: Sequence[ColoredVertexSet]
+
+class HctlDiagnostic(TypedDict):
+    kind: Literal["unknown_variable", "unbound_state_variable", "shadowed_state_variable",
+                  "unresolved_proposition", "missing_extra_variables"]
+    severity: Literal["error", "warning"]
+    message: str
+    formula: HctlFormula
+
+class ModelCheckingStatistics(TypedDict):
+    cached_formulas: int
+    hits: int
+    misses: int
+
+class FunctionTableEntry(TypedDict):
+    inputs: list[bool]
+    status: Literal["fixed", "forbidden", "free"]
+
+class SynthesisResult(TypedDict):
+    colors: ColorSet
+    functions: dict[str, list[FunctionTableEntry]]
+    summary: str
+
 class BbmFilterConfig(TypedDict, total=False):
     min_variables: int
     max_variables: int
@@ -1994,6 +2188,7 @@ HybridOperator = Literal["exists", "forall", "bind", "jump"]
 PhenotypeOscillation = Literal["required", "allowed", "forbidden"]
 BoolClauseType = Union[BddPartialValuation, BddValuation, Mapping[str, BoolType], Mapping[BddVariable, BoolType]]
 BoolExpressionType = Union[BooleanExpression, str]
+ExpressionSyntax = Literal["aeon", "python", "c", "sympy", "bnet"]
 VertexSetType = Union[VertexSet, ColoredVertexSet, VertexModel, SpaceModel, Mapping[VariableIdType, BoolType]]
 # IDT = TypeVar('IDT', covariant=True)
 # class Regulation(TypedDict, Generic[IDT]):
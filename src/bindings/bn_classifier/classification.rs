@@ -15,6 +15,9 @@ use pyo3::{Bound, Py, PyAny, PyResult, Python, pyclass, pymethods};
 use crate::bindings::algorithms::attractors::Attractors;
 use crate::bindings::algorithms::reachability::Reachability;
 use crate::bindings::bn_classifier::class::{Class, extend_map};
+use crate::bindings::lib_hctl_model_checker::builtin_propositions::{
+    encode_builtin_propositions, encode_proposition_name,
+};
 use crate::bindings::lib_hctl_model_checker::hctl_formula::HctlFormula;
 use crate::bindings::lib_param_bn::boolean_network::BooleanNetwork;
 use crate::bindings::lib_param_bn::model_annotation::ModelAnnotation;
@@ -225,14 +228,23 @@ impl Classification {
     /// Note that this method will automatically sanitize the `ColorSet` objects such that they
     /// use the "default" symbolic encoding for the provided `network`.
     ///
+    /// By default, all `ColorSet` objects are stored in the original text format (one BDD dump
+    /// per class), which is expected by the BN Classifier. Set `binary=True` to instead use
+    /// a compact binary format which shares BDD nodes between the individual classes (this
+    /// format is not supported by older versions of the BN Classifier). Set `compress=False`
+    /// to disable compression of the archive entries (faster, but larger files). Both formats
+    /// can be read by `Classification.load_classification`.
+    ///
     #[staticmethod]
-    #[pyo3(signature = (path, network, classification, annotations = None))]
+    #[pyo3(signature = (path, network, classification, annotations = None, binary = false, compress = true))]
     pub fn save_classification(
         py: Python,
         path: String,
         network: &BooleanNetwork,
         classification: HashMap<Class, ColorSet>,
         annotations: Option<ModelAnnotation>,
+        binary: bool,
+        compress: bool,
     ) -> PyResult<()> {
         let ctx = RsSymbolicContext::new(network.as_native()).map_err(runtime_error)?;
 
@@ -258,8 +270,13 @@ impl Classification {
             aeon_file.push_str(ann_string.as_str());
         }
 
-        let Err(e) = build_classification_archive(classes, path.as_str(), aeon_file.as_str())
-        else {
+        let Err(e) = build_classification_archive(
+            classes,
+            path.as_str(),
+            aeon_file.as_str(),
+            binary,
+            compress,
+        ) else {
             return Ok(());
         };
 
@@ -631,7 +648,7 @@ impl Classification {
             for it in assertions {
                 let formula = HctlFormula::new(&it, true, Some(graph.symbolic_context().get()))?;
                 max_var_count = max(max_var_count, formula.used_state_variables().len());
-                hctl_str_assertions.push(formula.__str__());
+                hctl_str_assertions.push(encode_builtin_propositions(&formula.__str__()));
                 hctl_assertions.push(formula);
             }
         }
@@ -642,7 +659,10 @@ impl Classification {
             let name = k.extract::<String>()?;
             let formula = HctlFormula::new(&v, true, Some(graph.symbolic_context().get()))?;
             max_var_count = max(max_var_count, formula.used_state_variables().len());
-            hctl_str_properties.push((name.clone(), formula.__str__()));
+            hctl_str_properties.push((
+                name.clone(),
+                encode_builtin_propositions(&formula.__str__()),
+            ));
             hctl_properties.push((name, formula));
         }
 
@@ -663,7 +683,7 @@ impl Classification {
         let native_substitution = if let Some(substitution) = substitution {
             substitution
                 .into_iter()
-                .map(|(a, b)| (a, b.as_native().clone()))
+                .map(|(a, b)| (encode_proposition_name(&a), b.as_native().clone()))
                 .collect::<HashMap<_, _>>()
         } else {
             HashMap::new()
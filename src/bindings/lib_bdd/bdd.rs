@@ -10,11 +10,11 @@ use crate::{
 use biodivine_lib_bdd::Bdd as RsBdd;
 use biodivine_lib_bdd::{BddPathIterator, BddSatisfyingValuations};
 use macros::Wrapper;
-use num_bigint::BigUint;
+use num_bigint::{BigInt, BigUint};
 use num_traits::FromPrimitive;
 use pyo3::basic::CompareOp;
 use pyo3::prelude::*;
-use pyo3::types::PyBytes;
+use pyo3::types::{PyBytes, PyDict};
 use rand::rngs::StdRng;
 use rand::{Rng, SeedableRng};
 use std::collections::hash_map::DefaultHasher;
@@ -199,6 +199,137 @@ impl Bdd {
             .to_dot_string(self.ctx.get().as_native(), zero_pruned)
     }
 
+    /// Produce a [GraphML](http://graphml.graphdrawing.org/) representation of the underlying
+    /// graph. If `zero_pruned` is set, edges leading to the `0` terminal are omitted for clarity.
+    ///
+    /// Each node has a `label` (variable name, or `0`/`1` for terminal nodes) and a `level`
+    /// (index of the decision variable; terminal nodes use `Bdd.variable_count`). Each edge
+    /// has a Boolean `high` attribute which distinguishes the high and low links. The
+    /// result can be loaded by most graph libraries (e.g., `networkx.parse_graphml`).
+    #[pyo3(signature = (zero_pruned = true))]
+    pub fn to_graphml(&self, zero_pruned: bool) -> String {
+        let bdd = self.as_native();
+        let ctx = self.ctx.get().as_native();
+        let mut out = String::new();
+        out.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        out.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+        out.push_str(
+            "  <key id=\"label\" for=\"node\" attr.name=\"label\" attr.type=\"string\"/>\n",
+        );
+        out.push_str("  <key id=\"level\" for=\"node\" attr.name=\"level\" attr.type=\"int\"/>\n");
+        out.push_str(
+            "  <key id=\"high\" for=\"edge\" attr.name=\"high\" attr.type=\"boolean\"/>\n",
+        );
+        out.push_str("  <graph id=\"bdd\" edgedefault=\"directed\">\n");
+        for pointer in Self::visible_pointers(bdd, zero_pruned) {
+            let label = Self::node_label(bdd, ctx, pointer);
+            let level = Self::node_level(bdd, pointer);
+            out.push_str(&format!(
+                "    <node id=\"n{}\"><data key=\"label\">{}</data><data key=\"level\">{level}</data></node>\n",
+                pointer.to_index(),
+                xml_escape(label.as_str()),
+            ));
+        }
+        for (source, target, high) in Self::visible_edges(bdd, zero_pruned) {
+            out.push_str(&format!(
+                "    <edge source=\"n{}\" target=\"n{}\"><data key=\"high\">{high}</data></edge>\n",
+                source.to_index(),
+                target.to_index(),
+            ));
+        }
+        out.push_str("  </graph>\n");
+        out.push_str("</graphml>\n");
+        out
+    }
+
+    /// Produce a simple `.svg` image of the underlying graph, which can be displayed without
+    /// `graphviz`. If `zero_pruned` is set, edges leading to the `0` terminal are omitted.
+    ///
+    /// Nodes are drawn in layers based on their decision variable (terminal nodes form the
+    /// last layer). High links are drawn as solid lines, low links as dashed lines.
+    ///
+    /// You can use this in Jupyter notebooks to visualize the BDD:
+    /// ```python
+    /// bdd = ...
+    ///
+    /// from IPython.display import SVG
+    /// SVG(bdd.to_svg())
+    /// ```
+    #[pyo3(signature = (zero_pruned = true))]
+    pub fn to_svg(&self, zero_pruned: bool) -> String {
+        const NODE_SPACING: usize = 60;
+        const LAYER_SPACING: usize = 70;
+        const MARGIN: usize = 30;
+
+        let bdd = self.as_native();
+        let ctx = self.ctx.get().as_native();
+        let pointers = Self::visible_pointers(bdd, zero_pruned);
+
+        // Assign every node a layer (only non-empty layers are drawn) and a position in it.
+        let mut levels = pointers
+            .iter()
+            .map(|it| Self::node_level(bdd, *it))
+            .collect::<Vec<_>>();
+        levels.sort();
+        levels.dedup();
+        let mut layers: Vec<Vec<biodivine_lib_bdd::BddPointer>> = vec![Vec::new(); levels.len()];
+        for pointer in pointers.iter().rev() {
+            let level = Self::node_level(bdd, *pointer);
+            let layer = levels.binary_search(&level).unwrap();
+            layers[layer].push(*pointer);
+        }
+        let max_width = layers.iter().map(|it| it.len()).max().unwrap_or(1);
+        let width = 2 * MARGIN + max_width.saturating_sub(1) * NODE_SPACING;
+        let height = 2 * MARGIN + layers.len().saturating_sub(1) * LAYER_SPACING;
+        let mut position = HashMap::new();
+        for (y, layer) in layers.iter().enumerate() {
+            let offset = (width - layer.len().saturating_sub(1) * NODE_SPACING) / 2;
+            for (x, pointer) in layer.iter().enumerate() {
+                position.insert(
+                    *pointer,
+                    (offset + x * NODE_SPACING, MARGIN + y * LAYER_SPACING),
+                );
+            }
+        }
+
+        let mut out = String::new();
+        out.push_str(&format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" viewBox=\"0 0 {width} {height}\">\n",
+        ));
+        for (source, target, high) in Self::visible_edges(bdd, zero_pruned) {
+            let (x1, y1) = position[&source];
+            let (x2, y2) = position[&target];
+            let dash = if high {
+                ""
+            } else {
+                " stroke-dasharray=\"4,3\""
+            };
+            out.push_str(&format!(
+                "  <line x1=\"{x1}\" y1=\"{y1}\" x2=\"{x2}\" y2=\"{y2}\" stroke=\"black\"{dash}/>\n",
+            ));
+        }
+        for pointer in pointers {
+            let (x, y) = position[&pointer];
+            let label = xml_escape(Self::node_label(bdd, ctx, pointer).as_str());
+            if pointer.is_terminal() {
+                out.push_str(&format!(
+                    "  <rect x=\"{}\" y=\"{}\" width=\"24\" height=\"24\" fill=\"white\" stroke=\"black\"/>\n",
+                    x - 12,
+                    y - 12,
+                ));
+            } else {
+                out.push_str(&format!(
+                    "  <circle cx=\"{x}\" cy=\"{y}\" r=\"16\" fill=\"white\" stroke=\"black\"/>\n",
+                ));
+            }
+            out.push_str(&format!(
+                "  <text x=\"{x}\" y=\"{y}\" font-size=\"11\" font-family=\"sans-serif\" text-anchor=\"middle\" dominant-baseline=\"central\">{label}</text>\n",
+            ));
+        }
+        out.push_str("</svg>\n");
+        out
+    }
+
     /// If this BDD is a terminal node, return its Boolean value. Otherwise, return `None`.
     fn as_bool(&self) -> Option<bool> {
         self.as_native().as_bool()
@@ -266,6 +397,33 @@ impl Bdd {
             .collect()
     }
 
+    /// Build a list of all **prime implicants** of this Boolean function, each represented as
+    /// a `BddPartialValuation`.
+    ///
+    /// A prime implicant is a conjunctive clause that implies the function, but no clause
+    /// with fewer literals that is contained in it does. The disjunction of all prime
+    /// implicants is the *Blake canonical form* of the function. Unlike `Bdd.to_dnf`, the
+    /// result is thus unique for each function (the clauses are sorted lexicographically
+    /// based on the variable order).
+    ///
+    /// Note that the number of prime implicants can be exponential in the number of
+    /// variables. Similar to `Bdd.to_dnf`, you can use `size_limit` to stop the computation
+    /// (with an `InterruptedError`) once the number of intermediate clauses exceeds
+    /// the given limit.
+    #[pyo3(signature = (size_limit = None))]
+    pub fn prime_implicants(
+        &self,
+        py: Python,
+        size_limit: Option<usize>,
+    ) -> PyResult<Vec<BddPartialValuation>> {
+        Ok(
+            Bdd::native_prime_implicants(py, self.as_native(), size_limit)?
+                .into_iter()
+                .map(|it| BddPartialValuation::new_raw(self.ctx.clone(), it))
+                .collect(),
+        )
+    }
+
     /// Return the number of graph nodes in this `Bdd`.
     fn node_count(&self) -> usize {
         self.as_native().size()
@@ -281,6 +439,84 @@ impl Bdd {
             .collect()
     }
 
+    /// Return the number of decision nodes in each level of this `Bdd`. The result is a list
+    /// indexed by `BddVariable` (i.e., it has `Bdd.variable_count` entries, and the
+    /// entry for a variable that does not appear in the `Bdd` is zero).
+    ///
+    /// Compared to `Bdd.node_count_per_variable`, this "width profile" can be directly
+    /// plotted to reveal the levels at which the `Bdd` grows.
+    fn width_profile(&self) -> Vec<usize> {
+        let bdd = self.as_native();
+        let mut result = vec![0; usize::from(bdd.num_vars())];
+        for pointer in bdd.pointers() {
+            if !pointer.is_terminal() {
+                result[bdd.var_of(pointer).to_index()] += 1;
+            }
+        }
+        result
+    }
+
+    /// Compute the number of distinct paths from the root to each node of this `Bdd`.
+    /// The result is a list indexed by the BDD node indices (corresponding to `BddPointer`
+    /// values).
+    ///
+    /// In particular, the path count of the `1` terminal node is the number of satisfying
+    /// clauses (see `Bdd.clause_cardinality`). Nodes with a high path count are the ones
+    /// through which most of the `Bdd` paths are "routed".
+    fn node_path_counts(&self) -> Vec<BigUint> {
+        let bdd = self.as_native();
+        let mut counts = vec![BigUint::ZERO; bdd.size()];
+        counts[bdd.root_pointer().to_index()] = BigUint::from(1u32);
+        // The children of each node have smaller indices than the node itself, so all
+        // paths reaching a node are known once we get to it in the descending order.
+        for pointer in bdd.pointers().collect::<Vec<_>>().into_iter().rev() {
+            if pointer.is_terminal() {
+                continue;
+            }
+            let count = counts[pointer.to_index()].clone();
+            counts[bdd.low_link_of(pointer).to_index()] += &count;
+            counts[bdd.high_link_of(pointer).to_index()] += count;
+        }
+        counts
+    }
+
+    /// Compute a summary of the structural properties of this `Bdd`. The result is
+    /// a dictionary with the following keys:
+    ///
+    ///  - `variable_count`: The number of admissible variables (see `Bdd.variable_count`).
+    ///  - `support_size`: The number of variables that actually appear in the `Bdd`.
+    ///  - `node_count`: The total number of nodes (see `Bdd.node_count`).
+    ///  - `decision_node_count`: The number of non-terminal nodes.
+    ///  - `max_width`: The maximal number of nodes in a single level (see `Bdd.width_profile`).
+    ///  - `depth`: The number of decision nodes on the longest path from the root.
+    ///  - `path_count`: The number of paths leading to the `1` terminal.
+    ///  - `cardinality`: The number of satisfying valuations.
+    fn statistics<'a>(&self, py: Python<'a>) -> PyResult<Bound<'a, PyDict>> {
+        let bdd = self.as_native();
+        let mut depth = vec![0usize; bdd.size()];
+        for pointer in bdd.pointers() {
+            if !pointer.is_terminal() {
+                let low = depth[bdd.low_link_of(pointer).to_index()];
+                let high = depth[bdd.high_link_of(pointer).to_index()];
+                depth[pointer.to_index()] = 1 + low.max(high);
+            }
+        }
+        let decision_nodes = bdd.pointers().filter(|it| !it.is_terminal()).count();
+        let result = PyDict::new(py);
+        result.set_item("variable_count", self.variable_count())?;
+        result.set_item("support_size", bdd.support_set().len())?;
+        result.set_item("node_count", bdd.size())?;
+        result.set_item("decision_node_count", decision_nodes)?;
+        result.set_item(
+            "max_width",
+            self.width_profile().into_iter().max().unwrap_or(0),
+        )?;
+        result.set_item("depth", depth[bdd.root_pointer().to_index()])?;
+        result.set_item("path_count", bdd.exact_clause_cardinality())?;
+        result.set_item("cardinality", bdd.exact_cardinality())?;
+        Ok(result)
+    }
+
     /// Test structural equality of two `Bdd` objects. Compared to normal `==`, this equality test
     /// matches the two BDD graphs exactly, node by node.
     ///
@@ -429,6 +665,35 @@ impl Bdd {
         }
     }
 
+    /// Compute the probability that a random valuation satisfies this `Bdd`, assuming every
+    /// variable is independently `true` with the probability given in `weights`.
+    ///
+    /// The `weights` dictionary maps variables (`BddVariable` or names) to probabilities
+    /// (`float`, `int`, or `fractions.Fraction`) from the `[0,1]` interval. Variables that
+    /// are not in `weights` are `true` with probability `1/2`. In particular,
+    /// `bdd.weighted_count({})` is equivalent to `bdd.cardinality() / 2 ** bdd.variable_count()`.
+    ///
+    /// By default, the computation uses floating-point arithmetic. With `exact=True`, the
+    /// result is an exact `fractions.Fraction` (floating-point weights are then interpreted
+    /// exactly as well, e.g., `0.1` is not exactly `1/10`).
+    ///
+    /// ```python
+    /// from fractions import Fraction
+    /// ctx = BddVariableSet(["a", "b"])
+    /// bdd = ctx.eval_expression("a | b")
+    /// assert bdd.weighted_count({}) == 0.75
+    /// assert bdd.weighted_count({"a": Fraction(1, 3)}, exact=True) == Fraction(2, 3)
+    /// ```
+    #[pyo3(signature = (weights, exact = false))]
+    pub fn weighted_count(
+        &self,
+        py: Python,
+        weights: &Bound<'_, PyDict>,
+        exact: bool,
+    ) -> PyResult<Py<PyAny>> {
+        Self::native_weighted_count(py, self.ctx.get(), self.as_native(), weights, exact)
+    }
+
     /// Compute the number of canonical clauses of this `Bdd`. These are the disjunctive clauses
     /// reported by `Bdd.clause_iterator`. Clause cardinality can be thus used as the expected
     /// item count for this iterator.
@@ -932,6 +1197,27 @@ impl Bdd {
             .ok_or_else(|| runtime_error("BDD is empty."))
     }
 
+    /// Pick the valuation that maximizes a linear score given by `weights`.
+    ///
+    /// The `weights` dictionary maps variables (`BddVariable` or names) to numbers. The score
+    /// of a valuation is the sum of the weights of all variables that are `true` in the
+    /// valuation (variables that are not in `weights` have weight `0`). If there are multiple
+    /// valuations with the maximal score, the method deterministically picks one of them.
+    ///
+    /// Note that `Bdd.valuation_most_positive` is a special case of this method where every
+    /// variable has weight `1`.
+    ///
+    /// ```python
+    /// ctx = BddVariableSet(["a", "b", "c"])
+    /// bdd = ctx.eval_expression("(a & !c) | (!a & c)")
+    /// assert bdd.valuation_max_weight({"a": 1, "c": 2}) == BddValuation(ctx, [False, False, True])
+    /// ```
+    pub fn valuation_max_weight(&self, weights: &Bound<'_, PyDict>) -> PyResult<BddValuation> {
+        Self::native_valuation_max_weight(self.ctx.get(), self.as_native(), weights)?
+            .map(|it| BddValuation::new_raw(self.ctx.clone(), it))
+            .ok_or_else(|| runtime_error("BDD is empty."))
+    }
+
     /// Create a uniform valuation sampler for this BDD, optionally initialized with a seed.
     ///
     /// Note that a uniform sampler is made specifically for a single BDD object and cannot
@@ -1174,6 +1460,332 @@ impl Bdd {
             value,
         }
     }
+
+    /// Compute the weighted count of `bdd` (see `Bdd.weighted_count`), where the variables
+    /// in `weights` are resolved using `ctx`.
+    pub fn native_weighted_count(
+        py: Python,
+        ctx: &BddVariableSet,
+        bdd: &RsBdd,
+        weights: &Bound<'_, PyDict>,
+        exact: bool,
+    ) -> PyResult<Py<PyAny>> {
+        let num_vars = usize::from(bdd.num_vars());
+        let fraction = py.import("fractions")?.getattr("Fraction")?;
+        // Every probability is stored as a fraction `a / b`. Missing variables are `1/2`.
+        let mut probabilities = vec![(BigUint::from(1u32), BigUint::from(2u32)); num_vars];
+        let mut float_probabilities = vec![0.5f64; num_vars];
+        for (key, value) in weights.iter() {
+            let var = ctx.resolve_variable(&key)?.to_index();
+            let value = fraction.call1((value,))?;
+            let numerator: BigInt = value.getattr("numerator")?.extract()?;
+            let denominator: BigUint = value.getattr("denominator")?.extract()?;
+            let Some(numerator) = numerator.to_biguint().filter(|it| *it <= denominator) else {
+                return throw_runtime_error(format!(
+                    "Weight of variable `{}` is not a probability.",
+                    ctx.as_native()
+                        .name_of(biodivine_lib_bdd::BddVariable::from_index(var))
+                ));
+            };
+            float_probabilities[var] = value.extract::<f64>()?;
+            probabilities[var] = (numerator, denominator);
+        }
+
+        let level = |pointer: biodivine_lib_bdd::BddPointer| {
+            if pointer.is_terminal() {
+                num_vars
+            } else {
+                bdd.var_of(pointer).to_index()
+            }
+        };
+
+        if !exact {
+            // Variables skipped by an edge contribute `p + (1 - p) = 1`, so they can be ignored.
+            let mut values: Vec<f64> = Vec::with_capacity(bdd.size());
+            for pointer in bdd.pointers() {
+                let value = if pointer.is_terminal() {
+                    if pointer.is_one() { 1.0 } else { 0.0 }
+                } else {
+                    let p = float_probabilities[level(pointer)];
+                    let low = values[bdd.low_link_of(pointer).to_index()];
+                    let high = values[bdd.high_link_of(pointer).to_index()];
+                    p * high + (1.0 - p) * low
+                };
+                values.push(value);
+            }
+            return values[bdd.root_pointer().to_index()].into_py_any(py);
+        }
+
+        // To avoid reducing fractions, the value of a node at level `l` is stored as
+        // a numerator with respect to the denominator `suffix[l] = b_l * ... * b_{n-1}`.
+        let mut suffix = vec![BigUint::from(1u32); num_vars + 1];
+        for var in (0..num_vars).rev() {
+            suffix[var] = &suffix[var + 1] * &probabilities[var].1;
+        }
+        let lift = |value: &BigUint, from: usize, to: usize| value * (&suffix[to] / &suffix[from]);
+        let mut values: Vec<BigUint> = Vec::with_capacity(bdd.size());
+        for pointer in bdd.pointers() {
+            let value = if pointer.is_terminal() {
+                BigUint::from(u32::from(pointer.is_one()))
+            } else {
+                let var = level(pointer);
+                let (a, b) = &probabilities[var];
+                let low_link = bdd.low_link_of(pointer);
+                let high_link = bdd.high_link_of(pointer);
+                let low = lift(&values[low_link.to_index()], level(low_link), var + 1);
+                let high = lift(&values[high_link.to_index()], level(high_link), var + 1);
+                a * high + (b - a) * low
+            };
+            values.push(value);
+        }
+        let root = bdd.root_pointer();
+        let numerator = lift(&values[root.to_index()], level(root), 0);
+        fraction
+            .call1((numerator, suffix[0].clone()))?
+            .into_py_any(py)
+    }
+
+    /// Find the valuation of `bdd` that maximizes the linear score given by `weights`
+    /// (see `Bdd.valuation_max_weight`), where the variables in `weights` are resolved
+    /// using `ctx`. Returns `None` if `bdd` is empty.
+    pub fn native_valuation_max_weight(
+        ctx: &BddVariableSet,
+        bdd: &RsBdd,
+        weights: &Bound<'_, PyDict>,
+    ) -> PyResult<Option<biodivine_lib_bdd::BddValuation>> {
+        if bdd.is_false() {
+            return Ok(None);
+        }
+
+        let num_vars = usize::from(bdd.num_vars());
+        let mut var_weights = vec![0.0f64; num_vars];
+        for (key, value) in weights.iter() {
+            var_weights[ctx.resolve_variable(&key)?.to_index()] = value.extract::<f64>()?;
+        }
+
+        // A variable that is skipped by an edge can be set freely, hence it contributes
+        // its weight only if the weight is positive. `gain[l]` is the sum of such positive
+        // weights for variables `0..l`.
+        let mut gain = vec![0.0f64; num_vars + 1];
+        for var in 0..num_vars {
+            gain[var + 1] = gain[var] + var_weights[var].max(0.0);
+        }
+        let level = |pointer: biodivine_lib_bdd::BddPointer| {
+            if pointer.is_terminal() {
+                num_vars
+            } else {
+                bdd.var_of(pointer).to_index()
+            }
+        };
+
+        // For every node, the best score of a path from this node to the `1` terminal
+        // (`None` for the `0` terminal), and whether this path continues using the high link.
+        let mut best: Vec<Option<(f64, bool)>> = Vec::with_capacity(bdd.size());
+        for pointer in bdd.pointers() {
+            if pointer.is_terminal() {
+                best.push(pointer.is_one().then_some((0.0, false)));
+                continue;
+            }
+            let var = level(pointer);
+            let edge_score = |child: biodivine_lib_bdd::BddPointer, value: bool| {
+                best[child.to_index()].map(|(score, _)| {
+                    let weight = if value { var_weights[var] } else { 0.0 };
+                    weight + gain[level(child)] - gain[var + 1] + score
+                })
+            };
+            let low = edge_score(bdd.low_link_of(pointer), false);
+            let high = edge_score(bdd.high_link_of(pointer), true);
+            best.push(match (low, high) {
+                (Some(low), Some(high)) if high > low => Some((high, true)),
+                (Some(low), _) => Some((low, false)),
+                (None, Some(high)) => Some((high, true)),
+                (None, None) => None,
+            });
+        }
+
+        let mut valuation: Vec<bool> = var_weights.iter().map(|it| *it > 0.0).collect();
+        let mut pointer = bdd.root_pointer();
+        while !pointer.is_terminal() {
+            let Some((_, value)) = best[pointer.to_index()] else {
+                unreachable!("Every node of a non-empty BDD can reach the `1` terminal.");
+            };
+            valuation[level(pointer)] = value;
+            pointer = if value {
+                bdd.high_link_of(pointer)
+            } else {
+                bdd.low_link_of(pointer)
+            };
+        }
+        Ok(Some(biodivine_lib_bdd::BddValuation::new(valuation)))
+    }
+
+    /// The pointers of all nodes that should be drawn in a visual representation of `bdd`.
+    fn visible_pointers(bdd: &RsBdd, zero_pruned: bool) -> Vec<biodivine_lib_bdd::BddPointer> {
+        bdd.pointers()
+            .filter(|it| !zero_pruned || !it.is_zero() || bdd.is_false())
+            .collect()
+    }
+
+    /// The `(source, target, is_high)` edges that should be drawn in a visual representation
+    /// of `bdd`.
+    fn visible_edges(
+        bdd: &RsBdd,
+        zero_pruned: bool,
+    ) -> Vec<(
+        biodivine_lib_bdd::BddPointer,
+        biodivine_lib_bdd::BddPointer,
+        bool,
+    )> {
+        let mut result = Vec::new();
+        for pointer in bdd.pointers().filter(|it| !it.is_terminal()) {
+            let low = bdd.low_link_of(pointer);
+            let high = bdd.high_link_of(pointer);
+            if !zero_pruned || !low.is_zero() {
+                result.push((pointer, low, false));
+            }
+            if !zero_pruned || !high.is_zero() {
+                result.push((pointer, high, true));
+            }
+        }
+        result
+    }
+
+    /// The label of a BDD node: the name of the decision variable, or `0`/`1` for terminals.
+    fn node_label(
+        bdd: &RsBdd,
+        ctx: &biodivine_lib_bdd::BddVariableSet,
+        pointer: biodivine_lib_bdd::BddPointer,
+    ) -> String {
+        if pointer.is_zero() {
+            "0".to_string()
+        } else if pointer.is_one() {
+            "1".to_string()
+        } else {
+            ctx.name_of(bdd.var_of(pointer))
+        }
+    }
+
+    /// The level of a BDD node: the index of the decision variable, or the number of
+    /// variables for terminals.
+    fn node_level(bdd: &RsBdd, pointer: biodivine_lib_bdd::BddPointer) -> usize {
+        if pointer.is_terminal() {
+            usize::from(bdd.num_vars())
+        } else {
+            bdd.var_of(pointer).to_index()
+        }
+    }
+
+    /// Rebuild the given `bdd` in the `target` variable set, replacing every decision variable
+    /// according to the given `mapping`. As opposed to `Bdd.rename`, the mapping does not need
+    /// to preserve the variable ordering. Returns `None` if some variable is not mapped.
+    ///
+    /// If the mapping preserves the ordering of the variables that appear in `bdd`, the
+    /// variables are only renamed. Otherwise, the `Bdd` is rebuilt node by node.
+    pub fn native_permute(
+        bdd: &RsBdd,
+        target: &biodivine_lib_bdd::BddVariableSet,
+        mapping: &HashMap<biodivine_lib_bdd::BddVariable, biodivine_lib_bdd::BddVariable>,
+    ) -> Option<RsBdd> {
+        let mut support = bdd.support_set().into_iter().collect::<Vec<_>>();
+        support.sort_by_key(|it| it.to_index());
+        let mut mapped = Vec::with_capacity(support.len());
+        for var in &support {
+            mapped.push(*mapping.get(var)?);
+        }
+        // The mapping preserves the ordering if the mapped support variables are sorted as well.
+        let preserves_order = mapped
+            .windows(2)
+            .all(|it| it[0].to_index() < it[1].to_index());
+        if preserves_order && bdd.num_vars() == target.num_vars() {
+            let renaming = support.into_iter().zip(mapped).collect::<HashMap<_, _>>();
+            let mut result = bdd.clone();
+            unsafe {
+                result.rename_variables(&renaming);
+            }
+            return Some(result);
+        }
+
+        // Nodes are processed bottom-up, i.e., the children are always translated first.
+        let mut results: Vec<RsBdd> = Vec::with_capacity(bdd.size());
+        for pointer in bdd.pointers() {
+            let result = if pointer.is_zero() {
+                target.mk_false()
+            } else if pointer.is_one() {
+                target.mk_true()
+            } else {
+                let literal = target.mk_var(*mapping.get(&bdd.var_of(pointer))?);
+                let low = &results[bdd.low_link_of(pointer).to_index()];
+                let high = &results[bdd.high_link_of(pointer).to_index()];
+                literal.and(high).or(&literal.not().and(low))
+            };
+            results.push(result);
+        }
+        Some(results.swap_remove(bdd.root_pointer().to_index()))
+    }
+
+    /// Compute the sorted list of prime implicants of the given `bdd`.
+    ///
+    /// The primes of `f` with top variable `x` are the primes of `f[x=0] & f[x=1]`, plus
+    /// `!x & p` (resp. `x & p`) for every prime `p` of `f[x=0]` (resp. `f[x=1]`) that is not
+    /// a prime of `f[x=0] & f[x=1]`.
+    pub fn native_prime_implicants(
+        py: Python,
+        bdd: &RsBdd,
+        size_limit: Option<usize>,
+    ) -> PyResult<Vec<biodivine_lib_bdd::BddPartialValuation>> {
+        fn rec(
+            py: Python,
+            bdd: &RsBdd,
+            size_limit: Option<usize>,
+            cache: &mut HashMap<RsBdd, Vec<biodivine_lib_bdd::BddPartialValuation>>,
+        ) -> PyResult<Vec<biodivine_lib_bdd::BddPartialValuation>> {
+            if bdd.is_false() {
+                return Ok(Vec::new());
+            }
+            if bdd.is_true() {
+                return Ok(vec![biodivine_lib_bdd::BddPartialValuation::empty()]);
+            }
+            if let Some(result) = cache.get(bdd) {
+                return Ok(result.clone());
+            }
+            py.check_signals()?;
+
+            let var = bdd.var_of(bdd.root_pointer());
+            let low = bdd.var_restrict(var, false);
+            let high = bdd.var_restrict(var, true);
+            let shared = rec(py, &low.and(&high), size_limit, cache)?;
+            let mut result = shared.clone();
+            let shared = shared.into_iter().collect::<HashSet<_>>();
+            for (value, branch) in [(false, &low), (true, &high)] {
+                for mut clause in rec(py, branch, size_limit, cache)? {
+                    if !shared.contains(&clause) {
+                        clause.set_value(var, value);
+                        result.push(clause);
+                    }
+                }
+            }
+
+            if let Some(size_limit) = size_limit
+                && size_limit < result.len()
+            {
+                return throw_interrupted_error(format!(
+                    "Exceeded size limit of {size_limit} clauses"
+                ));
+            }
+            cache.insert(bdd.clone(), result.clone());
+            Ok(result)
+        }
+
+        let mut result = rec(py, bdd, size_limit, &mut HashMap::new())?;
+        result.sort_by_cached_key(|clause| {
+            clause
+                .to_values()
+                .into_iter()
+                .map(|(var, value)| (var.to_index(), value))
+                .collect::<Vec<_>>()
+        });
+        Ok(result)
+    }
 }
 
 #[pymethods]
@@ -1267,3 +1879,12 @@ pub struct NaiveSampler(biodivine_lib_bdd::random_sampling::NaiveSampler<StdRng>
 pub struct UniformValuationSampler(
     biodivine_lib_bdd::random_sampling::UniformValuationSampler<StdRng>,
 );
+
+/// Escape the special XML characters in the given string.
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
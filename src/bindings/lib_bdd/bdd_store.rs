@@ -0,0 +1,255 @@
+use crate::bindings::lib_bdd::bdd::Bdd;
+use crate::bindings::lib_bdd::bdd_variable_set::BddVariableSet;
+use crate::internal::shared_bdd::{
+    SharedBddTable, read_string, read_varint, write_string, write_varint,
+};
+use crate::{AsNative, throw_index_error, throw_runtime_error};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use std::collections::{BTreeMap, HashMap};
+use std::io::Cursor;
+
+/// A collection of named `Bdd` objects (over the same `BddVariableSet`) which share
+/// their node storage.
+///
+/// When storing many related `Bdd` objects (e.g., attractors of individual colors,
+/// or classification categories), a large portion of their structure is typically
+/// the same. A `BddStore` keeps every unique node only once. The individual `Bdd`
+/// objects are then extracted on demand.
+///
+/// ```python
+/// ctx = BddVariableSet(["a", "b", "c"])
+/// a_and_b = ctx.eval_expression("a & b")
+/// a_and_b_or_c = ctx.eval_expression("(a & b) | c")
+///
+/// store = BddStore(ctx, { "x": a_and_b, "y": a_and_b_or_c })
+/// assert len(store) == 2
+/// assert "x" in store
+/// assert store.names() == ["x", "y"]
+/// assert store["y"] == a_and_b_or_c
+/// assert store.node_count() < store.unshared_node_count()
+///
+/// store["z"] = ctx.mk_true()
+/// del store["x"]
+/// assert store.names() == ["y", "z"]
+///
+/// copy = BddStore.from_bytes(ctx, store.to_bytes())
+/// assert copy.to_dict() == store.to_dict()
+/// ```
+#[pyclass(module = "biodivine_aeon")]
+#[derive(Clone)]
+pub struct BddStore {
+    ctx: Py<BddVariableSet>,
+    table: SharedBddTable,
+    roots: BTreeMap<String, usize>,
+    /// The number of roots that were removed or replaced since the last compaction.
+    stale: usize,
+}
+
+#[pymethods]
+impl BddStore {
+    /// Create a new `BddStore` for the given `BddVariableSet`, optionally initialized
+    /// with a dictionary of named `Bdd` objects.
+    #[new]
+    #[pyo3(signature = (ctx, bdds = None))]
+    fn new(ctx: Py<BddVariableSet>, bdds: Option<HashMap<String, Bdd>>) -> PyResult<BddStore> {
+        let num_vars = ctx.get().as_native().num_vars();
+        let mut store = BddStore {
+            ctx,
+            table: SharedBddTable::new(num_vars),
+            roots: BTreeMap::new(),
+            stale: 0,
+        };
+        for (name, bdd) in bdds.unwrap_or_default() {
+            store.__setitem__(name, &bdd)?;
+        }
+        Ok(store)
+    }
+
+    fn __len__(&self) -> usize {
+        self.roots.len()
+    }
+
+    fn __contains__(&self, name: &str) -> bool {
+        self.roots.contains_key(name)
+    }
+
+    fn __getitem__(&self, name: &str) -> PyResult<Bdd> {
+        let Some(root) = self.roots.get(name) else {
+            return throw_index_error(format!("Unknown `Bdd` name `{name}`."));
+        };
+        Ok(Bdd::new_raw_2(self.ctx.clone(), self.table.extract(*root)))
+    }
+
+    fn __setitem__(&mut self, name: String, bdd: &Bdd) -> PyResult<()> {
+        if !self.is_compatible(&bdd.__ctx__()) {
+            return throw_runtime_error("The `Bdd` is not compatible with this `BddStore`.");
+        }
+        let root = self.table.insert(bdd.as_native());
+        if self.roots.insert(name, root).is_some() {
+            // The old value may have left some unused nodes in the table.
+            self.stale += 1;
+            self.compact_if_stale();
+        }
+        Ok(())
+    }
+
+    fn __delitem__(&mut self, name: &str) -> PyResult<()> {
+        if self.roots.remove(name).is_none() {
+            return throw_index_error(format!("Unknown `Bdd` name `{name}`."));
+        }
+        self.stale += 1;
+        self.compact_if_stale();
+        Ok(())
+    }
+
+    fn __str__(&self) -> String {
+        format!(
+            "BddStore(len = {}, nodes = {})",
+            self.roots.len(),
+            self.node_count()
+        )
+    }
+
+    fn __getnewargs__(&self) -> (Py<BddVariableSet>,) {
+        (self.ctx.clone(),)
+    }
+
+    fn __getstate__<'a>(&self, py: Python<'a>) -> PyResult<Bound<'a, PyBytes>> {
+        self.to_bytes(py)
+    }
+
+    fn __setstate__(&mut self, state: &[u8]) -> PyResult<()> {
+        let store = Self::from_bytes(self.ctx.clone(), state)?;
+        self.table = store.table;
+        self.roots = store.roots;
+        self.stale = 0;
+        Ok(())
+    }
+
+    pub fn __ctx__(&self) -> Py<BddVariableSet> {
+        self.ctx.clone()
+    }
+
+    /// The sorted list of names of all `Bdd` objects in this store.
+    fn names(&self) -> Vec<String> {
+        self.roots.keys().cloned().collect()
+    }
+
+    /// The total number of unique nodes in this store (including the two terminal nodes).
+    fn node_count(&self) -> usize {
+        if self.stale == 0 {
+            self.table.node_count()
+        } else {
+            self.table.reachable_count(&self.root_list())
+        }
+    }
+
+    /// The total number of nodes that would be needed if every `Bdd` in this store
+    /// was stored separately (i.e., the sum of `Bdd.node_count` over all stored values).
+    fn unshared_node_count(&self) -> usize {
+        self.roots
+            .values()
+            .map(|root| self.table.bdd_size(*root))
+            .sum()
+    }
+
+    /// Extract all `Bdd` objects from this store into a dictionary.
+    fn to_dict(&self) -> HashMap<String, Bdd> {
+        self.roots
+            .iter()
+            .map(|(name, root)| {
+                let bdd = Bdd::new_raw_2(self.ctx.clone(), self.table.extract(*root));
+                (name.clone(), bdd)
+            })
+            .collect()
+    }
+
+    /// Serialize the whole store into a compact binary format (nodes shared by multiple
+    /// `Bdd` objects are only written once). Use `BddStore.from_bytes` to read the result.
+    fn to_bytes<'a>(&self, py: Python<'a>) -> PyResult<Bound<'a, PyBytes>> {
+        // Unused nodes are never written, even if the store is not compacted yet.
+        let (table, roots) = if self.stale == 0 {
+            (self.table.clone(), self.root_list())
+        } else {
+            self.table.compact(&self.root_list())
+        };
+        let roots = self.roots.keys().cloned().zip(roots).collect::<Vec<_>>();
+        let mut buffer = Vec::new();
+        let mut write = || -> std::io::Result<()> {
+            let names = self.ctx.get().variable_names();
+            write_varint(&mut buffer, names.len() as u64)?;
+            for name in &names {
+                write_string(&mut buffer, name)?;
+            }
+            table.write(&mut buffer, &roots)
+        };
+        if let Err(e) = write() {
+            return throw_runtime_error(format!("Cannot write `BddStore`: {e}"));
+        }
+        Ok(PyBytes::new(py, &buffer))
+    }
+
+    /// Read a `BddStore` that was serialized using `BddStore.to_bytes`.
+    ///
+    /// The `ctx` must use the same variables as the original store.
+    #[staticmethod]
+    fn from_bytes(ctx: Py<BddVariableSet>, data: &[u8]) -> PyResult<BddStore> {
+        let mut input = Cursor::new(data);
+        let read = |input: &mut Cursor<&[u8]>| -> Result<_, String> {
+            let count = read_varint(input)? as usize;
+            let mut names = Vec::with_capacity(count.min(1 << 16));
+            for _ in 0..count {
+                names.push(read_string(input)?);
+            }
+            let (table, roots) = SharedBddTable::read(input)?;
+            Ok((names, table, roots))
+        };
+        let (names, table, roots) = match read(&mut input) {
+            Ok(result) => result,
+            Err(e) => return throw_runtime_error(format!("Cannot read `BddStore`: {e}")),
+        };
+        if names != ctx.get().variable_names()
+            || table.num_vars() != ctx.get().as_native().num_vars()
+        {
+            return throw_runtime_error("The data is not compatible with the given context.");
+        }
+        Ok(BddStore {
+            ctx,
+            table,
+            roots: roots.into_iter().collect(),
+            stale: 0,
+        })
+    }
+}
+
+impl BddStore {
+    /// Remove all nodes that are not reachable from any of the stored roots, assuming enough
+    /// roots were removed or replaced since the last compaction.
+    ///
+    /// The compaction requires a full pass over the table, hence it is only performed once the
+    /// number of stale roots exceeds the number of live roots (i.e., its cost is amortized
+    /// over the preceding updates).
+    fn compact_if_stale(&mut self) {
+        if self.stale <= self.roots.len() {
+            return;
+        }
+        let (table, roots) = self.table.compact(&self.root_list());
+        self.table = table;
+        for (root, new_root) in self.roots.values_mut().zip(roots) {
+            *root = new_root;
+        }
+        self.stale = 0;
+    }
+
+    /// The root indices of all stored values (in the order of their names).
+    fn root_list(&self) -> Vec<usize> {
+        self.roots.values().copied().collect()
+    }
+
+    /// Check that the given context uses the same variables as this store.
+    fn is_compatible(&self, ctx: &Py<BddVariableSet>) -> bool {
+        self.ctx.as_ptr() == ctx.as_ptr()
+            || self.ctx.get().variable_names() == ctx.get().variable_names()
+    }
+}
@@ -1,14 +1,20 @@
+use crate::bindings::lib_bdd::bdd::Bdd;
+use crate::bindings::lib_bdd::bdd_variable_set::BddVariableSet;
 use crate::bindings::lib_param_bn::argument_types::bool_type::BoolType;
+use crate::internal::expression_syntax::ExpressionSyntax;
 use crate::pyo3_utils::richcmp_eq_by_key;
-use crate::{throw_runtime_error, throw_type_error};
-use RsBooleanExpression::{Not, Variable};
+use crate::{AsNative, throw_runtime_error, throw_type_error};
+use RsBooleanExpression::{Const, Not, Variable};
 use biodivine_lib_bdd::boolean_expression::BooleanExpression as RsBooleanExpression;
 use biodivine_lib_bdd::boolean_expression::BooleanExpression::{And, Cond, Iff, Imp, Or, Xor};
+use biodivine_lib_bdd::{
+    BddPartialValuation as RsBddPartialValuation, BddVariableSet as RsBddVariableSet,
+};
 use pyo3::basic::CompareOp;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyTuple};
-use std::collections::HashSet;
 use std::collections::hash_map::DefaultHasher;
+use std::collections::{BTreeSet, HashMap, HashSet};
 use std::hash::{Hash, Hasher};
 use std::sync::Arc;
 /*
@@ -97,6 +103,58 @@ impl BooleanExpression {
         }
     }
 
+    /// Parse a `BooleanExpression` written in one of the supported syntaxes:
+    ///
+    ///  - `aeon` (default): the native syntax (`true`, `false`, `!`, `&`, `|`, `^`, `=>`,
+    ///    `<=>`, `a ? b : c`).
+    ///  - `python`: `True`, `False`, `not`, `and`, `or`, `!=` (xor), `==` (iff).
+    ///  - `c`: `true`/`1`, `false`/`0`, `!`, `&&`, `||`, `^` or `!=` (xor), `==` (iff),
+    ///    `a ? b : c`.
+    ///  - `sympy`: `True`, `False`, `~`, `&`, `|`, `^`, `Implies(a, b)`, `Equivalent(a, b)`,
+    ///    `ITE(a, b, c)`, as well as `Not`, `And`, `Or` and `Xor` function calls.
+    ///  - `bnet`: `1`/`true`, `0`/`false`, `!`, `&`, `|`.
+    ///
+    /// Operator precedence follows the respective language (e.g., `not a == b` is
+    /// `not (a == b)` in Python). Comparison operators cannot be chained without parentheses.
+    ///
+    /// ```python
+    /// expected = BooleanExpression("(a & !b) | c")
+    /// assert BooleanExpression.parse("a and not b or c", "python") == expected
+    /// assert BooleanExpression.parse("a && !b || c", "c") == expected
+    /// ```
+    #[staticmethod]
+    #[pyo3(signature = (value, syntax = "aeon"))]
+    pub fn parse(value: &str, syntax: &str) -> PyResult<BooleanExpression> {
+        match resolve_syntax(syntax)?.parse(value) {
+            Ok(expression) => Ok(BooleanExpression::from_native(expression)),
+            Err(message) => throw_runtime_error(format!("Invalid expression: \"{message}\".")),
+        }
+    }
+
+    /// Print this `BooleanExpression` using one of the syntaxes supported by
+    /// `BooleanExpression.parse`.
+    ///
+    /// Operators that do not exist in the target syntax are rewritten into equivalent
+    /// expressions (e.g., `a => b` is printed as `not a or b` in Python). Note that such
+    /// rewriting can duplicate sub-expressions.
+    ///
+    /// By default, every binary operator is enclosed in parentheses (like in
+    /// `BooleanExpression.__str__`). With `minimal_parentheses=True`, only the parentheses
+    /// required by the operator precedence of the target syntax are used (in the native
+    /// syntax, different binary operators are still always separated by parentheses).
+    /// If `line_width` is specified, the output is split into lines of at most `line_width`
+    /// characters (where possible). Such output can still be read by `BooleanExpression.parse`.
+    #[pyo3(signature = (syntax = "aeon", minimal_parentheses = false, line_width = None))]
+    pub fn to_string(
+        &self,
+        syntax: &str,
+        minimal_parentheses: bool,
+        line_width: Option<usize>,
+    ) -> PyResult<String> {
+        let syntax = resolve_syntax(syntax)?;
+        Ok(syntax.print(self.as_native(), minimal_parentheses, line_width))
+    }
+
     /// Return a `BooleanExpression` of a constant value.
     #[staticmethod]
     pub fn mk_const(value: BoolType) -> PyResult<BooleanExpression> {
@@ -407,6 +465,112 @@ impl BooleanExpression {
     pub fn support_set(&self) -> HashSet<String> {
         self.as_native().support_set()
     }
+
+    /// Build a `Bdd` representation of this `BooleanExpression`.
+    ///
+    /// If `variable_set` is not given, the method creates a new `BddVariableSet` which
+    /// consists of the `BooleanExpression.support_set` variables (sorted alphabetically).
+    ///
+    /// ```python
+    /// bdd = BooleanExpression("a & (b | !a)").to_bdd()
+    /// assert bdd.__ctx__().variable_names() == ["a", "b"]
+    /// assert bdd.cardinality() == 1
+    /// ```
+    #[pyo3(signature = (variable_set = None))]
+    pub fn to_bdd(&self, py: Python, variable_set: Option<Py<BddVariableSet>>) -> PyResult<Bdd> {
+        let ctx = match variable_set {
+            Some(ctx) => ctx,
+            None => {
+                let ctx = support_variable_set(&[self.as_native()]);
+                Py::new(py, BddVariableSet::from(ctx))?
+            }
+        };
+        let Some(bdd) = ctx.get().as_native().safe_eval_expression(self.as_native()) else {
+            return throw_runtime_error("Expression contains unknown variables.");
+        };
+        Ok(Bdd::new_raw_2(ctx, bdd))
+    }
+
+    /// True if this `BooleanExpression` represents the same Boolean function as `other`
+    /// (i.e., the two expressions are satisfied by the same valuations).
+    ///
+    /// ```python
+    /// assert BooleanExpression("a => b").is_equivalent("!a | b")
+    /// assert not BooleanExpression("a => b").is_equivalent("b => a")
+    /// ```
+    pub fn is_equivalent(&self, other: &Bound<'_, PyAny>) -> PyResult<bool> {
+        let other = Self::resolve_expression(other)?;
+        let ctx = support_variable_set(&[self.as_native(), other.as_native()]);
+        Ok(ctx.eval_expression(self.as_native()) == ctx.eval_expression(other.as_native()))
+    }
+
+    /// Replace every occurrence of the variable `var` with the given `expression`.
+    ///
+    /// ```python
+    /// expr = BooleanExpression("a & b").substitute("b", "c | !a")
+    /// assert expr == BooleanExpression("a & (c | !a)")
+    /// ```
+    pub fn substitute(
+        &self,
+        var: &str,
+        expression: &Bound<'_, PyAny>,
+    ) -> PyResult<BooleanExpression> {
+        let expression = Self::resolve_expression(expression)?;
+        let substitution = HashMap::from([(var.to_string(), expression.as_native().clone())]);
+        Ok(Self::from_native(substitute(
+            self.as_native(),
+            &substitution,
+        )))
+    }
+
+    /// Evaluate this `BooleanExpression` under a (possibly partial) `valuation`.
+    ///
+    /// Every variable in `valuation` is replaced with the corresponding constant and
+    /// the result is simplified by propagating these constants. Unlike
+    /// `BooleanExpression.__call__`, the valuation does not have to cover all variables
+    /// of the expression. If it does, the result is a constant expression.
+    ///
+    /// ```python
+    /// expr = BooleanExpression("(a & b) | (c ^ a)")
+    /// assert expr.evaluate({ "a": True }) == BooleanExpression("b | !c")
+    /// assert expr.evaluate({ "a": False, "c": True }).as_const()
+    /// ```
+    pub fn evaluate(&self, valuation: &Bound<'_, PyDict>) -> PyResult<BooleanExpression> {
+        let mut substitution = HashMap::new();
+        for (key, value) in valuation {
+            let value = value.extract::<BoolType>()?;
+            substitution.insert(key.extract::<String>()?, Const(value.bool()));
+        }
+        let substituted = substitute(self.as_native(), &substitution);
+        Ok(Self::from_native(propagate_constants(&substituted)))
+    }
+
+    /// Compute a simplified `BooleanExpression` that is equivalent to this expression.
+    ///
+    /// The method first propagates constants and removes trivial redundancies (like double
+    /// negation or `a & a`). Then, it computes an optimized disjunctive normal form of
+    /// the expression using a `Bdd`. The result is the smaller of the two expressions.
+    ///
+    /// Note that the result is not guaranteed to be minimal, and that the DNF can be
+    /// exponentially large for some functions.
+    ///
+    /// ```python
+    /// assert BooleanExpression("a & (a | b)").simplify() == BooleanExpression("a")
+    /// assert BooleanExpression("(a & b) | (a & !b) | !a").simplify() == BooleanExpression("true")
+    /// ```
+    pub fn simplify(&self, py: Python) -> PyResult<BooleanExpression> {
+        let propagated = propagate_constants(self.as_native());
+        let ctx = support_variable_set(&[&propagated]);
+        let dnf = ctx
+            .eval_expression(&propagated)
+            ._to_optimized_dnf(true, &|_| py.check_signals())?;
+        let dnf = dnf_to_expression(&ctx, &dnf);
+        if expression_size(&dnf) < expression_size(&propagated) {
+            Ok(Self::from_native(dnf))
+        } else {
+            Ok(Self::from_native(propagated))
+        }
+    }
 }
 
 impl BooleanExpression {
@@ -447,6 +611,147 @@ impl BooleanExpression {
     }
 }
 
+/// Create a `BddVariableSet` with all variables that appear in the given expressions (sorted).
+fn support_variable_set(expressions: &[&RsBooleanExpression]) -> RsBddVariableSet {
+    let names = expressions
+        .iter()
+        .flat_map(|it| it.support_set())
+        .collect::<BTreeSet<_>>();
+    let names = names.iter().map(|it| it.as_str()).collect::<Vec<_>>();
+    RsBddVariableSet::new(&names)
+}
+
+/// Replace variables in `e` with the expressions given in `substitution`.
+fn substitute(
+    e: &RsBooleanExpression,
+    substitution: &HashMap<String, RsBooleanExpression>,
+) -> RsBooleanExpression {
+    let rec = |inner: &RsBooleanExpression| Box::new(substitute(inner, substitution));
+    match e {
+        Const(_) => e.clone(),
+        Variable(name) => substitution.get(name).cloned().unwrap_or_else(|| e.clone()),
+        Not(inner) => Not(rec(inner)),
+        And(left, right) => And(rec(left), rec(right)),
+        Or(left, right) => Or(rec(left), rec(right)),
+        Xor(left, right) => Xor(rec(left), rec(right)),
+        Imp(left, right) => Imp(rec(left), rec(right)),
+        Iff(left, right) => Iff(rec(left), rec(right)),
+        Cond(test, branch1, branch2) => Cond(rec(test), rec(branch1), rec(branch2)),
+    }
+}
+
+/// Negate `e`, removing double negation and negated constants.
+fn negate(e: RsBooleanExpression) -> RsBooleanExpression {
+    match e {
+        Const(value) => Const(!value),
+        Not(inner) => *inner,
+        e => Not(Box::new(e)),
+    }
+}
+
+/// Simplify `e` by propagating constants and removing trivial redundancies.
+fn propagate_constants(e: &RsBooleanExpression) -> RsBooleanExpression {
+    match e {
+        Const(_) | Variable(_) => e.clone(),
+        Not(inner) => negate(propagate_constants(inner)),
+        And(left, right) => match (propagate_constants(left), propagate_constants(right)) {
+            (Const(false), _) | (_, Const(false)) => Const(false),
+            (Const(true), x) | (x, Const(true)) => x,
+            (left, right) if left == right => left,
+            (left, right) => And(Box::new(left), Box::new(right)),
+        },
+        Or(left, right) => match (propagate_constants(left), propagate_constants(right)) {
+            (Const(true), _) | (_, Const(true)) => Const(true),
+            (Const(false), x) | (x, Const(false)) => x,
+            (left, right) if left == right => left,
+            (left, right) => Or(Box::new(left), Box::new(right)),
+        },
+        Xor(left, right) => match (propagate_constants(left), propagate_constants(right)) {
+            (Const(value), x) | (x, Const(value)) => {
+                if value {
+                    negate(x)
+                } else {
+                    x
+                }
+            }
+            (left, right) if left == right => Const(false),
+            (left, right) => Xor(Box::new(left), Box::new(right)),
+        },
+        Imp(left, right) => match (propagate_constants(left), propagate_constants(right)) {
+            (Const(false), _) | (_, Const(true)) => Const(true),
+            (Const(true), x) => x,
+            (x, Const(false)) => negate(x),
+            (left, right) if left == right => Const(true),
+            (left, right) => Imp(Box::new(left), Box::new(right)),
+        },
+        Iff(left, right) => match (propagate_constants(left), propagate_constants(right)) {
+            (Const(value), x) | (x, Const(value)) => {
+                if value {
+                    x
+                } else {
+                    negate(x)
+                }
+            }
+            (left, right) if left == right => Const(true),
+            (left, right) => Iff(Box::new(left), Box::new(right)),
+        },
+        Cond(test, branch1, branch2) => match propagate_constants(test) {
+            Const(true) => propagate_constants(branch1),
+            Const(false) => propagate_constants(branch2),
+            test => {
+                let branch1 = propagate_constants(branch1);
+                let branch2 = propagate_constants(branch2);
+                if branch1 == branch2 {
+                    branch1
+                } else {
+                    Cond(Box::new(test), Box::new(branch1), Box::new(branch2))
+                }
+            }
+        },
+    }
+}
+
+/// Convert a list of conjunctive clauses into a disjunction of conjunctions.
+fn dnf_to_expression(ctx: &RsBddVariableSet, dnf: &[RsBddPartialValuation]) -> RsBooleanExpression {
+    let clauses = dnf.iter().map(|clause| {
+        clause
+            .to_values()
+            .into_iter()
+            .map(|(var, value)| {
+                let literal = Variable(ctx.name_of(var));
+                if value { literal } else { negate(literal) }
+            })
+            .reduce(|left, right| And(Box::new(left), Box::new(right)))
+            .unwrap_or(Const(true))
+    });
+    clauses
+        .reduce(|left, right| Or(Box::new(left), Box::new(right)))
+        .unwrap_or(Const(false))
+}
+
+/// The number of operators, variables and constants in `e`.
+fn expression_size(e: &RsBooleanExpression) -> usize {
+    match e {
+        Const(_) | Variable(_) => 1,
+        Not(inner) => 1 + expression_size(inner),
+        And(left, right)
+        | Or(left, right)
+        | Xor(left, right)
+        | Imp(left, right)
+        | Iff(left, right) => 1 + expression_size(left) + expression_size(right),
+        Cond(test, branch1, branch2) => {
+            1 + expression_size(test) + expression_size(branch1) + expression_size(branch2)
+        }
+    }
+}
+
+fn resolve_syntax(syntax: &str) -> PyResult<ExpressionSyntax> {
+    match ExpressionSyntax::try_from(syntax) {
+        Ok(syntax) => Ok(syntax),
+        Err(message) => throw_runtime_error(message),
+    }
+}
+
 fn eval(e: &RsBooleanExpression, valuation: &Bound<'_, PyDict>) -> PyResult<bool> {
     match e {
         RsBooleanExpression::Const(x) => Ok(*x),
@@ -3,6 +3,7 @@ use pyo3::{Bound, PyResult};
 
 pub mod bdd;
 pub mod bdd_pointer;
+pub mod bdd_store;
 pub mod bdd_valuation;
 pub mod bdd_variable;
 pub mod bdd_variable_set;
@@ -16,6 +17,7 @@ pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add_class::<bdd::UniformValuationSampler>()?;
     module.add_class::<bdd::_BddValuationIterator>()?;
     module.add_class::<bdd::_BddClauseIterator>()?;
+    module.add_class::<bdd_store::BddStore>()?;
     module.add_class::<boolean_expression::BooleanExpression>()?;
     module.add_class::<bdd_variable::BddVariable>()?;
     module.add_class::<bdd_pointer::BddPointer>()?;
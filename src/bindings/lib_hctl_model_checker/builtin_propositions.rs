@@ -0,0 +1,321 @@
+use std::collections::{HashMap, HashSet};
+
+use biodivine_hctl_model_checker::preprocessing::hctl_tree::{HctlTreeNode, NodeType};
+use biodivine_hctl_model_checker::preprocessing::operator_enums::Atomic;
+use biodivine_hctl_model_checker::preprocessing::parser::parse_extended_formula;
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
+use biodivine_lib_param_bn::symbolic_async_graph::{GraphColoredVertices, SymbolicAsyncGraph};
+use pyo3::{Py, PyResult, Python};
+
+use crate::bindings::algorithms::attractors::Attractors;
+use crate::bindings::algorithms::token_python::CancelTokenPython;
+use crate::bindings::lib_hctl_model_checker::model_checking::graph_network;
+use crate::bindings::lib_param_bn::symbolic::asynchronous_graph::AsynchronousGraph;
+use crate::internal::algorithms::configurable::{Config as _, Configurable as _};
+use crate::internal::algorithms::trap_spaces::{TrapSpaces, TrapSpacesConfig};
+use crate::{AsNative, throw_runtime_error};
+
+/// The HCTL parser only allows alphanumeric characters and `_` in proposition names, hence
+/// the names are encoded before a formula is given to the parser (and decoded afterwards):
+/// `%name:argument%` becomes `%__name__argument%`, names that already start with `__` receive
+/// another `__` prefix, and all other names are unchanged. The encoding is injective,
+/// so a parametrized built-in proposition can never clash with a user proposition.
+const ESCAPE: &str = "__";
+
+/// Encode a single proposition name such that it is accepted by the HCTL parser
+/// (see `ESCAPE`). Names with an invalid argument are left unchanged, such that the parser
+/// reports them.
+pub fn encode_proposition_name(name: &str) -> String {
+    if let Some((name, argument)) = name.split_once(':')
+        && is_identifier(name)
+        && is_identifier(argument)
+        && name.starts_with(|c: char| c.is_alphanumeric())
+        && !name.ends_with('_')
+        && !name.contains(ESCAPE)
+    {
+        format!("{ESCAPE}{name}{ESCAPE}{argument}")
+    } else if name.starts_with(ESCAPE) {
+        format!("{ESCAPE}{name}")
+    } else {
+        name.to_string()
+    }
+}
+
+/// Inverse of [encode_proposition_name].
+pub fn decode_proposition_name(name: &str) -> String {
+    let Some(rest) = name.strip_prefix(ESCAPE) else {
+        return name.to_string();
+    };
+    if rest.starts_with(ESCAPE) {
+        return rest.to_string();
+    }
+    match rest.split_once(ESCAPE) {
+        Some((name, argument)) => format!("{name}:{argument}"),
+        None => name.to_string(),
+    }
+}
+
+/// Encode all extended propositions and quantifier domains (`%name%`) of the formula string
+/// such that it is accepted by the HCTL parser (see [encode_proposition_name]).
+pub fn encode_builtin_propositions(formula: &str) -> String {
+    let mut result = String::with_capacity(formula.len());
+    let mut rest = formula;
+    while let Some(start) = rest.find('%') {
+        result.push_str(&rest[..=start]);
+        rest = &rest[(start + 1)..];
+        let Some(end) = rest.find('%') else {
+            break;
+        };
+        result.push_str(&encode_proposition_name(&rest[..end]));
+        result.push('%');
+        rest = &rest[(end + 1)..];
+    }
+    result.push_str(rest);
+    result
+}
+
+/// Decode all extended propositions and quantifier domains of a formula that was parsed
+/// from a string produced by [encode_builtin_propositions].
+pub fn decode_builtin_propositions(formula: HctlTreeNode) -> HctlTreeNode {
+    match formula.node_type {
+        NodeType::Terminal(Atomic::WildCardProp(name)) => {
+            HctlTreeNode::mk_wild_card(decode_proposition_name(&name).as_str())
+        }
+        NodeType::Terminal(_) => formula,
+        NodeType::Unary(op, a) => HctlTreeNode::mk_unary(decode_builtin_propositions(*a), op),
+        NodeType::Binary(op, a, b) => HctlTreeNode::mk_binary(
+            decode_builtin_propositions(*a),
+            decode_builtin_propositions(*b),
+            op,
+        ),
+        NodeType::Hybrid(op, var, domain, a) => HctlTreeNode::mk_hybrid(
+            decode_builtin_propositions(*a),
+            var.as_str(),
+            domain.map(|it| decode_proposition_name(&it)),
+            op,
+        ),
+    }
+}
+
+/// Parse an extended HCTL formula that can contain parametrized built-in propositions
+/// (e.g. `%basin:1%`).
+pub fn parse_formula_with_builtins(formula: &str) -> Result<HctlTreeNode, String> {
+    let formula = parse_extended_formula(&encode_builtin_propositions(formula))?;
+    Ok(decode_builtin_propositions(formula))
+}
+
+/// Check if the value is a valid name of a variable or proposition.
+pub fn is_identifier(value: &str) -> bool {
+    !value.is_empty() && value.chars().all(|c| c.is_alphanumeric() || c == '_')
+}
+
+/// Check if the proposition name refers to one of the built-in propositions
+/// (see `resolve_builtin_propositions`).
+pub fn is_builtin_proposition(name: &str) -> bool {
+    match name.split_once(':') {
+        None => matches!(name, "attractor" | "fixed_point"),
+        Some(("trap_space", kind)) => matches!(kind, "minimal" | "essential"),
+        Some(("basin", index)) => index.parse::<usize>().is_ok(),
+        Some(_) => false,
+    }
+}
+
+/// Names of all extended propositions and quantifier domains used in the formula.
+pub fn collect_wild_cards(formula: &HctlTreeNode) -> HashSet<String> {
+    match &formula.node_type {
+        NodeType::Terminal(Atomic::WildCardProp(name)) => HashSet::from([name.clone()]),
+        NodeType::Terminal(_) => HashSet::new(),
+        NodeType::Unary(_, a) => collect_wild_cards(a),
+        NodeType::Binary(_, a, b) => {
+            let mut result = collect_wild_cards(a);
+            result.extend(collect_wild_cards(b));
+            result
+        }
+        NodeType::Hybrid(_, _, domain, a) => {
+            let mut result = collect_wild_cards(a);
+            result.extend(domain.iter().cloned());
+            result
+        }
+    }
+}
+
+/// Add the values of all built-in propositions that appear in the given `formulas`
+/// (but are not already present in the `substitution`) into the `substitution` map.
+///
+/// The supported propositions are `attractor`, `fixed_point`, `trap_space:minimal`,
+/// `trap_space:essential`, and `basin:i`. Formulas that cannot be parsed are skipped, since
+/// the model checker reports the error later anyway.
+pub fn resolve_builtin_propositions(
+    py: Python,
+    graph: &AsynchronousGraph,
+    formulas: &[String],
+    substitution: &mut HashMap<String, GraphColoredVertices>,
+) -> PyResult<()> {
+    let mut names = formulas
+        .iter()
+        .filter_map(|it| parse_formula_with_builtins(it).ok())
+        .flat_map(|it| collect_wild_cards(&it))
+        .filter(|it| !substitution.contains_key(it))
+        .collect::<Vec<_>>();
+    names.sort();
+    names.dedup();
+
+    let mut provider = BuiltinPropositions::new(graph);
+    for name in names {
+        if let Some(value) = provider.resolve(py, &name)? {
+            substitution.insert(name, value);
+        }
+    }
+    Ok(())
+}
+
+/// Computes the values of built-in propositions on demand, such that the potentially
+/// expensive intermediate results (e.g. attractors) are computed at most once.
+struct BuiltinPropositions<'a> {
+    graph: &'a AsynchronousGraph,
+    default_graph: Option<Py<AsynchronousGraph>>,
+    attractors: Option<Vec<GraphColoredVertices>>,
+}
+
+impl<'a> BuiltinPropositions<'a> {
+    fn new(graph: &'a AsynchronousGraph) -> BuiltinPropositions<'a> {
+        BuiltinPropositions {
+            graph,
+            default_graph: None,
+            attractors: None,
+        }
+    }
+
+    /// Returns `None` if the given name is not a built-in proposition.
+    fn resolve(&mut self, py: Python, name: &str) -> PyResult<Option<GraphColoredVertices>> {
+        let (name, argument) = match name.split_once(':') {
+            Some((name, argument)) => (name, Some(argument)),
+            None => (name, None),
+        };
+        let value = match (name, argument) {
+            ("attractor", None) => {
+                let empty = self.graph.as_native().mk_empty_colored_vertices();
+                self.attractors(py)?.iter().fold(empty, |a, b| a.union(b))
+            }
+            ("fixed_point", None) => {
+                // Fixed points are the states without successors in the (possibly
+                // restricted) model checking graph.
+                let stg = self.graph.as_native();
+                let unit = stg.unit_colored_vertices();
+                unit.minus(&stg.can_post(unit))
+            }
+            ("trap_space", Some(kind)) => self.trap_spaces(py, kind)?,
+            ("basin", Some(index)) => {
+                let attractors = self.attractors(py)?;
+                let attractor = index.parse::<usize>().ok().and_then(|i| attractors.get(i));
+                let Some(attractor) = attractor else {
+                    return throw_runtime_error(format!(
+                        "Invalid attractor index `{index}` (found {} attractors).",
+                        attractors.len()
+                    ));
+                };
+                let attractor = attractor.clone();
+                self.basin(py, &attractor)?
+            }
+            ("attractor" | "fixed_point", Some(_)) | ("trap_space" | "basin", None) => {
+                return throw_runtime_error(format!(
+                    "Invalid arguments of built-in proposition `{name}`."
+                ));
+            }
+            _ => return Ok(None),
+        };
+        Ok(Some(value))
+    }
+
+    /// The model checking graph represented using the "default" `AsynchronousGraph` of its
+    /// network (i.e. without the extra symbolic variables), restricted to the same unit set.
+    fn default_graph(&mut self, py: Python) -> PyResult<Py<AsynchronousGraph>> {
+        if let Some(graph) = &self.default_graph {
+            return Ok(graph.clone());
+        }
+        let network = graph_network(py, self.graph)?;
+        let graph = AsynchronousGraph::new(py, network, None, None)?;
+        let unit = self.transfer_unit(graph.as_native())?;
+        let graph = Py::new(py, graph.restrict_native(&unit))?;
+        self.default_graph = Some(graph.clone());
+        Ok(graph)
+    }
+
+    fn attractors(&mut self, py: Python) -> PyResult<&Vec<GraphColoredVertices>> {
+        if self.attractors.is_none() {
+            let default_graph = self.default_graph(py)?;
+            let attractors = Attractors::attractors(default_graph.clone().into(), None, None, py)?
+                .iter()
+                .map(|it| self.transfer(it.as_native(), default_graph.get().as_native()))
+                .collect::<PyResult<Vec<_>>>()?;
+            self.attractors = Some(attractors);
+        }
+        Ok(self.attractors.as_ref().unwrap())
+    }
+
+    fn trap_spaces(&self, py: Python, kind: &str) -> PyResult<GraphColoredVertices> {
+        let network = graph_network(py, self.graph)?;
+        let mut config = TrapSpacesConfig::try_from(network.borrow(py).as_native())?;
+        let unit = self.transfer_unit(&config.graph)?;
+        config.graph = config.graph.restrict(&unit);
+        config.restriction = config.ctx.mk_unit_colored_spaces(&config.graph);
+        let config = config.with_cancellation(CancelTokenPython::default());
+        let space_graph = config.graph.clone();
+        let space_ctx = config.ctx.clone();
+        let trap_spaces = TrapSpaces::with_config(config);
+        let spaces = match kind {
+            "minimal" => trap_spaces.minimal_symbolic()?,
+            "essential" => trap_spaces.essential_symbolic()?,
+            _ => {
+                return throw_runtime_error(format!(
+                    "Unknown trap space kind `{kind}`. Expected `minimal` or `essential`."
+                ));
+            }
+        };
+        self.transfer(&spaces.to_colored_vertices(&space_ctx), &space_graph)
+    }
+
+    /// All states of the model checking graph that can reach the given `attractor`.
+    fn basin(
+        &self,
+        py: Python,
+        attractor: &GraphColoredVertices,
+    ) -> PyResult<GraphColoredVertices> {
+        let stg = self.graph.as_native();
+        let mut result = attractor.clone();
+        loop {
+            py.check_signals()?;
+            let new = stg.pre(&result).minus(&result);
+            if new.is_empty() {
+                return Ok(result);
+            }
+            result = result.union(&new);
+        }
+    }
+
+    /// Translate the unit set of the model checking graph into the encoding of
+    /// the `target` graph.
+    fn transfer_unit(&self, target: &SymbolicAsyncGraph) -> PyResult<GraphColoredVertices> {
+        let stg = self.graph.as_native();
+        let ctx = stg.symbolic_context();
+        let unit = stg.unit_colored_vertices().as_bdd();
+        let unit = GraphColoredVertices::new(unit.exists(ctx.all_extra_state_variables()), ctx);
+        match target.transfer_from(&unit, stg) {
+            Some(unit) => Ok(unit.intersect(target.unit_colored_vertices())),
+            None => throw_runtime_error("The two contexts are not compatible."),
+        }
+    }
+
+    /// Translate a set from the `source` graph into the encoding of the model checking graph.
+    fn transfer(
+        &self,
+        set: &GraphColoredVertices,
+        source: &SymbolicAsyncGraph,
+    ) -> PyResult<GraphColoredVertices> {
+        let stg = self.graph.as_native();
+        match stg.transfer_from(set, source) {
+            Some(set) => Ok(set.intersect(stg.unit_colored_vertices())),
+            None => throw_runtime_error("The two contexts are not compatible."),
+        }
+    }
+}
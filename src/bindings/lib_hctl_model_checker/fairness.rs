@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+
+use biodivine_hctl_model_checker::preprocessing::hctl_tree::{HctlTreeNode, NodeType};
+use biodivine_hctl_model_checker::preprocessing::operator_enums::{BinaryOp, UnaryOp};
+use biodivine_lib_param_bn::VariableId;
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
+use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
+use pyo3::{FromPyObject, PyResult, Python};
+
+use crate::bindings::lib_hctl_model_checker::builtin_propositions::parse_formula_with_builtins;
+use crate::bindings::lib_hctl_model_checker::model_checking::{check_formulae, pre_total};
+use crate::bindings::lib_param_bn::argument_types::variable_id_type::VariableIdType;
+use crate::bindings::lib_param_bn::symbolic::asynchronous_graph::AsynchronousGraph;
+use crate::bindings::lib_param_bn::symbolic::set_colored_vertex::ColoredVertexSet;
+use crate::bindings::lib_param_bn::variable_id::VariableIdResolvable;
+use crate::{AsNative, throw_runtime_error};
+
+/// Internal helper struct which corresponds to the `FairnessConfig` typed dictionary.
+#[derive(FromPyObject)]
+pub struct PyFairnessConfig {
+    #[pyo3(item, default = None)]
+    pub weak: Option<Vec<VariableIdType>>,
+    #[pyo3(item, default = None)]
+    pub strong: Option<Vec<VariableIdType>>,
+    #[pyo3(item, default = None)]
+    pub infinitely_often: Option<Vec<ColoredVertexSet>>,
+}
+
+/// A single fairness assumption about the infinite paths of an `AsynchronousGraph`.
+enum FairnessRequirement {
+    /// If the variable can be updated continuously, it is eventually updated.
+    Weak(VariableId),
+    /// If the variable can be updated infinitely often, it is updated infinitely often.
+    Strong(VariableId),
+    /// The path visits the given set of states infinitely often.
+    States(GraphColoredVertices),
+}
+
+/// Evaluates CTL formulas where path quantifiers only range over fair paths.
+///
+/// Sub-formulas without temporal operators (including extended propositions) are evaluated
+/// using the HCTL model checker. Temporal operators nested inside hybrid operators are
+/// not supported.
+pub struct FairModelChecker<'a> {
+    graph: &'a AsynchronousGraph,
+    substitution: Option<&'a HashMap<String, GraphColoredVertices>>,
+    requirements: Vec<FairnessRequirement>,
+    unit: GraphColoredVertices,
+    /// States from which at least one fair path exists.
+    fair: GraphColoredVertices,
+}
+
+impl<'a> FairModelChecker<'a> {
+    pub fn new(
+        py: Python,
+        graph: &'a AsynchronousGraph,
+        config: &PyFairnessConfig,
+        substitution: Option<&'a HashMap<String, GraphColoredVertices>>,
+    ) -> PyResult<FairModelChecker<'a>> {
+        let stg = graph.as_native();
+        let mut requirements = Vec::new();
+        for var in config.weak.clone().unwrap_or_default() {
+            requirements.push(FairnessRequirement::Weak(var.resolve(stg)?));
+        }
+        for var in config.strong.clone().unwrap_or_default() {
+            requirements.push(FairnessRequirement::Strong(var.resolve(stg)?));
+        }
+        for set in config.infinitely_often.iter().flatten() {
+            requirements.push(FairnessRequirement::States(set.as_native().clone()));
+        }
+        let unit = stg.unit_colored_vertices().clone();
+        let mut checker = FairModelChecker {
+            graph,
+            substitution,
+            requirements,
+            fair: unit.clone(),
+            unit,
+        };
+        checker.fair = checker.fair_globally(py, &checker.unit)?;
+        Ok(checker)
+    }
+
+    /// Parse and evaluate a single formula.
+    pub fn check_string(&self, py: Python, formula: &str) -> PyResult<GraphColoredVertices> {
+        match parse_formula_with_builtins(formula) {
+            Ok(formula) => self.check(py, &formula),
+            Err(e) => throw_runtime_error(e),
+        }
+    }
+
+    /// Evaluate the given formula, assuming path quantifiers only consider fair paths.
+    pub fn check(&self, py: Python, formula: &HctlTreeNode) -> PyResult<GraphColoredVertices> {
+        if !has_temporal_operator(formula) {
+            let result = check_formulae(self.graph, &[formula.to_string()], self.substitution)?;
+            return Ok(result.into_iter().next().unwrap());
+        }
+        let not = |set: &GraphColoredVertices| self.unit.minus(set);
+        match &formula.node_type {
+            NodeType::Unary(op, a) => {
+                let a = self.check(py, a)?;
+                match op {
+                    UnaryOp::Not => Ok(not(&a)),
+                    UnaryOp::EX => Ok(self.exist_next(&a)),
+                    UnaryOp::AX => Ok(not(&self.exist_next(&not(&a)))),
+                    UnaryOp::EF => self.exist_until(py, &self.unit, &a),
+                    UnaryOp::AF => Ok(not(&self.fair_globally(py, &not(&a))?)),
+                    UnaryOp::EG => self.fair_globally(py, &a),
+                    UnaryOp::AG => Ok(not(&self.exist_until(py, &self.unit, &not(&a))?)),
+                }
+            }
+            NodeType::Binary(op, a, b) => {
+                let a = self.check(py, a)?;
+                let b = self.check(py, b)?;
+                match op {
+                    BinaryOp::And => Ok(a.intersect(&b)),
+                    BinaryOp::Or => Ok(a.union(&b)),
+                    BinaryOp::Imp => Ok(not(&a).union(&b)),
+                    BinaryOp::Iff => Ok(a.intersect(&b).union(&not(&a.union(&b)))),
+                    BinaryOp::Xor => Ok(a.minus(&b).union(&b.minus(&a))),
+                    BinaryOp::EU => self.exist_until(py, &a, &b),
+                    BinaryOp::EW => {
+                        let until = self.exist_until(py, &a, &b)?;
+                        Ok(until.union(&self.fair_globally(py, &a)?))
+                    }
+                    BinaryOp::AU => {
+                        // !A[a U b] = E[!b U (!a & !b)] | EG !b
+                        let neither = not(&a.union(&b));
+                        let until = self.exist_until(py, &not(&b), &neither)?;
+                        let globally = self.fair_globally(py, &not(&b))?;
+                        Ok(not(&until.union(&globally)))
+                    }
+                    BinaryOp::AW => {
+                        // !A[a W b] = E[!b U (!a & !b)]
+                        let neither = not(&a.union(&b));
+                        Ok(not(&self.exist_until(py, &not(&b), &neither)?))
+                    }
+                }
+            }
+            _ => throw_runtime_error(
+                "Fairness constraints are not supported for temporal operators nested in hybrid operators.",
+            ),
+        }
+    }
+
+    /// States with a successor in `set` from which a fair path exists.
+    fn exist_next(&self, set: &GraphColoredVertices) -> GraphColoredVertices {
+        pre_total(self.graph.as_native(), &set.intersect(&self.fair))
+    }
+
+    /// States with a path through `allowed` into a state of `target` from which a fair
+    /// path exists.
+    fn exist_until(
+        &self,
+        py: Python,
+        allowed: &GraphColoredVertices,
+        target: &GraphColoredVertices,
+    ) -> PyResult<GraphColoredVertices> {
+        self.reach_backward(py, allowed, &target.intersect(&self.fair))
+    }
+
+    /// States with a fair path that stays in `set` forever.
+    ///
+    /// This is the fixed-point algorithm of Kesten, Pnueli and Raviv: it repeatedly removes
+    /// states that cannot satisfy some of the fairness requirements while staying in `set`,
+    /// and then computes everything that can reach the remaining states within `set`.
+    fn fair_globally(
+        &self,
+        py: Python,
+        set: &GraphColoredVertices,
+    ) -> PyResult<GraphColoredVertices> {
+        let stg = self.graph.as_native();
+        let mut core = set.clone();
+        loop {
+            py.check_signals()?;
+            let previous = core.clone();
+            for requirement in &self.requirements {
+                core = match requirement {
+                    FairnessRequirement::Weak(var) => {
+                        let disabled = core.minus(&stg.var_can_post(*var, &core));
+                        let updated = core.intersect(&stg.var_pre(*var, &core));
+                        self.reach_backward(py, &core, &disabled.union(&updated))?
+                    }
+                    FairnessRequirement::Strong(var) => {
+                        let disabled = core.minus(&stg.var_can_post(*var, &core));
+                        let updated = core.intersect(&stg.var_pre(*var, &core));
+                        disabled.union(&self.reach_backward(py, &core, &updated)?)
+                    }
+                    FairnessRequirement::States(states) => {
+                        self.reach_backward(py, &core, &core.intersect(states))?
+                    }
+                };
+            }
+            core = core.intersect(&pre_total(stg, &core));
+            if previous.is_subset(&core) {
+                break;
+            }
+        }
+        self.reach_backward(py, set, &core)
+    }
+
+    /// States that can reach `target` using a path that only visits `allowed` before `target`.
+    fn reach_backward(
+        &self,
+        py: Python,
+        allowed: &GraphColoredVertices,
+        target: &GraphColoredVertices,
+    ) -> PyResult<GraphColoredVertices> {
+        let stg = self.graph.as_native();
+        let mut result = target.clone();
+        loop {
+            py.check_signals()?;
+            let new = pre_total(stg, &result).intersect(allowed).minus(&result);
+            if new.is_empty() {
+                return Ok(result);
+            }
+            result = result.union(&new);
+        }
+    }
+}
+
+/// Check if the formula contains any temporal operator.
+fn has_temporal_operator(formula: &HctlTreeNode) -> bool {
+    match &formula.node_type {
+        NodeType::Terminal(_) => false,
+        NodeType::Unary(UnaryOp::Not, a) => has_temporal_operator(a),
+        NodeType::Unary(_, _) => true,
+        NodeType::Binary(BinaryOp::EU | BinaryOp::AU | BinaryOp::EW | BinaryOp::AW, _, _) => true,
+        NodeType::Binary(_, a, b) => has_temporal_operator(a) || has_temporal_operator(b),
+        NodeType::Hybrid(_, _, _, a) => has_temporal_operator(a),
+    }
+}
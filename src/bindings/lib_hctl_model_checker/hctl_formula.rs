@@ -1,7 +1,12 @@
+use crate::bindings::lib_hctl_model_checker::builtin_propositions::{
+    decode_builtin_propositions, encode_builtin_propositions, is_builtin_proposition,
+};
 use crate::bindings::lib_param_bn::symbolic::asynchronous_graph::AsynchronousGraph;
 use crate::bindings::lib_param_bn::symbolic::symbolic_context::SymbolicContext;
+use crate::internal::smv::formula_to_smv_ctl;
+use crate::internal::temporal_syntax::{expand_bounded_operators, mk_bounded_operator, parse_ltl};
 use crate::pyo3_utils::richcmp_eq_by_key;
-use crate::{AsNative, throw_runtime_error, throw_type_error};
+use crate::{AsNative, runtime_error, throw_runtime_error, throw_type_error};
 use biodivine_hctl_model_checker::mc_utils::{
     check_hctl_var_support, collect_unique_hctl_vars, collect_unique_wild_cards,
 };
@@ -15,9 +20,9 @@ use biodivine_hctl_model_checker::preprocessing::parser::{
 };
 use pyo3::basic::CompareOp;
 use pyo3::prelude::PyAnyMethods;
-use pyo3::types::{PyDict, PyTuple};
+use pyo3::types::{PyDict, PyDictMethods, PyTuple};
 use pyo3::{Bound, Py, PyAny, PyResult, Python, pyclass, pymethods};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::hash::{DefaultHasher, Hash, Hasher};
 use std::sync::Arc;
 
@@ -68,6 +73,11 @@ impl HctlFormula {
         } else {
             return throw_type_error("Expected `String` or `HctlFormula`.");
         };
+        let formula_string = encode_builtin_propositions(formula_string.as_str());
+        let formula_string = match expand_bounded_operators(formula_string.as_str()) {
+            Ok(formula_string) => formula_string,
+            Err(e) => return throw_runtime_error(e),
+        };
 
         let formula_native = match (allow_extended, minimize_with) {
             (false, None) => parse_hctl_formula(formula_string.as_str()),
@@ -82,13 +92,112 @@ impl HctlFormula {
 
         match formula_native {
             Err(e) => throw_runtime_error(e),
-            Ok(formula) => Ok(Self::from_native(formula)),
+            Ok(formula) => Ok(Self::from_native(decode_builtin_propositions(formula))),
         }
     }
 
     pub fn as_native(&self) -> &HctlTreeNode {
         self.value
     }
+
+    /// Recursively collect scoping and naming problems of the given sub-formula (see
+    /// `HctlFormula.validate`). The `bound` list contains the state variables that are
+    /// quantified by the parent operators.
+    fn collect_diagnostics(
+        &self,
+        node: &HctlTreeNode,
+        ctx: &SymbolicContext,
+        known_properties: &HashSet<String>,
+        bound: &mut Vec<String>,
+        result: &mut Vec<Diagnostic>,
+    ) {
+        let mut report = |kind: &'static str, severity: &'static str, message: String| {
+            result.push(Diagnostic {
+                kind,
+                severity,
+                message,
+                formula: self.mk_child_ref(node),
+            });
+        };
+        match &node.node_type {
+            NodeType::Terminal(Atomic::Prop(name)) => {
+                if ctx.as_native().find_network_variable(name).is_none() {
+                    report(
+                        "unknown_variable",
+                        "error",
+                        format!("Network variable `{name}` does not exist."),
+                    );
+                }
+            }
+            NodeType::Terminal(Atomic::Var(var)) => {
+                if !bound.contains(var) {
+                    report(
+                        "unbound_state_variable",
+                        "error",
+                        format!("State variable `{{{var}}}` is not bound by any quantifier."),
+                    );
+                }
+            }
+            NodeType::Terminal(Atomic::WildCardProp(name)) => {
+                if !known_properties.contains(name) && !is_builtin_proposition(name) {
+                    report(
+                        "unresolved_proposition",
+                        "error",
+                        format!("Extended proposition `%{name}%` has no value."),
+                    );
+                }
+            }
+            NodeType::Terminal(_) => (),
+            NodeType::Unary(_, a) => {
+                self.collect_diagnostics(a, ctx, known_properties, bound, result);
+            }
+            NodeType::Binary(_, a, b) => {
+                self.collect_diagnostics(a, ctx, known_properties, bound, result);
+                self.collect_diagnostics(b, ctx, known_properties, bound, result);
+            }
+            NodeType::Hybrid(op, var, domain, a) => {
+                if let Some(domain) = domain
+                    && !known_properties.contains(domain)
+                    && !is_builtin_proposition(domain)
+                {
+                    report(
+                        "unresolved_proposition",
+                        "error",
+                        format!("Quantifier domain `%{domain}%` has no value."),
+                    );
+                }
+                if matches!(op, HybridOp::Jump) {
+                    if !bound.contains(var) {
+                        report(
+                            "unbound_state_variable",
+                            "error",
+                            format!("State variable `{{{var}}}` is not bound by any quantifier."),
+                        );
+                    }
+                    self.collect_diagnostics(a, ctx, known_properties, bound, result);
+                } else {
+                    if bound.contains(var) {
+                        report(
+                            "shadowed_state_variable",
+                            "warning",
+                            format!("State variable `{{{var}}}` shadows an outer quantifier."),
+                        );
+                    }
+                    bound.push(var.clone());
+                    self.collect_diagnostics(a, ctx, known_properties, bound, result);
+                    bound.pop();
+                }
+            }
+        }
+    }
+}
+
+/// A single problem found by `HctlFormula.validate`.
+struct Diagnostic {
+    kind: &'static str,
+    severity: &'static str,
+    message: String,
+    formula: HctlFormula,
 }
 
 fn encode_hybrid_operator(op: &HybridOp) -> String {
@@ -343,19 +452,85 @@ impl HctlFormula {
     ///
     /// For unary operators, provide only one `HctlFormula`. For binary operators, provide
     /// both formulas.
+    ///
+    /// The `exist_future`, `all_future`, `exist_global`, and `all_global` operators also accept
+    /// an optional `bound` (i.e. `EF[<=k]`, `AF[<=k]`, `EG[<=k]`, and `AG[<=k]`). Such bounded
+    /// operators only consider the first `k` steps of each path: for example, `EF[<=k] phi`
+    /// holds if `phi` can be reached in at most `k` steps, and `AG[<=k] phi` holds if `phi`
+    /// holds in the first `k` steps of every path. Bounded operators are translated into
+    /// nested `EX`/`AX` operators, i.e. `EF[<=2] phi` is the same as
+    /// `phi | EX (phi | EX phi)`. The same syntax is also supported when parsing formulas.
+    /// Since the unrolled formula contains a copy of `phi` for every step, the bound is
+    /// limited to at most `1000` steps.
+    ///
+    /// ```python
+    /// a = HctlFormula("a")
+    /// bounded = HctlFormula.mk_temporal("exist_future", a, bound=1)
+    /// assert bounded == HctlFormula("EF[<=1] a")
+    /// assert bounded == HctlFormula("a | EX a")
+    /// ```
     #[staticmethod]
-    #[pyo3(signature = (op, a, b = None))]
-    fn mk_temporal(op: String, a: HctlFormula, b: Option<HctlFormula>) -> PyResult<HctlFormula> {
+    #[pyo3(signature = (op, a, b = None, bound = None))]
+    fn mk_temporal(
+        op: String,
+        a: HctlFormula,
+        b: Option<HctlFormula>,
+        bound: Option<usize>,
+    ) -> PyResult<HctlFormula> {
         let native = if let Some(b) = b {
+            if bound.is_some() {
+                return throw_type_error("Binary temporal operators do not support bounds.");
+            }
             let op = resolve_temporal_binary_operator(op)?;
             HctlTreeNode::mk_binary(a.as_native().clone(), b.as_native().clone(), op)
         } else {
             let op = resolve_temporal_unary_operator(op)?;
-            HctlTreeNode::mk_unary(a.as_native().clone(), op)
+            if let Some(bound) = bound {
+                match mk_bounded_operator(&op, a.as_native(), bound) {
+                    Ok(native) => native,
+                    Err(e) => return throw_type_error(e),
+                }
+            } else {
+                HctlTreeNode::mk_unary(a.as_native().clone(), op)
+            }
         };
         Ok(Self::from_native(native))
     }
 
+    /// Create a new `HctlFormula` from a formula of linear temporal logic (LTL), such that
+    /// the `HctlFormula` holds in a state if and only if the LTL formula holds on *all* paths
+    /// starting in this state. The result can be then used with `ModelChecking.verify` as
+    /// any other `HctlFormula`.
+    ///
+    /// The LTL syntax uses the same propositions and Boolean operators as HCTL (`~`, `&`,
+    /// `|`, `^`, `=>`, `<=>`, `true`, `false`, `%prop%`), together with temporal operators
+    /// `X` (next), `F` (future), `G` (globally), `U` (until), and `W` (weak until).
+    /// Unary operators bind tighter than binary temporal operators, which bind tighter
+    /// than Boolean operators. Propositions named `X`, `F`, `G`, `U`, `W`, `true`, or `false`
+    /// must be written in double quotes (e.g. `G "F"`); otherwise, they are treated as
+    /// operators or constants.
+    ///
+    /// Only a fragment of LTL that can be translated into HCTL is supported. After all
+    /// negations are pushed to propositions, the formula can only use:
+    ///  - `X`, `G`, and `&` with arbitrary arguments;
+    ///  - `|` where at least one argument has no temporal operators;
+    ///  - `F`, `U`, and `W` on arguments without temporal operators;
+    ///  - `F G p` where `p` has no temporal operators. This uses one quantified state
+    ///    variable, hence the `AsynchronousGraph` must be created using
+    ///    `AsynchronousGraph.mk_for_model_checking`.
+    ///
+    /// ```python
+    /// assert HctlFormula.from_ltl("G (a => F b)") == HctlFormula("AG (~a | AF b)")
+    /// assert HctlFormula.from_ltl("~(a U b)") == HctlFormula("~b AW (~a & ~b)")
+    /// ```
+    #[staticmethod]
+    fn from_ltl(formula: &str) -> PyResult<HctlFormula> {
+        match parse_ltl(formula) {
+            Ok(native) => Ok(Self::from_native(native)),
+            Err(e) => throw_runtime_error(e),
+        }
+    }
+
     /// Create a new `HctlFormula` that uses a binary Boolean operator (see `BinaryOperator`).
     #[staticmethod]
     fn mk_boolean(op: String, a: &HctlFormula, b: &HctlFormula) -> PyResult<HctlFormula> {
@@ -1080,6 +1255,121 @@ impl HctlFormula {
         collect_unique_wild_cards(self.as_native().clone()).0
     }
 
+    /// Check that this `HctlFormula` can be model-checked using the given `context`
+    /// (`AsynchronousGraph` or `SymbolicContext`), returning a list of `HctlDiagnostic`
+    /// dictionaries that describe all the discovered problems (an empty list means
+    /// the formula is valid).
+    ///
+    /// The following problems are reported (in the order in which they appear in the formula):
+    ///  - `unknown_variable`: A proposition that is not a variable of the `context`.
+    ///  - `unbound_state_variable`: A state variable (e.g. `{x}` or `@{x}`) that is used
+    ///    outside of the scope of any quantifier that binds it.
+    ///  - `shadowed_state_variable`: A quantifier that binds a variable which is already
+    ///    bound by an outer quantifier (this is only a warning).
+    ///  - `unresolved_proposition`: An extended proposition or domain which is neither given
+    ///    in the `substitution`, nor a built-in proposition (see `ModelChecking.verify`).
+    ///  - `missing_extra_variables`: The `context` does not have enough extra symbolic
+    ///    variables to represent all quantified state variables
+    ///    (see `AsynchronousGraph.mk_for_model_checking`). This is only a warning, since
+    ///    `ModelChecking.verify` adds the missing variables automatically.
+    ///
+    /// Each diagnostic contains the `kind` of the problem, its `severity` (`"error"` or
+    /// `"warning"`), a human-readable `message`, and the sub-`formula` where the problem was
+    /// found.
+    ///
+    /// ```python
+    /// bn = BooleanNetwork(["a", "b"])
+    /// ctx = SymbolicContext(bn)
+    /// formula = HctlFormula("!{x}: (c & %p% & @{y}: EX {x})")
+    /// kinds = [d["kind"] for d in formula.validate(ctx)]
+    /// assert kinds == [
+    ///     "unknown_variable",
+    ///     "unresolved_proposition",
+    ///     "unbound_state_variable",
+    ///     "missing_extra_variables",
+    /// ]
+    /// ```
+    #[pyo3(signature = (context, substitution = None))]
+    pub fn validate<'a>(
+        &self,
+        py: Python<'a>,
+        context: &Bound<'a, PyAny>,
+        substitution: Option<HashMap<String, Bound<'a, PyAny>>>,
+    ) -> PyResult<Vec<Bound<'a, PyDict>>> {
+        let ctx = if let Ok(graph) = context.cast::<AsynchronousGraph>() {
+            graph.get().symbolic_context()
+        } else if let Ok(ctx) = context.extract::<Py<SymbolicContext>>() {
+            ctx
+        } else {
+            return throw_type_error("Expected `AsynchronousGraph` or `SymbolicContext`.");
+        };
+        let ctx = ctx.get();
+        let known_properties = substitution
+            .map(|it| it.into_keys().collect::<HashSet<_>>())
+            .unwrap_or_default();
+
+        let mut diagnostics = Vec::new();
+        self.collect_diagnostics(
+            self.value,
+            ctx,
+            &known_properties,
+            &mut Vec::new(),
+            &mut diagnostics,
+        );
+
+        // The model checker renames the state variables such that as few of them as possible
+        // are used. This number of extra symbolic variables is then needed for each
+        // network variable. If the formula cannot be minimized (due to the problems
+        // reported above), we use the number of variables in the original formula.
+        let native = ctx.as_native();
+        let formula = encode_builtin_propositions(&self.__str__());
+        let required = match parse_and_minimize_extended_formula(native, &formula) {
+            Ok(minimized) => collect_unique_hctl_vars(minimized).len(),
+            Err(_) => self.used_state_variables().len(),
+        };
+        let available = native
+            .network_variables()
+            .map(|var| native.extra_state_variables(var).len())
+            .min()
+            .unwrap_or(0);
+        if required > available {
+            diagnostics.push(Diagnostic {
+                kind: "missing_extra_variables",
+                severity: "warning",
+                message: format!(
+                    "The formula needs {required} extra symbolic variable(s) per network variable, but only {available} are available."
+                ),
+                formula: self.clone(),
+            });
+        }
+
+        let mut result = Vec::new();
+        for diagnostic in diagnostics {
+            let dict = PyDict::new(py);
+            dict.set_item("kind", diagnostic.kind)?;
+            dict.set_item("severity", diagnostic.severity)?;
+            dict.set_item("message", diagnostic.message)?;
+            dict.set_item("formula", diagnostic.formula)?;
+            result.push(dict);
+        }
+        Ok(result)
+    }
+
+    /// Convert this formula into a CTL expression in the syntax of NuSMV/nuXmv
+    /// (e.g. to be used as a `CTLSPEC` of a model produced by `BooleanNetwork.to_smv`).
+    ///
+    /// Only the CTL fragment of HCTL is supported: a `RuntimeError` is raised if the formula
+    /// uses hybrid operators, state variables, or extended propositions. The weak until
+    /// operators (`EW` and `AW`) are rewritten, since SMV does not support them.
+    ///
+    /// ```python
+    /// assert HctlFormula("AG (a => EF ~b)").to_smv_ctl() == "AG (a -> EF !b)"
+    /// assert HctlFormula("a EU b").to_smv_ctl() == "E [ a U b ]"
+    /// ```
+    pub fn to_smv_ctl(&self) -> PyResult<String> {
+        formula_to_smv_ctl(self.value).map_err(runtime_error)
+    }
+
     /// Return the direct child sub-formulas of this `HctlFormula` (one child for unary and hybrid
     /// operators, two children for binary operators, no children for atoms).
     ///
@@ -1,11 +1,17 @@
 use pyo3::prelude::{PyModule, PyModuleMethods};
 use pyo3::{Bound, PyResult};
 
+pub mod builtin_propositions;
+pub mod fairness;
 pub mod hctl_formula;
 pub mod model_checking;
+pub mod model_checking_session;
+pub mod property_templates;
 
 pub(crate) fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add_class::<hctl_formula::HctlFormula>()?;
     module.add_class::<model_checking::ModelChecking>()?;
+    module.add_class::<model_checking_session::ModelCheckingSession>()?;
+    module.add_class::<property_templates::PropertyTemplates>()?;
     Ok(())
 }
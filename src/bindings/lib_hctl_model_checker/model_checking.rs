@@ -1,15 +1,35 @@
 use std::collections::HashMap;
 
+use biodivine_hctl_model_checker::mc_utils::{check_hctl_var_support, collect_unique_hctl_vars};
 use biodivine_hctl_model_checker::model_checking::{
     model_check_multiple_extended_formulae_dirty, model_check_multiple_formulae_dirty,
 };
+use biodivine_hctl_model_checker::preprocessing::hctl_tree::{HctlTreeNode, NodeType};
+use biodivine_hctl_model_checker::preprocessing::operator_enums::{BinaryOp, UnaryOp};
+use biodivine_hctl_model_checker::preprocessing::parser::parse_and_minimize_extended_formula;
+use biodivine_lib_bdd::BddPartialValuation;
+use biodivine_lib_param_bn::biodivine_std::traits::Set;
+use biodivine_lib_param_bn::symbolic_async_graph::{
+    GraphColoredVertices, GraphColors, SymbolicAsyncGraph,
+};
 use pyo3::prelude::PyAnyMethods;
-use pyo3::types::PyList;
-use pyo3::{Bound, Py, PyAny, PyResult, Python, pyclass, pymethods};
+use pyo3::types::{PyDict, PyList};
+use pyo3::{Bound, IntoPyObject, Py, PyAny, PyResult, Python, pyclass, pymethods};
 
+use crate::bindings::lib_hctl_model_checker::builtin_propositions::{
+    encode_builtin_propositions, encode_proposition_name, resolve_builtin_propositions,
+};
+use crate::bindings::lib_hctl_model_checker::fairness::{FairModelChecker, PyFairnessConfig};
 use crate::bindings::lib_hctl_model_checker::hctl_formula::HctlFormula;
+use crate::bindings::lib_param_bn::argument_types::subspace_valuation_type::SubspaceValuationType;
+use crate::bindings::lib_param_bn::boolean_network::BooleanNetwork;
 use crate::bindings::lib_param_bn::symbolic::asynchronous_graph::AsynchronousGraph;
+use crate::bindings::lib_param_bn::symbolic::model_color::ColorModel;
+use crate::bindings::lib_param_bn::symbolic::model_vertex::VertexModel;
+use crate::bindings::lib_param_bn::symbolic::set_color::ColorSet;
 use crate::bindings::lib_param_bn::symbolic::set_colored_vertex::ColoredVertexSet;
+use crate::bindings::lib_param_bn::symbolic::symbolic_context::SymbolicContext;
+use crate::internal::temporal_syntax::expand_bounded_operators;
 use crate::{AsNative, throw_runtime_error, throw_type_error};
 
 #[pyclass(module = "biodivine_aeon", frozen)]
@@ -30,92 +50,790 @@ impl ModelChecking {
     /// a `substitution` map which assigns each proposition a set of valid vertex-color pairs
     /// (the algorithm fails if the used extended propositions cannot be resolved).
     ///
+    /// Extended propositions that are not given in the `substitution` can also refer to one
+    /// of the following built-in sets, which are computed on demand:
+    ///  - `%attractor%`: All attractor states (see `Attractors.attractors`).
+    ///  - `%fixed_point%`: All fixed-point states (see `FixedPoints.symbolic`).
+    ///  - `%trap_space:minimal%` and `%trap_space:essential%`: All states that belong to
+    ///    some minimal (or essential) trap space (see `TrapSpaces`).
+    ///  - `%basin:i%`: All states that can reach the `i`-th attractor, using the same
+    ///    order as `Attractors.attractors`.
+    ///
+    /// All built-in sets are computed using the dynamics of the given `graph`, i.e. if the
+    /// graph is restricted (see `AsynchronousGraph.restrict`), the sets describe
+    /// the restricted dynamics.
+    ///
+    /// The `graph` can be an `AsynchronousGraph`, or a `BooleanNetwork` (which is then
+    /// represented using `AsynchronousGraph(network)`).
+    ///
     /// *The following only applies to HCTL formulas that use quantified state variables (i.e.
     /// "plain" CTL formulas do not need this):*
     ///
-    /// Note that the provided `AsynchronousGraph` must contain enough symbolic variables
-    /// to successfully represent all quantified variables in the provided formulae. You can
-    /// create such a graph using `AsynchronousGraph.mk_for_model_checking`, or by manually creating
-    /// enough extra symbolic variables for each variable in the `SymbolicContext`.
-    ///
-    /// The resulting `ColoredVertexSet` uses the symbolic encoding of the provided
-    /// `AsynchronousGraph` that supports the extra symbolic variables necessary for model
-    /// checking. If you want to output the result in a way that is compatible with the "default"
-    /// `AsynchronousGraph` representation (i.e. `AsynchronousGraph(network)`), you can use
-    /// `AsynchronousGraph.transfer_from` to translate the sets into the "default"
-    /// symbolic encoding.
+    /// To model-check quantified state variables, the `AsynchronousGraph` must contain enough
+    /// extra symbolic variables to represent all quantified variables in the provided formulae
+    /// (see `AsynchronousGraph.mk_for_model_checking`). If this is not the case, a suitable
+    /// graph (with the same network and unit set) is created automatically, and all sets are
+    /// translated between the two symbolic encodings. In other words, the `substitution`
+    /// sets and the resulting `ColoredVertexSet` always use the symbolic encoding of the
+    /// provided `graph`. Creating the graph using `AsynchronousGraph.mk_for_model_checking` is
+    /// still useful to avoid repeating this translation when verifying many formulas.
+    ///
+    /// ```python
+    /// bn = BooleanNetwork.from_aeon("a -> b\nb -| a")
+    /// graph = AsynchronousGraph(bn)
+    /// result = ModelChecking.verify(graph, "!{x}: AX EF {x}")
+    /// assert result.is_subset(graph.mk_unit_colored_vertices())
+    /// assert result == ModelChecking.verify(bn, "!{x}: AX EF {x}")
+    /// ```
+    ///
+    /// Optionally, you can provide a `FairnessConfig` which restricts the path quantifiers of
+    /// CTL operators only to *fair* paths. This is useful because asynchronous dynamics admit
+    /// "unrealistic" paths where some variable is never updated. The config can contain:
+    ///  - `weak`: A list of variables such that each variable that can be updated continuously
+    ///    from some point on is eventually updated.
+    ///  - `strong`: A list of variables such that each variable that can be updated infinitely
+    ///    often is also updated infinitely often.
+    ///  - `infinitely_often`: A list of `ColoredVertexSet` objects that must be visited
+    ///    infinitely often.
+    ///
+    /// Under fairness, `E` operators require the existence of a fair path, while `A` operators
+    /// only consider fair paths (i.e. they hold trivially in states without any fair path).
+    /// States without successors are treated as having a self-loop (as usual). This self-loop
+    /// satisfies every `weak` and `strong` requirement (no variable can be updated in such
+    /// state), but it only satisfies an `infinitely_often` requirement if the state belongs to
+    /// the required set. Fairness is not supported for temporal operators that appear inside
+    /// hybrid operators.
+    ///
+    /// ```python
+    /// bn = BooleanNetwork.from_aeon("b -| b\n$a: true\n$b: !b")
+    /// graph = AsynchronousGraph(bn)
+    /// # Without fairness, `b` can oscillate forever while `a` is never updated.
+    /// assert ModelChecking.verify(graph, "AF a") != graph.mk_unit_colored_vertices()
+    /// fair = ModelChecking.verify(graph, "AF a", fairness={"weak": ["a"]})
+    /// assert fair == graph.mk_unit_colored_vertices()
+    /// ```
     #[staticmethod]
-    #[pyo3(signature = (graph, property, substitution = None))]
+    #[pyo3(signature = (graph, property, substitution = None, fairness = None))]
     pub fn verify<'a>(
         py: Python<'a>,
-        graph: &AsynchronousGraph,
+        graph: &Bound<'a, PyAny>,
         property: &Bound<'a, PyAny>,
         substitution: Option<HashMap<String, ColoredVertexSet>>,
+        fairness: Option<PyFairnessConfig>,
     ) -> PyResult<Bound<'a, PyAny>> {
-        // Extract properties. This could be either one property or a list of properties.
-        let mut properties = Vec::new();
-        let mut is_singular = true;
-        if let Ok(prop) = property.extract::<HctlFormula>() {
-            properties.push(prop.__str__());
-        } else if let Ok(prop_str) = property.extract::<String>() {
-            properties.push(prop_str);
-        } else if let Ok(prop_list) = property.cast::<PyList>() {
-            is_singular = false;
-            for x in prop_list {
-                if let Ok(prop) = x.extract::<HctlFormula>() {
-                    properties.push(prop.__str__());
-                } else if let Ok(prop_str) = x.extract::<String>() {
-                    properties.push(prop_str);
-                } else {
-                    return throw_type_error(format!(
-                        "Expected `str` or `HctlFormula`. Got {x:?}."
-                    ));
-                }
+        let (properties, is_singular) = extract_properties(property)?;
+        let (caller, checking) = prepare_graph(py, graph, &properties)?;
+        let (caller, graph) = (caller.get(), checking.get());
+        let substitution = native_substitution(py, graph, caller, substitution, &properties)?;
+        let mut fairness = fairness;
+        if let Some(sets) = fairness
+            .as_mut()
+            .and_then(|it| it.infinitely_often.as_mut())
+        {
+            for set in sets {
+                let native = transfer_set(graph, caller, set.as_native())?;
+                *set = ColoredVertexSet::mk_native(graph.symbolic_context(), native);
             }
-        } else {
-            return throw_type_error(format!(
-                "Expected `str`, `HctlFormula`, or `list`. Got {property:?}."
-            ));
         }
-
-        let properties = properties.iter().map(|it| it.as_str()).collect::<Vec<_>>();
-
-        let result = if let Some(substitution) = substitution {
-            let native = substitution
-                .into_iter()
-                .map(|(a, b)| (a, b.as_native().clone()))
-                .collect::<HashMap<_, _>>();
-            model_check_multiple_extended_formulae_dirty(properties, graph.as_native(), &native)
+        let result = if let Some(fairness) = fairness {
+            let checker = FairModelChecker::new(py, graph, &fairness, substitution.as_ref())?;
+            properties
+                .iter()
+                .map(|it| checker.check_string(py, it))
+                .collect::<PyResult<Vec<_>>>()?
         } else {
-            // Model-check as normal properties.
-            model_check_multiple_formulae_dirty(properties, graph.as_native())
+            check_formulae(graph, &properties, substitution.as_ref())?
         };
+        let result = result
+            .iter()
+            .map(|it| transfer_set(caller, graph, it))
+            .collect::<PyResult<Vec<_>>>()?;
 
         // Perform the necessary type conversions to return either a single element or
         // a list of elements, depending on context. There's probably a nicer way to do this,
         //  but it should be good enough for now.
-        match result {
-            Err(e) => throw_runtime_error(e),
-            Ok(result) => {
-                if is_singular {
-                    let item = result.into_iter().next().unwrap();
-                    let result = ColoredVertexSet::mk_native(graph.symbolic_context(), item);
-                    Ok(Py::new(py, result)?.into_bound(py).into_any())
+        if is_singular {
+            let item = result.into_iter().next().unwrap();
+            let result = ColoredVertexSet::mk_native(caller.symbolic_context(), item);
+            Ok(Py::new(py, result)?.into_bound(py).into_any())
+        } else {
+            let result_iter = result
+                .into_iter()
+                .map(|it| {
+                    Py::new(
+                        py,
+                        ColoredVertexSet::mk_native(caller.symbolic_context(), it),
+                    )
+                })
+                .collect::<PyResult<Vec<Py<ColoredVertexSet>>>>()?;
+            let result_list = PyList::new(py, result_iter)?;
+
+            Ok(result_list.into_any())
+        }
+    }
+
+    /// Explain why the given HCTL `formula` holds (or does not hold) in the given `state`
+    /// for the given `color`.
+    ///
+    /// The explanation is a single trajectory of the `graph` (within the given `color`): a
+    /// *witness* if the formula uses an existential temporal operator (`EX`, `EF`, `EG`,
+    /// `EU`, `EW`) and holds, or a *counterexample* if the formula uses a universal temporal
+    /// operator (`AX`, `AF`, `AG`, `AU`, `AW`) and does not hold. A negated temporal operator
+    /// (e.g. `~AG phi`) is also supported, in which case the roles are swapped. The temporal
+    /// operator must appear at the root of the formula, but its sub-formulas can be arbitrary
+    /// (they are evaluated using `ModelChecking.verify`, including the optional `substitution`).
+    ///
+    /// The result is a `HctlExplanation` dictionary:
+    ///  - `holds`: Whether the `formula` holds in the given `state` and `color`.
+    ///  - `kind`: Either `"witness"` or `"counterexample"`.
+    ///  - `path`: A list of `(VertexModel, HctlFormula)` pairs, starting in `state`, where
+    ///    each step is annotated with the sub-formula that the step satisfies.
+    ///  - `loop_start`: If set, the trajectory is a "lasso": after the last step, it
+    ///    continues with the step at this index (this is needed to explain `EG`-like
+    ///    properties). States without successors are treated as having a self-loop (as in
+    ///    the model checker).
+    ///
+    /// If the property cannot be explained by a single trajectory (an existential formula that
+    /// does not hold, or a universal formula that holds), the result is `None`.
+    ///
+    /// The `state` must specify the value of every network variable. The `color` can be either
+    /// a `ColorModel` or a non-empty `ColorSet` (in which case an arbitrary color is used).
+    /// Colors from a compatible `AsynchronousGraph` (e.g. without the extra symbolic variables
+    /// needed for model checking) are transferred into the encoding of `graph` automatically.
+    ///
+    /// ```python
+    /// bn = BooleanNetwork.from_aeon("a -> b\nb -| a\n$a: !b\n$b: a")
+    /// graph = AsynchronousGraph(bn)
+    /// color = graph.mk_unit_colors()
+    /// result = ModelChecking.explain(graph, "EF (a & b)", {"a": 0, "b": 0}, color)
+    /// assert result["holds"] and result["kind"] == "witness"
+    /// assert [s.to_named_dict() for (s, _) in result["path"]] == [
+    ///     {"a": False, "b": False}, {"a": True, "b": False}, {"a": True, "b": True}
+    /// ]
+    /// ```
+    #[staticmethod]
+    #[pyo3(signature = (graph, formula, state, color, substitution = None))]
+    pub fn explain<'a>(
+        py: Python<'a>,
+        graph: &AsynchronousGraph,
+        formula: &Bound<'a, PyAny>,
+        state: SubspaceValuationType,
+        color: &Bound<'a, PyAny>,
+        substitution: Option<HashMap<String, ColoredVertexSet>>,
+    ) -> PyResult<Option<Bound<'a, PyDict>>> {
+        let formula = HctlFormula::resolve_formula(formula, true, None)?;
+        let properties = [formula.__str__()];
+        let substitution = native_substitution(py, graph, graph, substitution, &properties)?;
+        let ctx = graph.symbolic_context();
+        let stg = graph.as_native();
+
+        // Only consider a single color of the graph, and a single state within that color.
+        let color = resolve_color(py, graph, color)?;
+        let universe = stg.unit_colored_vertices().intersect_colors(&color);
+        let valuation = state.resolve(stg)?;
+        let mut fixed = valuation.iter().map(|(var, _)| *var).collect::<Vec<_>>();
+        fixed.sort();
+        fixed.dedup();
+        if fixed.len() != stg.symbolic_context().num_state_variables() {
+            return throw_runtime_error(
+                "The `state` must specify the value of every network variable.",
+            );
+        }
+        let start = stg.mk_subspace(&valuation).intersect(&universe);
+        if start.is_empty() {
+            return throw_runtime_error("The `state` is not valid for the given `color`.");
+        }
+
+        let sat = |node: &HctlTreeNode| -> PyResult<GraphColoredVertices> {
+            let result = check_formulae(graph, &[node.to_string()], substitution.as_ref())?;
+            Ok(result.into_iter().next().unwrap().intersect(&universe))
+        };
+
+        let (negated, inner) = match &formula.as_native().node_type {
+            NodeType::Unary(UnaryOp::Not, child) => (true, child.as_ref()),
+            _ => (false, formula.as_native()),
+        };
+        let Some((existential, property)) = PathProperty::resolve(inner) else {
+            return throw_runtime_error(
+                "Only formulas with a (possibly negated) temporal operator at the root can be explained.",
+            );
+        };
+
+        let inner_holds = start.is_subset(&sat(inner)?);
+        let holds = inner_holds != negated;
+        let kind = if existential != negated {
+            "witness"
+        } else {
+            "counterexample"
+        };
+        if inner_holds != existential {
+            // Existential property that fails, or universal property that holds.
+            return Ok(None);
+        }
+
+        let (path, loop_start) = property.build_path(py, stg, &start, &universe, &sat)?;
+        let path = path
+            .into_iter()
+            .map(|(state, node)| {
+                let vertex = mk_vertex_model(&ctx, &state);
+                (vertex, HctlFormula::from_native(node))
+            })
+            .collect::<Vec<_>>();
+
+        let result = PyDict::new(py);
+        result.set_item("holds", holds)?;
+        result.set_item("kind", kind)?;
+        result.set_item("path", path)?;
+        result.set_item("loop_start", loop_start)?;
+        Ok(Some(result))
+    }
+
+    /// Compute the colors (parametrizations) of a partially specified network for which all
+    /// the given `properties` hold, together with a summary of the resulting function tables.
+    ///
+    /// The `graph` and `properties` arguments are the same as in `ModelChecking.verify`
+    /// (a single property or a list of properties). The `mode` determines how each property
+    /// is evaluated with respect to the `initial_states` (all states by default):
+    ///  - `"universal"`: The property must hold in every initial state of the color.
+    ///  - `"existential"`: The property must hold in at least one initial state of the color
+    ///    (each property can be satisfied in a different initial state).
+    ///
+    /// In both cases, only colors that have at least one initial state are considered.
+    ///
+    /// The result is a `SynthesisResult` dictionary:
+    ///  - `colors`: The `ColorSet` of all colors that satisfy all `properties`.
+    ///  - `functions`: For every uninterpreted function (explicit parameters and implicit
+    ///    update functions, see `SymbolicContext.get_function_table`), the list of its table
+    ///    entries. Each entry contains its `inputs` and its `status` in `colors`: `"fixed"`
+    ///    (the entry is `1` in all colors), `"forbidden"` (the entry is `0` in all colors),
+    ///    or `"free"` (both values are possible). The dictionary is empty if no color
+    ///    satisfies the properties.
+    ///  - `summary`: A human-readable description of the result, with one line for every
+    ///    table entry (e.g. `f(0, 1) = 1 (fixed)` or `f(1, 1) = * (free)`).
+    ///
+    /// ```python
+    /// bn = BooleanNetwork.from_aeon("a ->? b\nb -| a\n$a: !b")
+    /// result = ModelChecking.synthesize(bn, "AG EF (a & b)")
+    /// assert result["functions"]["b"] == [
+    ///     {"inputs": [False], "status": "forbidden"},
+    ///     {"inputs": [True], "status": "fixed"},
+    /// ]
+    /// ```
+    #[staticmethod]
+    #[pyo3(signature = (graph, properties, mode = "universal", initial_states = None, substitution = None))]
+    pub fn synthesize<'a>(
+        py: Python<'a>,
+        graph: &Bound<'a, PyAny>,
+        properties: &Bound<'a, PyAny>,
+        mode: &str,
+        initial_states: Option<&ColoredVertexSet>,
+        substitution: Option<HashMap<String, ColoredVertexSet>>,
+    ) -> PyResult<Bound<'a, PyDict>> {
+        let is_universal = match mode {
+            "universal" => true,
+            "existential" => false,
+            _ => {
+                return throw_runtime_error(format!(
+                    "Unknown synthesis mode `{mode}`. Expected `universal` or `existential`."
+                ));
+            }
+        };
+        let (properties, _) = extract_properties(properties)?;
+        let (caller, checking) = prepare_graph(py, graph, &properties)?;
+        let (caller, graph) = (caller.get(), checking.get());
+        let substitution = native_substitution(py, graph, caller, substitution, &properties)?;
+        let results = check_formulae(graph, &properties, substitution.as_ref())?;
+
+        let stg = caller.as_native();
+        let initial = match initial_states {
+            Some(set) => set.as_native().intersect(stg.unit_colored_vertices()),
+            None => stg.unit_colored_vertices().clone(),
+        };
+        let mut colors = initial.colors();
+        for result in results {
+            let result = transfer_set(caller, graph, &result)?;
+            colors = if is_universal {
+                colors.minus(&initial.minus(&result).colors())
+            } else {
+                colors.intersect(&initial.intersect(&result).colors())
+            };
+        }
+
+        let (functions, summary) = summarize_function_tables(py, caller, &colors)?;
+        let result = PyDict::new(py);
+        result.set_item(
+            "colors",
+            ColorSet::mk_native(caller.symbolic_context(), colors),
+        )?;
+        result.set_item("functions", functions)?;
+        result.set_item("summary", summary)?;
+        Ok(result)
+    }
+}
+
+/// An existential temporal property which can be explained using a single trajectory.
+enum PathProperty {
+    Next(HctlTreeNode),
+    Future(HctlTreeNode),
+    Global(HctlTreeNode),
+    Until(HctlTreeNode, HctlTreeNode),
+    WeakUntil(HctlTreeNode, HctlTreeNode),
+}
+
+impl PathProperty {
+    /// Resolve the temporal operator at the root of `node`. Returns `true` together with
+    /// the property itself for existential operators, and `false` together with
+    /// the (existential) negation of the property for universal operators.
+    fn resolve(node: &HctlTreeNode) -> Option<(bool, PathProperty)> {
+        let not = |node: &HctlTreeNode| HctlTreeNode::mk_unary(node.clone(), UnaryOp::Not);
+        match &node.node_type {
+            NodeType::Unary(op, a) => {
+                let a = a.as_ref().clone();
+                match op {
+                    UnaryOp::EX => Some((true, PathProperty::Next(a))),
+                    UnaryOp::EF => Some((true, PathProperty::Future(a))),
+                    UnaryOp::EG => Some((true, PathProperty::Global(a))),
+                    UnaryOp::AX => Some((false, PathProperty::Next(not(&a)))),
+                    UnaryOp::AF => Some((false, PathProperty::Global(not(&a)))),
+                    UnaryOp::AG => Some((false, PathProperty::Future(not(&a)))),
+                    _ => None,
+                }
+            }
+            NodeType::Binary(op, a, b) => {
+                let (a, b) = (a.as_ref().clone(), b.as_ref().clone());
+                // !A[a U b] = E[!b W (!a & !b)] and !A[a W b] = E[!b U (!a & !b)].
+                let neither = HctlTreeNode::mk_binary(not(&a), not(&b), BinaryOp::And);
+                match op {
+                    BinaryOp::EU => Some((true, PathProperty::Until(a, b))),
+                    BinaryOp::EW => Some((true, PathProperty::WeakUntil(a, b))),
+                    BinaryOp::AU => Some((false, PathProperty::WeakUntil(not(&b), neither))),
+                    BinaryOp::AW => Some((false, PathProperty::Until(not(&b), neither))),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Build a trajectory starting in `start` which satisfies this property, assuming such
+    /// trajectory exists. The result contains the annotated steps and the optional index
+    /// where the final loop starts.
+    fn build_path<F>(
+        &self,
+        py: Python,
+        graph: &SymbolicAsyncGraph,
+        start: &GraphColoredVertices,
+        universe: &GraphColoredVertices,
+        sat: &F,
+    ) -> PyResult<(Vec<(GraphColoredVertices, HctlTreeNode)>, Option<usize>)>
+    where
+        F: Fn(&HctlTreeNode) -> PyResult<GraphColoredVertices>,
+    {
+        let result = match self {
+            PathProperty::Next(a) => {
+                let successors = post_total(graph, start).intersect(&sat(a)?);
+                if successors.is_empty() {
+                    None
                 } else {
-                    let result_iter = result
-                        .into_iter()
-                        .map(|it| {
-                            Py::new(
-                                py,
-                                ColoredVertexSet::mk_native(graph.symbolic_context(), it),
-                            )
-                        })
-                        .collect::<PyResult<Vec<Py<ColoredVertexSet>>>>()?;
-                    let result_list = PyList::new(py, result_iter)?;
-
-                    Ok(result_list.into_any())
+                    let root = HctlTreeNode::mk_unary(a.clone(), UnaryOp::EX);
+                    let path = vec![
+                        (start.clone(), root),
+                        (successors.pick_singleton(), a.clone()),
+                    ];
+                    Some((path, None))
                 }
             }
+            PathProperty::Future(a) => {
+                let root = HctlTreeNode::mk_unary(a.clone(), UnaryOp::EF);
+                find_path(py, graph, start, universe, &sat(a)?)?
+                    .map(|path| (annotate_path(path, &root, a), None))
+            }
+            PathProperty::Until(a, b) => find_path(py, graph, start, &sat(a)?, &sat(b)?)?
+                .map(|path| (annotate_path(path, a, b), None)),
+            PathProperty::Global(a) => {
+                let root = HctlTreeNode::mk_unary(a.clone(), UnaryOp::EG);
+                find_lasso(py, graph, start, &sat(&root)?)?.map(|(path, loop_start)| {
+                    let path = path.into_iter().map(|it| (it, a.clone())).collect();
+                    (path, Some(loop_start))
+                })
+            }
+            PathProperty::WeakUntil(a, b) => {
+                let until = HctlTreeNode::mk_binary(a.clone(), b.clone(), BinaryOp::EU);
+                let property = if start.is_subset(&sat(&until)?) {
+                    PathProperty::Until(a.clone(), b.clone())
+                } else {
+                    PathProperty::Global(a.clone())
+                };
+                return property.build_path(py, graph, start, universe, sat);
+            }
+        };
+        match result {
+            Some(result) => Ok(result),
+            None => throw_runtime_error("Cannot construct a trajectory for the given property."),
+        }
+    }
+}
+
+/// Extract the properties argument, which can be either one property (`str` or `HctlFormula`),
+/// or a list of properties. The properties are returned as strings accepted by the model
+/// checker (i.e. with bounded operators expanded), together with a flag which indicates that
+/// a single property was given.
+fn extract_properties(property: &Bound<'_, PyAny>) -> PyResult<(Vec<String>, bool)> {
+    let mut properties = Vec::new();
+    let mut is_singular = true;
+    if let Ok(prop) = property.extract::<HctlFormula>() {
+        properties.push(prop.__str__());
+    } else if let Ok(prop_str) = property.extract::<String>() {
+        properties.push(prop_str);
+    } else if let Ok(prop_list) = property.cast::<PyList>() {
+        is_singular = false;
+        for x in prop_list {
+            if let Ok(prop) = x.extract::<HctlFormula>() {
+                properties.push(prop.__str__());
+            } else if let Ok(prop_str) = x.extract::<String>() {
+                properties.push(prop_str);
+            } else {
+                return throw_type_error(format!("Expected `str` or `HctlFormula`. Got {x:?}."));
+            }
         }
+    } else {
+        return throw_type_error(format!(
+            "Expected `str`, `HctlFormula`, or `list`. Got {property:?}."
+        ));
+    }
+
+    let properties = properties
+        .iter()
+        .map(|it| expand_bounded_operators(it))
+        .collect::<Result<Vec<_>, _>>()
+        .or_else(throw_runtime_error)?;
+    Ok((properties, is_singular))
+}
+
+/// Prepare the `AsynchronousGraph` (or `BooleanNetwork`) given by the user for model checking
+/// of the given `properties`.
+///
+/// Returns the graph whose symbolic encoding is used for the inputs and outputs, and the graph
+/// that is used for model checking. If the former does not have enough extra symbolic variables
+/// for the `properties`, the latter is a new graph with the same network, unit set,
+/// and the necessary number of extra symbolic variables.
+fn prepare_graph(
+    py: Python,
+    graph: &Bound<'_, PyAny>,
+    properties: &[String],
+) -> PyResult<(Py<AsynchronousGraph>, Py<AsynchronousGraph>)> {
+    let graph = if let Ok(graph) = graph.extract::<Py<AsynchronousGraph>>() {
+        graph
+    } else if let Ok(network) = graph.extract::<Py<BooleanNetwork>>() {
+        Py::new(py, AsynchronousGraph::new(py, network, None, None)?)?
+    } else {
+        return throw_type_error("Expected `AsynchronousGraph` or `BooleanNetwork`.");
+    };
+
+    let stg = graph.get().as_native();
+    let mut required = 0;
+    let mut is_supported = true;
+    for property in properties {
+        // Invalid properties are reported later by the model checker.
+        let property = encode_builtin_propositions(property);
+        let Ok(tree) = parse_and_minimize_extended_formula(stg.symbolic_context(), &property)
+        else {
+            continue;
+        };
+        required = required.max(collect_unique_hctl_vars(tree.clone()).len());
+        is_supported = is_supported && check_hctl_var_support(stg, tree);
+    }
+    if is_supported {
+        return Ok((graph.clone_ref(py), graph));
+    }
+
+    let network = graph_network(py, graph.get())?;
+    let requirement = required.into_pyobject(py)?.into_any();
+    let extended = AsynchronousGraph::mk_for_model_checking(py, network, &requirement)?;
+    let unit = transfer_set(&extended, graph.get(), stg.unit_colored_vertices())?;
+    let extended = Py::new(py, extended.restrict_native(&unit))?;
+    Ok((graph, extended))
+}
+
+/// The `BooleanNetwork` of the given `graph` (either the original network, or a network
+/// reconstructed from the symbolic representation).
+pub fn graph_network(py: Python, graph: &AsynchronousGraph) -> PyResult<Py<BooleanNetwork>> {
+    let ctx = graph.symbolic_context();
+    if let Some(network) = ctx.get().get_network() {
+        return Ok(network.clone());
+    }
+    graph.reconstruct_network(py)
+}
+
+/// Translate a set from the `source` graph into the encoding of the `target` graph.
+///
+/// The result does not depend on the extra symbolic variables of the `source` graph. If both
+/// graphs are the same, the set is returned unchanged.
+pub fn transfer_set(
+    target: &AsynchronousGraph,
+    source: &AsynchronousGraph,
+    set: &GraphColoredVertices,
+) -> PyResult<GraphColoredVertices> {
+    if std::ptr::eq(target, source) {
+        return Ok(set.clone());
+    }
+    let source_ctx = source.as_native().symbolic_context();
+    let bdd = set.as_bdd().exists(source_ctx.all_extra_state_variables());
+    let set = GraphColoredVertices::new(bdd, source_ctx);
+    match target.as_native().transfer_from(&set, source.as_native()) {
+        Some(set) => Ok(set.intersect(target.as_native().unit_colored_vertices())),
+        None => throw_runtime_error("The two contexts are not compatible."),
+    }
+}
+
+/// Convert a substitution map (in the encoding of the `source` graph) into the native
+/// representation used by the model checker (in the encoding of `graph`), extended with
+/// the values of all built-in propositions used by the `properties`.
+fn native_substitution(
+    py: Python,
+    graph: &AsynchronousGraph,
+    source: &AsynchronousGraph,
+    substitution: Option<HashMap<String, ColoredVertexSet>>,
+    properties: &[String],
+) -> PyResult<Option<HashMap<String, GraphColoredVertices>>> {
+    let is_given = substitution.is_some();
+    let mut result = HashMap::new();
+    for (name, set) in substitution.unwrap_or_default() {
+        result.insert(name, transfer_set(graph, source, set.as_native())?);
+    }
+    resolve_builtin_propositions(py, graph, properties, &mut result)?;
+    if is_given || !result.is_empty() {
+        Ok(Some(result))
+    } else {
+        Ok(None)
+    }
+}
+
+/// Classify every function table entry in the given `colors` as `fixed` (always `1`),
+/// `forbidden` (always `0`), or `free`. Returns a dictionary with the entries of every
+/// function (by name), and a readable summary of all entries.
+fn summarize_function_tables<'a>(
+    py: Python<'a>,
+    graph: &AsynchronousGraph,
+    colors: &GraphColors,
+) -> PyResult<(Bound<'a, PyDict>, String)> {
+    let functions = PyDict::new(py);
+    if colors.is_empty() {
+        return Ok((functions, "No color satisfies all properties.".to_string()));
+    }
+
+    let ctx = graph.as_native().symbolic_context();
+    let network = graph_network(py, graph)?;
+    let network = network.borrow(py);
+    let network = network.as_native();
+    let mut tables = Vec::new();
+    for par in ctx.network_parameters() {
+        let name = network.get_parameter(par).get_name().clone();
+        tables.push((name, ctx.get_explicit_function_table(par)));
+    }
+    for var in ctx.network_implicit_parameters() {
+        let name = network.get_variable_name(var).clone();
+        tables.push((name, ctx.get_implicit_function_table(var).unwrap()));
+    }
+
+    let mut summary = vec![format!(
+        "{} color(s) satisfy all properties.",
+        colors.exact_cardinality()
+    )];
+    for (name, table) in tables {
+        let mut entries = Vec::new();
+        for (inputs, var) in table {
+            let can_be_true = !colors.as_bdd().var_select(var, true).is_false();
+            let can_be_false = !colors.as_bdd().var_select(var, false).is_false();
+            let (status, value) = match (can_be_true, can_be_false) {
+                (true, false) => ("fixed", "1"),
+                (false, true) => ("forbidden", "0"),
+                _ => ("free", "*"),
+            };
+            let row = inputs
+                .iter()
+                .map(|it| if *it { "1" } else { "0" })
+                .collect::<Vec<_>>()
+                .join(", ");
+            summary.push(format!("{name}({row}) = {value} ({status})"));
+            let entry = PyDict::new(py);
+            entry.set_item("inputs", inputs)?;
+            entry.set_item("status", status)?;
+            entries.push(entry);
+        }
+        functions.set_item(name, entries)?;
+    }
+    Ok((functions, summary.join("\n")))
+}
+
+/// Model-check the given formulae, with or without extended propositions. The proposition
+/// names (in formulae and in the substitution) are encoded for the model checker, see
+/// [encode_builtin_propositions].
+pub fn check_formulae(
+    graph: &AsynchronousGraph,
+    properties: &[String],
+    substitution: Option<&HashMap<String, GraphColoredVertices>>,
+) -> PyResult<Vec<GraphColoredVertices>> {
+    let properties = properties
+        .iter()
+        .map(|it| encode_builtin_propositions(it))
+        .collect::<Vec<_>>();
+    let properties = properties.iter().map(|it| it.as_str()).collect::<Vec<_>>();
+    let result = if let Some(substitution) = substitution {
+        let substitution = substitution
+            .iter()
+            .map(|(name, set)| (encode_proposition_name(name), set.clone()))
+            .collect::<HashMap<_, _>>();
+        model_check_multiple_extended_formulae_dirty(properties, graph.as_native(), &substitution)
+    } else {
+        // Model-check as normal properties.
+        model_check_multiple_formulae_dirty(properties, graph.as_native())
+    };
+    result.or_else(throw_runtime_error)
+}
+
+/// Resolve a `ColorModel` or `ColorSet` into a single color of the given `graph`.
+fn resolve_color(
+    py: Python,
+    graph: &AsynchronousGraph,
+    color: &Bound<'_, PyAny>,
+) -> PyResult<GraphColors> {
+    let color = if let Ok(model) = color.extract::<ColorModel>() {
+        model.to_symbolic()
+    } else if let Ok(set) = color.extract::<ColorSet>() {
+        set
+    } else {
+        return throw_type_error(format!(
+            "Expected `ColorModel` or `ColorSet`. Got {color:?}."
+        ));
+    };
+    let ctx = graph.symbolic_context();
+    let color_ctx = color.__ctx__();
+    let native = if color_ctx.as_ptr() == ctx.as_ptr() {
+        color.as_native().clone()
+    } else {
+        let transferred =
+            ctx.get()
+                .transfer_native(py, color.as_native().as_bdd(), color_ctx.get());
+        let Some(bdd) = transferred else {
+            return throw_runtime_error("The `color` is not compatible with the `graph`.");
+        };
+        GraphColors::new(bdd, ctx.get().as_native())
+    };
+    let native = native.intersect(graph.as_native().unit_colors());
+    if native.is_empty() {
+        return throw_runtime_error("The `color` is not a valid color of the `graph`.");
+    }
+    Ok(native.pick_singleton())
+}
+
+/// Successors of the given states, assuming states without successors have a self-loop.
+fn post_total(graph: &SymbolicAsyncGraph, set: &GraphColoredVertices) -> GraphColoredVertices {
+    let sinks = set.minus(&graph.can_post(set));
+    graph.post(set).union(&sinks)
+}
+
+/// Predecessors of the given states, assuming states without successors have a self-loop.
+pub fn pre_total(graph: &SymbolicAsyncGraph, set: &GraphColoredVertices) -> GraphColoredVertices {
+    let sinks = set.minus(&graph.can_post(set));
+    graph.pre(set).union(&sinks)
+}
+
+/// Find a shortest trajectory from `start` to `target` such that all states before the last
+/// one are in `allowed`.
+fn find_path(
+    py: Python,
+    graph: &SymbolicAsyncGraph,
+    start: &GraphColoredVertices,
+    allowed: &GraphColoredVertices,
+    target: &GraphColoredVertices,
+) -> PyResult<Option<Vec<GraphColoredVertices>>> {
+    let mut layers = vec![start.clone()];
+    let mut visited = start.clone();
+    loop {
+        let last = layers.last().unwrap();
+        let reached = last.intersect(target);
+        if !reached.is_empty() {
+            // Backtrack through the BFS layers.
+            let mut current = reached.pick_singleton();
+            let mut path = vec![current.clone()];
+            for layer in layers[..layers.len() - 1].iter().rev() {
+                let layer = layer.intersect(allowed);
+                current = pre_total(graph, &current)
+                    .intersect(&layer)
+                    .pick_singleton();
+                path.push(current.clone());
+            }
+            path.reverse();
+            return Ok(Some(path));
+        }
+        py.check_signals()?;
+        let next = post_total(graph, &last.intersect(allowed))
+            .minus(&visited)
+            .intersect(&allowed.union(target));
+        if next.is_empty() {
+            return Ok(None);
+        }
+        visited = visited.union(&next);
+        layers.push(next);
+    }
+}
+
+/// Find a lasso-shaped trajectory from `start` which stays in `allowed` forever. Returns
+/// the path together with the index of the step where the final loop starts.
+///
+/// The `allowed` set must be closed in the sense that every state in it has a successor
+/// in it (e.g. the result of `EG phi`).
+fn find_lasso(
+    py: Python,
+    graph: &SymbolicAsyncGraph,
+    start: &GraphColoredVertices,
+    allowed: &GraphColoredVertices,
+) -> PyResult<Option<(Vec<GraphColoredVertices>, usize)>> {
+    if !start.is_subset(allowed) {
+        return Ok(None);
+    }
+    let mut path = vec![start.clone()];
+    loop {
+        py.check_signals()?;
+        let successors = post_total(graph, path.last().unwrap()).intersect(allowed);
+        if successors.is_empty() {
+            return Ok(None);
+        }
+        // Close the loop as soon as possible.
+        let loop_start = path
+            .iter()
+            .position(|it| !successors.intersect(it).is_empty());
+        if let Some(loop_start) = loop_start {
+            return Ok(Some((path, loop_start)));
+        }
+        path.push(successors.pick_singleton());
+    }
+}
+
+/// Annotate every step of the path with `step` except for the last one, which is
+/// annotated with `last`.
+fn annotate_path(
+    path: Vec<GraphColoredVertices>,
+    step: &HctlTreeNode,
+    last: &HctlTreeNode,
+) -> Vec<(GraphColoredVertices, HctlTreeNode)> {
+    let count = path.len();
+    path.into_iter()
+        .enumerate()
+        .map(|(i, it)| {
+            let node = if i + 1 == count { last } else { step };
+            (it, node.clone())
+        })
+        .collect()
+}
+
+/// Convert a singleton set into a `VertexModel` (extra symbolic variables are ignored).
+fn mk_vertex_model(ctx: &Py<SymbolicContext>, state: &GraphColoredVertices) -> VertexModel {
+    let valuation = state.vertices().as_bdd().sat_witness().unwrap();
+    let mut native = BddPartialValuation::empty();
+    for var in ctx.get().as_native().state_variables() {
+        native.set_value(*var, valuation[*var]);
     }
+    VertexModel::new_native(ctx.clone(), native)
 }
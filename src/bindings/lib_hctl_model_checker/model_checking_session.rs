@@ -0,0 +1,352 @@
+use std::collections::{HashMap, HashSet};
+
+use biodivine_hctl_model_checker::preprocessing::hctl_tree::{HctlTreeNode, NodeType};
+use biodivine_hctl_model_checker::preprocessing::operator_enums::{
+    Atomic, BinaryOp, HybridOp, UnaryOp,
+};
+use biodivine_lib_param_bn::symbolic_async_graph::GraphColoredVertices;
+use pyo3::prelude::PyAnyMethods;
+use pyo3::types::{PyDict, PyDictMethods, PyList};
+use pyo3::{Bound, Py, PyAny, PyResult, Python, pyclass, pymethods};
+
+use crate::bindings::lib_hctl_model_checker::builtin_propositions::{
+    collect_wild_cards, resolve_builtin_propositions,
+};
+use crate::bindings::lib_hctl_model_checker::hctl_formula::HctlFormula;
+use crate::bindings::lib_hctl_model_checker::model_checking::check_formulae;
+use crate::bindings::lib_param_bn::symbolic::asynchronous_graph::AsynchronousGraph;
+use crate::bindings::lib_param_bn::symbolic::set_colored_vertex::ColoredVertexSet;
+use crate::{AsNative, throw_type_error};
+
+/// A model checking "session" bound to a single `AsynchronousGraph` which remembers
+/// the results of all evaluated sub-formulas.
+///
+/// Each formula is first canonicalized (see `HctlFormula(..., minimize_with=...)`), meaning
+/// that sub-formulas which only differ in the names of quantified variables, or in
+/// redundant syntax, share the same cache entry. Repeated `ModelCheckingSession.verify`
+/// calls then only evaluate the sub-formulas that have not been seen before.
+///
+/// Only *closed* sub-formulas (without free state variables) that do not use extended
+/// propositions or quantifier domains are cached, since the meaning of the remaining
+/// sub-formulas depends on their context or on the provided `substitution`. Furthermore,
+/// only sub-formulas that start with a temporal or hybrid operator are cached. Boolean
+/// combinations of closed sub-formulas are computed directly from their (cached) results.
+///
+/// ```python
+/// bn = BooleanNetwork.from_aeon("a -> b\nb -| a")
+/// session = ModelCheckingSession(AsynchronousGraph.mk_for_model_checking(bn, 1))
+/// first = session.verify("AG EF (a & b)")
+/// second = session.verify("EF (a & b) & AG EF (a & b)")
+/// assert second == first
+/// assert session.statistics()["hits"] > 0
+/// ```
+#[pyclass(module = "biodivine_aeon")]
+pub struct ModelCheckingSession {
+    graph: Py<AsynchronousGraph>,
+    cache: HashMap<String, GraphColoredVertices>,
+    hits: usize,
+    misses: usize,
+}
+
+#[pymethods]
+impl ModelCheckingSession {
+    /// Create a new `ModelCheckingSession` with an empty cache.
+    ///
+    /// The same requirements on the `graph` apply as in `ModelChecking.verify` (i.e. it must
+    /// have enough extra symbolic variables for all quantified state variables).
+    #[new]
+    fn new(graph: Py<AsynchronousGraph>) -> ModelCheckingSession {
+        ModelCheckingSession {
+            graph,
+            cache: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    fn __str__(&self) -> String {
+        format!(
+            "ModelCheckingSession(cached = {}, hits = {}, misses = {})",
+            self.cache.len(),
+            self.hits,
+            self.misses
+        )
+    }
+
+    /// The `AsynchronousGraph` used by this session.
+    fn graph(&self) -> Py<AsynchronousGraph> {
+        self.graph.clone()
+    }
+
+    /// Verify the provided HCTL formula or formulas, returning the vertex-color pairs for which
+    /// the property holds.
+    ///
+    /// The arguments and results are the same as in `ModelChecking.verify`, but all evaluated
+    /// sub-formulas are stored in the cache of this session and reused by subsequent calls.
+    #[pyo3(signature = (property, substitution = None))]
+    fn verify<'a>(
+        &mut self,
+        py: Python<'a>,
+        property: &Bound<'a, PyAny>,
+        substitution: Option<HashMap<String, ColoredVertexSet>>,
+    ) -> PyResult<Bound<'a, PyAny>> {
+        let graph = self.graph.clone();
+        let graph = graph.get();
+        let ctx = graph.symbolic_context();
+        let ctx = ctx.get();
+        let mut substitution = substitution
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(a, b)| (a, b.as_native().clone()))
+            .collect::<HashMap<_, _>>();
+
+        let mut verify_one = |value: &Bound<'a, PyAny>| -> PyResult<Py<ColoredVertexSet>> {
+            let formula = HctlFormula::resolve_formula(value, true, Some(ctx))?;
+            resolve_builtin_propositions(py, graph, &[formula.__str__()], &mut substitution)?;
+            let result = self.evaluate(py, graph, formula.as_native(), &substitution)?;
+            Py::new(
+                py,
+                ColoredVertexSet::mk_native(graph.symbolic_context(), result),
+            )
+        };
+
+        if let Ok(list) = property.cast::<PyList>() {
+            let results = list
+                .iter()
+                .map(|it| verify_one(&it))
+                .collect::<PyResult<Vec<_>>>()?;
+            Ok(PyList::new(py, results)?.into_any())
+        } else if property.extract::<HctlFormula>().is_ok() || property.extract::<String>().is_ok()
+        {
+            Ok(verify_one(property)?.into_bound(py).into_any())
+        } else {
+            throw_type_error(format!(
+                "Expected `str`, `HctlFormula`, or `list`. Got {property:?}."
+            ))
+        }
+    }
+
+    /// Returns a `ModelCheckingStatistics` dictionary with the number of `cached_formulas`,
+    /// and the number of cache `hits` and `misses` since the session was created (or cleared).
+    fn statistics<'a>(&self, py: Python<'a>) -> PyResult<Bound<'a, PyDict>> {
+        let result = PyDict::new(py);
+        result.set_item("cached_formulas", self.cache.len())?;
+        result.set_item("hits", self.hits)?;
+        result.set_item("misses", self.misses)?;
+        Ok(result)
+    }
+
+    /// Remove all cached results and reset the cache statistics.
+    fn clear(&mut self) {
+        self.cache.clear();
+        self.hits = 0;
+        self.misses = 0;
+    }
+}
+
+impl ModelCheckingSession {
+    /// Evaluate a (canonical) formula, using and updating the cache for all eligible
+    /// sub-formulas.
+    fn evaluate(
+        &mut self,
+        py: Python,
+        graph: &AsynchronousGraph,
+        formula: &HctlTreeNode,
+        substitution: &HashMap<String, GraphColoredVertices>,
+    ) -> PyResult<GraphColoredVertices> {
+        py.check_signals()?;
+        if is_closed(formula) {
+            match &formula.node_type {
+                NodeType::Terminal(_) => (),
+                NodeType::Unary(UnaryOp::Not, a) => {
+                    let a = self.evaluate(py, graph, a, substitution)?;
+                    return Ok(graph.as_native().mk_unit_colored_vertices().minus(&a));
+                }
+                NodeType::Binary(op, a, b) if is_boolean(op) => {
+                    let a = self.evaluate(py, graph, a, substitution)?;
+                    let b = self.evaluate(py, graph, b, substitution)?;
+                    return Ok(apply_boolean(graph, op, &a, &b));
+                }
+                _ => return self.evaluate_cached(py, graph, formula, substitution),
+            }
+        }
+        self.evaluate_skeleton(py, graph, formula, substitution)
+    }
+
+    /// Evaluate a closed formula that starts with a temporal or hybrid operator, using
+    /// the cache if possible.
+    fn evaluate_cached(
+        &mut self,
+        py: Python,
+        graph: &AsynchronousGraph,
+        formula: &HctlTreeNode,
+        substitution: &HashMap<String, GraphColoredVertices>,
+    ) -> PyResult<GraphColoredVertices> {
+        let key = formula.to_string();
+        if let Some(result) = self.cache.get(&key) {
+            self.hits += 1;
+            return Ok(result.clone());
+        }
+        self.misses += 1;
+        let result = self.evaluate_skeleton(py, graph, formula, substitution)?;
+        self.cache.insert(key, result.clone());
+        Ok(result)
+    }
+
+    /// Evaluate the formula using the model checker, with all closed sub-formulas replaced
+    /// by extended propositions that refer to their (cached) results.
+    fn evaluate_skeleton(
+        &mut self,
+        py: Python,
+        graph: &AsynchronousGraph,
+        formula: &HctlTreeNode,
+        substitution: &HashMap<String, GraphColoredVertices>,
+    ) -> PyResult<GraphColoredVertices> {
+        let mut local = HashMap::new();
+        let skeleton = self.skeleton(py, graph, formula, substitution, &mut local)?;
+        for name in collect_wild_cards(formula) {
+            if let Some(set) = substitution.get(&name) {
+                local.insert(name, set.clone());
+            }
+        }
+        let local = if local.is_empty() { None } else { Some(&local) };
+        let result = check_formulae(graph, &[skeleton], local)?;
+        Ok(result.into_iter().next().unwrap())
+    }
+
+    /// Convert the formula into a string where every maximal closed sub-formula
+    /// (other than the formula itself and propositions) is replaced by a fresh extended proposition
+    /// whose value is saved into `local`.
+    fn skeleton(
+        &mut self,
+        py: Python,
+        graph: &AsynchronousGraph,
+        formula: &HctlTreeNode,
+        substitution: &HashMap<String, GraphColoredVertices>,
+        local: &mut HashMap<String, GraphColoredVertices>,
+    ) -> PyResult<String> {
+        let mut child = |node: &HctlTreeNode| -> PyResult<String> {
+            if matches!(node.node_type, NodeType::Terminal(_)) || !is_closed(node) {
+                return self.skeleton(py, graph, node, substitution, local);
+            }
+            let set = self.evaluate(py, graph, node, substitution)?;
+            let mut index = local.len();
+            let mut name = format!("_session_{index}");
+            while local.contains_key(&name) || substitution.contains_key(&name) {
+                index += 1;
+                name = format!("_session_{index}");
+            }
+            local.insert(name.clone(), set);
+            Ok(format!("%{name}%"))
+        };
+        Ok(match &formula.node_type {
+            NodeType::Terminal(_) => formula.to_string(),
+            NodeType::Unary(op, a) => format!("({} {})", unary_symbol(op), child(a)?),
+            NodeType::Binary(op, a, b) => {
+                let a = child(a)?;
+                let b = child(b)?;
+                format!("({a} {} {b})", binary_symbol(op))
+            }
+            NodeType::Hybrid(op, var, domain, a) => {
+                let domain = domain
+                    .as_ref()
+                    .map(|it| format!(" in %{it}%"))
+                    .unwrap_or_default();
+                format!("({}{{{var}}}{domain}: {})", hybrid_symbol(op), child(a)?)
+            }
+        })
+    }
+}
+
+/// A sub-formula is closed if it has no free state variables and does not depend
+/// on the user-provided substitution.
+fn is_closed(formula: &HctlTreeNode) -> bool {
+    free_variables(formula).is_some_and(|it| it.is_empty())
+}
+
+fn is_boolean(op: &BinaryOp) -> bool {
+    matches!(
+        op,
+        BinaryOp::And | BinaryOp::Or | BinaryOp::Xor | BinaryOp::Imp | BinaryOp::Iff
+    )
+}
+
+/// Apply a Boolean operator to the results of two sub-formulas.
+fn apply_boolean(
+    graph: &AsynchronousGraph,
+    op: &BinaryOp,
+    a: &GraphColoredVertices,
+    b: &GraphColoredVertices,
+) -> GraphColoredVertices {
+    let unit = graph.as_native().mk_unit_colored_vertices();
+    match op {
+        BinaryOp::And => a.intersect(b),
+        BinaryOp::Or => a.union(b),
+        BinaryOp::Xor => a.minus(b).union(&b.minus(a)),
+        BinaryOp::Imp => unit.minus(a).union(b),
+        BinaryOp::Iff => unit.minus(&a.minus(b).union(&b.minus(a))),
+        _ => unreachable!("Not a Boolean operator."),
+    }
+}
+
+/// Compute the free state variables of a formula, or `None` if the formula uses extended
+/// propositions or quantifier domains.
+fn free_variables(formula: &HctlTreeNode) -> Option<HashSet<String>> {
+    match &formula.node_type {
+        NodeType::Terminal(Atomic::Var(var)) => Some(HashSet::from([var.clone()])),
+        NodeType::Terminal(Atomic::WildCardProp(_)) => None,
+        NodeType::Terminal(_) => Some(HashSet::new()),
+        NodeType::Unary(_, a) => free_variables(a),
+        NodeType::Binary(_, a, b) => {
+            let mut result = free_variables(a)?;
+            result.extend(free_variables(b)?);
+            Some(result)
+        }
+        NodeType::Hybrid(_, _, Some(_), _) => None,
+        NodeType::Hybrid(HybridOp::Jump, var, None, a) => {
+            let mut result = free_variables(a)?;
+            result.insert(var.clone());
+            Some(result)
+        }
+        NodeType::Hybrid(_, var, None, a) => {
+            let mut result = free_variables(a)?;
+            result.remove(var);
+            Some(result)
+        }
+    }
+}
+
+fn unary_symbol(op: &UnaryOp) -> &'static str {
+    match op {
+        UnaryOp::Not => "~",
+        UnaryOp::EX => "EX",
+        UnaryOp::AX => "AX",
+        UnaryOp::EF => "EF",
+        UnaryOp::AF => "AF",
+        UnaryOp::EG => "EG",
+        UnaryOp::AG => "AG",
+    }
+}
+
+fn binary_symbol(op: &BinaryOp) -> &'static str {
+    match op {
+        BinaryOp::And => "&",
+        BinaryOp::Or => "|",
+        BinaryOp::Xor => "^",
+        BinaryOp::Imp => "=>",
+        BinaryOp::Iff => "<=>",
+        BinaryOp::EU => "EU",
+        BinaryOp::AU => "AU",
+        BinaryOp::EW => "EW",
+        BinaryOp::AW => "AW",
+    }
+}
+
+fn hybrid_symbol(op: &HybridOp) -> &'static str {
+    match op {
+        HybridOp::Exists => "3",
+        HybridOp::Forall => "V",
+        HybridOp::Bind => "!",
+        HybridOp::Jump => "@",
+    }
+}
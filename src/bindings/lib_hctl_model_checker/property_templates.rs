@@ -0,0 +1,200 @@
+use std::collections::{HashMap, HashSet};
+
+use biodivine_hctl_model_checker::preprocessing::parser::parse_extended_formula;
+use pyo3::prelude::PyAnyMethods;
+use pyo3::{Bound, PyAny, PyResult, pyclass, pymethods};
+
+use crate::bindings::lib_hctl_model_checker::builtin_propositions::is_identifier;
+use crate::bindings::lib_hctl_model_checker::hctl_formula::HctlFormula;
+use crate::bindings::lib_param_bn::argument_types::bool_type::BoolType;
+use crate::{throw_runtime_error, throw_type_error};
+
+/// An "algorithm object" that groups methods which build `HctlFormula` objects for commonly
+/// used dynamic properties.
+///
+/// The templates only use plain HCTL (no extended propositions), hence they can be used
+/// both in `ModelChecking.verify` and as properties of `Classification`. A phenotype
+/// argument can be given either as a state formula (`str` or `HctlFormula`, e.g. `"a & ~b"`),
+/// or as a dictionary which assigns values to (some) network variables
+/// (e.g. `{"a": True, "b": False}`).
+///
+/// All properties are intended for the "universal" interpretation used by
+/// `Classification.classify_dynamic_properties` (a color satisfies the property if it
+/// holds in every state). Each method also documents when the formula holds in
+/// an individual state.
+///
+/// The formulas use quantified state variables, so the `AsynchronousGraph` must be created
+/// using `AsynchronousGraph.mk_for_model_checking` (unless you let `ModelChecking.verify`
+/// prepare the graph for you).
+///
+/// ```python
+/// bn = BooleanNetwork.from_aeon("a -| b\nb -| a\n$a: !b\n$b: !a")
+/// toggle = PropertyTemplates.bistable({"a": True, "b": False}, {"a": False, "b": True})
+/// graph = AsynchronousGraph(bn)
+/// assert ModelChecking.verify(bn, toggle) == graph.mk_unit_colored_vertices()
+/// ```
+#[pyclass(module = "biodivine_aeon", frozen)]
+pub struct PropertyTemplates {
+    _dummy: (),
+}
+
+#[pymethods]
+impl PropertyTemplates {
+    /// A stable state (fixed point) which satisfies the `phenotype` is reachable from
+    /// the current state.
+    ///
+    /// Without a `phenotype`, any stable state is acceptable. The formula is
+    /// `EF (!{x}: (AX {x} & phenotype))`.
+    #[staticmethod]
+    #[pyo3(signature = (phenotype = None))]
+    pub fn reaches_stable_state(phenotype: Option<&Bound<'_, PyAny>>) -> PyResult<HctlFormula> {
+        let phenotype = phenotype.map(resolve_phenotype).transpose()?;
+        let used = used_state_variables(phenotype.iter());
+        let x = &fresh_state_variables(1, &used)[0];
+        let phenotype = phenotype.map(|it| it.__str__());
+        let phenotype = phenotype.as_deref().unwrap_or("true");
+        mk_formula(format!("EF (!{{{x}}}: (AX {{{x}}} & ({phenotype})))"))
+    }
+
+    /// There is an attractor in which the given network `variable` oscillates, i.e. the
+    /// attractor contains states where the `variable` is true as well as states where it
+    /// is false.
+    ///
+    /// The attractor does not have to be reachable from the current state. The formula is
+    /// `3{x}: @{x}: (AG EF {x} & EF variable & EF ~variable)`.
+    #[staticmethod]
+    pub fn oscillates(variable: &str) -> PyResult<HctlFormula> {
+        if !is_identifier(variable) {
+            return throw_runtime_error(format!("Invalid variable name `{variable}`."));
+        }
+        let x = &fresh_state_variables(1, &HashSet::new())[0];
+        mk_formula(format!(
+            "3{{{x}}}: @{{{x}}}: ((AG EF {{{x}}}) & (EF {variable}) & (EF ~{variable}))"
+        ))
+    }
+
+    /// There are two different attractors, such that the first fully resides within
+    /// `phenotype_a` and the second fully resides within `phenotype_b`.
+    ///
+    /// The attractors do not have to be reachable from the current state. The formula is
+    /// `3{x}: 3{y}: (@{x}: (AG EF {x} & AG phenotype_a & ~EF {y})) &
+    /// (@{y}: (AG EF {y} & AG phenotype_b))`.
+    #[staticmethod]
+    pub fn bistable(
+        phenotype_a: &Bound<'_, PyAny>,
+        phenotype_b: &Bound<'_, PyAny>,
+    ) -> PyResult<HctlFormula> {
+        let phenotype_a = resolve_phenotype(phenotype_a)?;
+        let phenotype_b = resolve_phenotype(phenotype_b)?;
+        let used = used_state_variables([&phenotype_a, &phenotype_b].into_iter());
+        let names = fresh_state_variables(2, &used);
+        let (x, y) = (&names[0], &names[1]);
+        let phenotype_a = phenotype_a.__str__();
+        let phenotype_b = phenotype_b.__str__();
+        mk_formula(format!(
+            "3{{{x}}}: 3{{{y}}}: ((@{{{x}}}: ((AG EF {{{x}}}) & (AG ({phenotype_a})) & ~(EF {{{y}}}))) & (@{{{y}}}: ((AG EF {{{y}}}) & (AG ({phenotype_b})))))"
+        ))
+    }
+
+    /// Once the `target` phenotype is reached, the `source` phenotype can never be visited
+    /// again, and every `source` state can actually reach the `target` phenotype.
+    ///
+    /// The `source` and `target` phenotypes are expected to be disjoint. The formula is
+    /// `AG ((source => EF target) & (target => AG ~source))`.
+    #[staticmethod]
+    pub fn irreversible(
+        source: &Bound<'_, PyAny>,
+        target: &Bound<'_, PyAny>,
+    ) -> PyResult<HctlFormula> {
+        let source = resolve_phenotype(source)?.__str__();
+        let target = resolve_phenotype(target)?.__str__();
+        mk_formula(format!(
+            "AG ((({source}) => (EF ({target}))) & (({target}) => (AG ~({source}))))"
+        ))
+    }
+
+    /// There are at least `count` different attractors (`count` must be positive).
+    ///
+    /// The attractors do not have to be reachable from the current state. The formula uses
+    /// `count` quantified state variables, each of which is required to be in an attractor
+    /// that cannot reach any of the following variables. For example, for `count = 2`, the
+    /// formula is `3{x}: 3{y}: (@{x}: (AG EF {x} & ~EF {y})) & (@{y}: AG EF {y})`.
+    #[staticmethod]
+    pub fn multistable(count: usize) -> PyResult<HctlFormula> {
+        if count == 0 {
+            return throw_runtime_error("The number of attractors must be positive.");
+        }
+        let names = fresh_state_variables(count, &HashSet::new());
+        let mut formula = String::new();
+        for name in &names {
+            formula.push_str(&format!("3{{{name}}}: "));
+        }
+        let attractors = names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let mut clause = format!("(AG EF {{{name}}})");
+                for other in &names[(i + 1)..] {
+                    clause.push_str(&format!(" & ~(EF {{{other}}})"));
+                }
+                format!("(@{{{name}}}: ({clause}))")
+            })
+            .collect::<Vec<_>>();
+        formula.push_str(&format!("({})", attractors.join(" & ")));
+        mk_formula(formula)
+    }
+}
+
+/// Convert a phenotype argument (`str`, `HctlFormula`, or a dictionary of variable values)
+/// into an `HctlFormula`.
+fn resolve_phenotype(phenotype: &Bound<'_, PyAny>) -> PyResult<HctlFormula> {
+    if phenotype.extract::<HctlFormula>().is_ok() || phenotype.extract::<String>().is_ok() {
+        return HctlFormula::resolve_formula(phenotype, false, None);
+    }
+    let Ok(values) = phenotype.extract::<HashMap<String, BoolType>>() else {
+        return throw_type_error(format!(
+            "Expected `str`, `HctlFormula`, or `dict[str, BoolType]`. Got {phenotype:?}."
+        ));
+    };
+    let mut values = values.into_iter().collect::<Vec<_>>();
+    values.sort_by(|(a, _), (b, _)| a.cmp(b));
+    let mut literals = Vec::new();
+    for (name, value) in values {
+        if !is_identifier(&name) {
+            return throw_runtime_error(format!("Invalid variable name `{name}`."));
+        }
+        literals.push(if value.bool() {
+            name
+        } else {
+            format!("~{name}")
+        });
+    }
+    if literals.is_empty() {
+        return mk_formula("true".to_string());
+    }
+    mk_formula(literals.join(" & "))
+}
+
+fn mk_formula(formula: String) -> PyResult<HctlFormula> {
+    match parse_extended_formula(formula.as_str()) {
+        Ok(native) => Ok(HctlFormula::from_native(native)),
+        Err(e) => throw_runtime_error(e),
+    }
+}
+
+fn used_state_variables<'a>(formulas: impl Iterator<Item = &'a HctlFormula>) -> HashSet<String> {
+    formulas.flat_map(|it| it.used_state_variables()).collect()
+}
+
+/// Pick `count` state variable names (`x`, `y`, `z`, and then `x_i`) that are not `used` by
+/// any of the phenotype formulas, such that the phenotypes cannot capture the template
+/// variables.
+fn fresh_state_variables(count: usize, used: &HashSet<String>) -> Vec<String> {
+    ["x", "y", "z"]
+        .into_iter()
+        .map(|it| it.to_string())
+        .chain((0..).map(|i| format!("x_{i}")))
+        .filter(|it| !used.contains(it))
+        .take(count)
+        .collect()
+}
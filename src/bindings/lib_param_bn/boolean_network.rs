@@ -1,20 +1,25 @@
 use super::regulatory_graph::RegulatoryGraph;
+use crate::bindings::lib_bdd::bdd::Bdd;
 use crate::bindings::lib_param_bn::argument_types::regulation::RegulationOutput;
 use crate::bindings::lib_param_bn::argument_types::regulation_type::RegulationType;
 use crate::bindings::lib_param_bn::argument_types::sign_type::SignType;
 use crate::bindings::lib_param_bn::argument_types::variable_id_multiple_type::VariableIdMultipleType;
 use crate::bindings::lib_param_bn::argument_types::variable_id_type::VariableIdType;
+use crate::bindings::lib_param_bn::network_reduction::{NetworkReduction, ReductionStep};
 use crate::bindings::lib_param_bn::parameter_id::ParameterId;
 use crate::bindings::lib_param_bn::symbolic::symbolic_context::SymbolicContext;
 use crate::bindings::lib_param_bn::update_function::UpdateFunction;
 use crate::bindings::lib_param_bn::variable_id::{VariableId, VariableIdResolvable};
+use crate::internal::smv::network_to_smv;
 use crate::pyo3_utils::richcmp_eq_by_key;
 use crate::{AsNative, runtime_error, throw_index_error, throw_runtime_error, throw_type_error};
+use biodivine_lib_bdd::BddVariable;
 use biodivine_lib_io_bma::BmaModel;
 use biodivine_lib_param_bn::symbolic_async_graph::RegulationConstraint as NativeRegulationConstraint;
 use biodivine_lib_param_bn::symbolic_async_graph::SymbolicContext as NativeSymbolicContext;
-use biodivine_lib_param_bn::{FnUpdate, Monotonicity};
+use biodivine_lib_param_bn::{BinaryOp, FnUpdate, Monotonicity};
 use macros::Wrapper;
+use pyo3::IntoPyObjectExt;
 use pyo3::basic::CompareOp;
 use pyo3::prelude::*;
 use std::collections::{HashMap, HashSet};
@@ -451,6 +456,268 @@ impl BooleanNetwork {
         )
     }
 
+    /// Create a copy of this `BooleanNetwork` in which the variables appear in the given `order`.
+    ///
+    /// The `order` must contain every network variable exactly once. Variable names,
+    /// regulations, parameters, and update functions are preserved, but the `VariableId`
+    /// objects of the new network follow the new ordering. Since a `SymbolicContext` encodes
+    /// the network variables in the order in which they appear in the network, this can be
+    /// used to build a `SymbolicContext` (or `AsynchronousGraph`) with a different ordering
+    /// of symbolic variables (see also `RegulatoryGraph.suggest_variable_order`):
+    ///
+    /// ```python
+    /// order = network.suggest_variable_order()
+    /// graph = AsynchronousGraph(network.reorder_variables(order))
+    /// ```
+    ///
+    /// Symbolic sets computed for the reordered network can be transferred back to a context
+    /// with the original ordering using `SymbolicContext.transfer_from` or
+    /// `AsynchronousGraph.transfer_from`.
+    pub fn reorder_variables(
+        self_: PyRef<'_, Self>,
+        py: Python,
+        order: Vec<VariableIdType>,
+    ) -> PyResult<Py<BooleanNetwork>> {
+        let native = self_.as_native();
+        let reordered_rg = self_.as_ref().reorder_variables(order.clone())?;
+        let order = self_.as_ref().resolve_permutation(order)?;
+        let variables = order
+            .iter()
+            .enumerate()
+            .map(|(i, old)| (*old, biodivine_lib_param_bn::VariableId::from_index(i)))
+            .collect::<HashMap<_, _>>();
+        let mut reordered_bn =
+            biodivine_lib_param_bn::BooleanNetwork::new(reordered_rg.as_native().clone());
+        let mut parameters = HashMap::new();
+        for id in native.parameters() {
+            let param = native.get_parameter(id);
+            let new_id = reordered_bn
+                .add_parameter(param.get_name(), param.get_arity())
+                .map_err(runtime_error)?;
+            parameters.insert(id, new_id);
+        }
+        for var in native.variables() {
+            if let Some(fun) = native.get_update_function(var) {
+                let fun = fun.rename_all(&variables, &parameters);
+                reordered_bn
+                    .set_update_function(variables[&var], Some(fun))
+                    .map_err(runtime_error)?;
+            }
+        }
+        Py::new(py, (BooleanNetwork(reordered_bn), reordered_rg))
+    }
+
+    /// Create a new `BooleanNetwork` which merges this network with the `other` network.
+    ///
+    /// By default, variables with the same name are considered to be shared between the two
+    /// networks. Alternatively, you can provide an explicit `mapping` which assigns variables
+    /// of the `other` network (keys) to names in the resulting network (values). A variable
+    /// of the `other` network that is not covered by the `mapping` retains its original name.
+    /// Explicit parameters are merged by name, but a parameter with the same name must also
+    /// have the same arity in both networks.
+    ///
+    /// The variables of this network keep their `VariableId` (and `ParameterId`) in the result.
+    /// The new variables of the `other` network are appended after them.
+    ///
+    /// The regulations of the result are the union of both regulatory graphs. When both networks
+    /// contain the same regulation, the sign is preserved only if it is the same in both
+    /// networks, and the regulation is essential only if it is essential in both networks.
+    ///
+    /// If a shared variable has an update function in only one of the networks, this function
+    /// is used. If both networks specify a function, the `conflict` policy decides the outcome:
+    ///  - `and`: The result is a conjunction of the two functions.
+    ///  - `or`: The result is a disjunction of the two functions.
+    ///  - `left`: The function and the regulations of this network are used, i.e. regulations
+    ///    of the `other` network that target this variable are discarded.
+    ///  - `parameter`: The function is replaced by a new explicit parameter that depends
+    ///    on all regulators of the variable (see `BooleanNetwork.assign_parameter_name`).
+    ///
+    /// Note that for `and` and `or`, a regulation that is only present in one of the networks
+    /// may stop being essential in the combined function. Such regulations are thus marked as
+    /// non-essential. If you set `repair_graph=True`, the regulatory graph is instead
+    /// inferred from the resulting update functions (see `BooleanNetwork.infer_valid_graph`).
+    #[pyo3(signature = (other, mapping = None, conflict = "and", repair_graph = false))]
+    pub fn compose(
+        self_: PyRef<'_, Self>,
+        py: Python,
+        other: PyRef<'_, BooleanNetwork>,
+        mapping: Option<HashMap<VariableIdType, String>>,
+        conflict: &str,
+        repair_graph: bool,
+    ) -> PyResult<Py<BooleanNetwork>> {
+        let conflict = ConflictPolicy::try_from(conflict)?;
+        let left = self_.as_native();
+        let right = other.as_native();
+
+        let mut right_names = HashMap::new();
+        if let Some(mapping) = mapping {
+            for (k, v) in mapping {
+                let k = k.resolve(right)?;
+                if !biodivine_lib_param_bn::BooleanNetwork::is_valid_name(v.as_str()) {
+                    return throw_runtime_error(format!("Invalid variable name `{v}`."));
+                }
+                right_names.insert(k, v);
+            }
+        }
+        for var in right.variables() {
+            right_names
+                .entry(var)
+                .or_insert_with(|| right.get_variable_name(var).clone());
+        }
+
+        // Variables of this network go first, followed by the new variables of the other network.
+        let mut names = left.as_graph().variable_names();
+        let mut used_names = HashSet::new();
+        for var in right.variables() {
+            let name = &right_names[&var];
+            if !used_names.insert(name.clone()) {
+                return throw_runtime_error(format!(
+                    "Multiple variables are mapped to the name `{name}`."
+                ));
+            }
+            if left.as_graph().find_variable(name).is_none() {
+                names.push(name.clone());
+            }
+        }
+        let mut rg = biodivine_lib_param_bn::RegulatoryGraph::new(names);
+        let right_vars: HashMap<_, _> = right
+            .variables()
+            .map(|var| {
+                let name = right_names[&var].as_str();
+                (var, rg.find_variable(name).unwrap())
+            })
+            .collect();
+        let rename_regulation = |reg: &biodivine_lib_param_bn::Regulation| {
+            (right_vars[&reg.regulator], right_vars[&reg.target])
+        };
+
+        // Shared variables which have an update function in both networks.
+        let conflicts: HashSet<biodivine_lib_param_bn::VariableId> = right
+            .variables()
+            .filter(|var| right.get_update_function(*var).is_some())
+            .map(|var| right_vars[&var])
+            .filter(|var| var.to_index() < left.num_vars())
+            .filter(|var| left.get_update_function(*var).is_some())
+            .collect();
+
+        // Merge regulations.
+        let left_keys: HashSet<_> = left
+            .as_graph()
+            .regulations()
+            .map(|reg| (reg.regulator, reg.target))
+            .collect();
+        let right_keys: HashSet<_> = right
+            .as_graph()
+            .regulations()
+            .map(rename_regulation)
+            .collect();
+        let mut regulations: HashMap<_, biodivine_lib_param_bn::Regulation> = HashMap::new();
+        for reg in left.as_graph().regulations() {
+            regulations.insert((reg.regulator, reg.target), reg.clone());
+        }
+        for reg in right.as_graph().regulations() {
+            let (regulator, target) = rename_regulation(reg);
+            if conflict == ConflictPolicy::Left && conflicts.contains(&target) {
+                // The target keeps the function (and regulations) of this network,
+                // so the regulation is unused.
+                continue;
+            } else if let Some(existing) = regulations.get_mut(&(regulator, target)) {
+                existing.observable = existing.observable && reg.observable;
+                if existing.monotonicity != reg.monotonicity {
+                    existing.monotonicity = None;
+                }
+            } else {
+                let reg = biodivine_lib_param_bn::Regulation {
+                    regulator,
+                    target,
+                    observable: reg.observable,
+                    monotonicity: reg.monotonicity,
+                };
+                regulations.insert((regulator, target), reg);
+            }
+        }
+        if matches!(conflict, ConflictPolicy::And | ConflictPolicy::Or) {
+            for (key, reg) in regulations.iter_mut() {
+                if conflicts.contains(&key.1) && left_keys.contains(key) != right_keys.contains(key)
+                {
+                    reg.observable = false;
+                }
+            }
+        }
+        let mut regulations = regulations.into_values().collect::<Vec<_>>();
+        regulations.sort_by_key(|it| (it.target, it.regulator));
+        for reg in regulations {
+            rg.add_raw_regulation(reg).map_err(runtime_error)?;
+        }
+
+        // Merge parameters.
+        let mut bn = biodivine_lib_param_bn::BooleanNetwork::new(rg);
+        for param in left.parameters() {
+            let param = left.get_parameter(param);
+            bn.add_parameter(param.get_name(), param.get_arity())
+                .map_err(runtime_error)?;
+        }
+        let mut right_params = HashMap::new();
+        for id in right.parameters() {
+            let param = right.get_parameter(id);
+            let new_id = if let Some(existing) = bn.find_parameter(param.get_name()) {
+                if bn.get_parameter(existing).get_arity() != param.get_arity() {
+                    return throw_runtime_error(format!(
+                        "Parameter `{}` has a different arity in each network.",
+                        param.get_name()
+                    ));
+                }
+                existing
+            } else {
+                bn.add_parameter(param.get_name(), param.get_arity())
+                    .map_err(runtime_error)?
+            };
+            right_params.insert(id, new_id);
+        }
+
+        // Merge update functions.
+        for var in left.variables() {
+            if let Some(fun) = left.get_update_function(var) {
+                bn.set_update_function(var, Some(fun.clone()))
+                    .map_err(runtime_error)?;
+            }
+        }
+        for var in right.variables() {
+            let Some(fun) = right.get_update_function(var) else {
+                continue;
+            };
+            let new_var = right_vars[&var];
+            let fun = fun.rename_all(&right_vars, &right_params);
+            let fun = if !conflicts.contains(&new_var) {
+                Some(fun)
+            } else {
+                let existing = bn.get_update_function(new_var).clone().unwrap();
+                match conflict {
+                    ConflictPolicy::And => Some(existing.and(fun)),
+                    ConflictPolicy::Or => Some(existing.or(fun)),
+                    ConflictPolicy::Left => Some(existing),
+                    ConflictPolicy::Parameter => None,
+                }
+            };
+            bn.set_update_function(new_var, fun)
+                .map_err(runtime_error)?;
+        }
+        if conflict == ConflictPolicy::Parameter {
+            let mut conflicts = Vec::from_iter(conflicts);
+            conflicts.sort();
+            for var in conflicts {
+                bn.assign_parameter_name(var, None).map_err(runtime_error)?;
+            }
+        }
+
+        let bn = if repair_graph {
+            bn.infer_valid_graph().map_err(runtime_error)?
+        } else {
+            bn
+        };
+        BooleanNetwork(bn).export_to_python(py)
+    }
+
     /// Produce a new `BooleanNetwork` where the given variable has been eliminated by inlining
     /// its update function into all downstream variables.
     ///
@@ -508,6 +775,249 @@ impl BooleanNetwork {
         BooleanNetwork(bn).export_to_python(py)
     }
 
+    /// Repeatedly apply attractor-preserving reductions to this `BooleanNetwork` until no
+    /// further reduction is possible. Returns the reduced network together with
+    /// a `NetworkReduction` object that can be used to lift results computed on the
+    /// reduced network back to the variables of this network.
+    ///
+    /// The `strategy` is a list of reductions that should be applied (by default, `constants`
+    /// and `outputs`):
+    ///  - `constants`: Variables with a constant update function (as determined symbolically)
+    ///    are inlined into their targets (see also `BooleanNetwork.inline_constants`).
+    ///  - `outputs`: Variables that do not regulate any other variable are removed.
+    ///  - `inline`: Variables without a self-regulation that only regulate a single other
+    ///    variable are inlined into this target (see also `BooleanNetwork.inline_variable`).
+    ///
+    /// The `constants` and `outputs` reductions preserve the asynchronous attractors
+    /// of the network. The `inline` reduction is thus not used by default, since it is only
+    /// guaranteed to preserve fixed points: it can create or destroy complex attractors.
+    ///
+    /// Note that inlining can introduce new explicit parameters when a variable is inlined into
+    /// a target with an unknown update function. The colors of the reduced network are thus
+    /// not always compatible with the colors of the original network.
+    #[pyo3(signature = (strategy = None))]
+    pub fn reduce(
+        self_: Py<BooleanNetwork>,
+        py: Python,
+        strategy: Option<Vec<String>>,
+    ) -> PyResult<(Py<BooleanNetwork>, NetworkReduction)> {
+        let steps = match strategy {
+            None => vec![ReductionStep::Constants, ReductionStep::Outputs],
+            Some(strategy) => strategy
+                .iter()
+                .map(|it| ReductionStep::try_from(it.as_str()))
+                .collect::<PyResult<Vec<_>>>()?,
+        };
+        let reduction = NetworkReduction::reduce(py, self_, &steps)?;
+        Ok((reduction.reduced_network(), reduction))
+    }
+
+    /// Build the *expanded network* of this `BooleanNetwork`. The expanded network makes
+    /// the AND-OR structure of the update functions explicit, which is required by analysis
+    /// methods like stable motifs or the logical domain of influence.
+    ///
+    /// For every variable `x`, the expanded network contains a node `x` and a node `not_x`
+    /// that represent the literals `x = 1` and `x = 0`. The function of `x` (resp. `not_x`)
+    /// is first converted to the disjunction of all prime implicants of `f_x` (resp. `!f_x`),
+    /// as computed by `Bdd.prime_implicants`. Every prime implicant with more than one literal
+    /// is then represented by a *composite* node (e.g. `and_a_not_b` for `a & !b`) whose update
+    /// function is the conjunction of the corresponding literal nodes. Composite nodes are
+    /// shared between all functions that contain the same conjunction. If two different
+    /// conjunctions would produce the same name (e.g. `x & y_z` and `x_y & z`), the name
+    /// of the later one receives a numeric suffix (`and_x_y_z_2`). The functions of literal
+    /// nodes are then disjunctions of composite and literal nodes. All regulations in the
+    /// expanded network are essential activations.
+    ///
+    /// Returns the expanded network together with a *provenance map* which assigns every node
+    /// of the expanded network the conjunction of literals (over the variables of this
+    /// network) that it represents. If `include_dnf=True`, the result additionally contains
+    /// a dictionary that assigns each literal node the prime implicants that were used to build
+    /// its update function.
+    ///
+    /// The method requires a fully specified network (i.e. no explicit or implicit
+    /// parameters). It also fails if a literal node name (e.g. `not_x`) collides with
+    /// an existing variable name.
+    #[pyo3(signature = (include_dnf = false))]
+    pub fn to_expanded_network(&self, py: Python, include_dnf: bool) -> PyResult<Py<PyAny>> {
+        let bn = self.as_native();
+        if bn.num_parameters() > 0 || bn.num_implicit_parameters() > 0 {
+            return throw_runtime_error(
+                "Expanded network can only be built for fully specified networks.",
+            );
+        }
+        let ctx = NativeSymbolicContext::new(bn).map_err(runtime_error)?;
+        let literal_name = |var: biodivine_lib_param_bn::VariableId, value: bool| {
+            let name = bn.get_variable_name(var);
+            if value {
+                name.clone()
+            } else {
+                format!("not_{name}")
+            }
+        };
+
+        // Node names and their provenance, in the order in which they are created.
+        let mut nodes: Vec<(String, Vec<(biodivine_lib_param_bn::VariableId, bool)>)> = Vec::new();
+        for value in [true, false] {
+            for var in bn.variables() {
+                nodes.push((literal_name(var, value), vec![(var, value)]));
+            }
+        }
+
+        // For every literal node, the list of nodes that appear in its disjunction
+        // (`None` represents a `true` constant).
+        let mut disjunctions: Vec<Option<Vec<String>>> = Vec::new();
+        let mut dnf: HashMap<String, Vec<HashMap<String, bool>>> = HashMap::new();
+        let mut composite: HashMap<Vec<(biodivine_lib_param_bn::VariableId, bool)>, String> =
+            HashMap::new();
+        let mut used_names: HashSet<String> = nodes.iter().map(|(name, _)| name.clone()).collect();
+        for value in [true, false] {
+            for var in bn.variables() {
+                let function = bn.get_update_function(var).as_ref().unwrap();
+                let function = ctx.mk_fn_update_true(function);
+                let function = if value { function } else { function.not() };
+                let clauses = Bdd::native_prime_implicants(py, &function, None)?;
+                let clauses = clauses
+                    .into_iter()
+                    .map(|clause| {
+                        clause
+                            .to_values()
+                            .into_iter()
+                            .map(|(bdd_var, literal)| {
+                                let var = ctx
+                                    .find_state_variable(bdd_var)
+                                    .expect("Correctness violation: Unknown state variable.");
+                                (var, literal)
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>();
+
+                if include_dnf {
+                    let items = clauses
+                        .iter()
+                        .map(|clause| {
+                            clause
+                                .iter()
+                                .map(|(var, literal)| {
+                                    (bn.get_variable_name(*var).clone(), *literal)
+                                })
+                                .collect::<HashMap<_, _>>()
+                        })
+                        .collect::<Vec<_>>();
+                    dnf.insert(literal_name(var, value), items);
+                }
+
+                if clauses.iter().any(|it| it.is_empty()) {
+                    disjunctions.push(None);
+                    continue;
+                }
+
+                let mut items = Vec::new();
+                for clause in clauses {
+                    if clause.len() == 1 {
+                        let (var, literal) = clause[0];
+                        items.push(literal_name(var, literal));
+                    } else if let Some(name) = composite.get(&clause) {
+                        items.push(name.clone());
+                    } else {
+                        let names = clause
+                            .iter()
+                            .map(|(var, literal)| literal_name(*var, *literal))
+                            .collect::<Vec<_>>();
+                        // Different conjunctions can produce the same name when variable
+                        // names contain `_` (e.g. `x & y_z` and `x_y & z`).
+                        let base = format!("and_{}", names.join("_"));
+                        let mut name = base.clone();
+                        let mut index = 2;
+                        while !used_names.insert(name.clone()) {
+                            name = format!("{base}_{index}");
+                            index += 1;
+                        }
+                        composite.insert(clause.clone(), name.clone());
+                        nodes.push((name.clone(), clause));
+                        items.push(name);
+                    }
+                }
+                disjunctions.push(Some(items));
+            }
+        }
+
+        let names = nodes
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect::<Vec<_>>();
+        if names.iter().collect::<HashSet<_>>().len() != names.len() {
+            return throw_runtime_error(
+                "Generated node names of the expanded network are not unique.",
+            );
+        }
+
+        let mut graph = biodivine_lib_param_bn::RegulatoryGraph::new(names);
+        let mut conjunctions = Vec::new();
+        for (name, literals) in nodes.iter().skip(disjunctions.len()) {
+            let inputs = literals
+                .iter()
+                .map(|(var, value)| literal_name(*var, *value))
+                .collect::<Vec<_>>();
+            for input in &inputs {
+                graph
+                    .add_regulation(input, name, true, Some(Monotonicity::Activation))
+                    .map_err(runtime_error)?;
+            }
+            conjunctions.push((name, inputs));
+        }
+        for ((name, _), items) in nodes.iter().zip(disjunctions.iter()) {
+            for input in items.iter().flatten() {
+                graph
+                    .add_regulation(input, name, true, Some(Monotonicity::Activation))
+                    .map_err(runtime_error)?;
+            }
+        }
+
+        let mut expanded = biodivine_lib_param_bn::BooleanNetwork::new(graph);
+        let mk_fn = |expanded: &biodivine_lib_param_bn::BooleanNetwork, inputs: &[String]| {
+            inputs
+                .iter()
+                .map(|it| FnUpdate::mk_var(expanded.as_graph().find_variable(it).unwrap()))
+                .collect::<Vec<_>>()
+        };
+        for ((name, _), items) in nodes.iter().zip(disjunctions.iter()) {
+            let var = expanded.as_graph().find_variable(name).unwrap();
+            let function = match items {
+                None => FnUpdate::Const(true),
+                Some(items) if items.is_empty() => FnUpdate::Const(false),
+                Some(items) => FnUpdate::mk_disjunction(&mk_fn(&expanded, items)),
+            };
+            expanded
+                .set_update_function(var, Some(function))
+                .map_err(runtime_error)?;
+        }
+        for (name, inputs) in conjunctions {
+            let var = expanded.as_graph().find_variable(name).unwrap();
+            let function = FnUpdate::mk_conjunction(&mk_fn(&expanded, &inputs));
+            expanded
+                .set_update_function(var, Some(function))
+                .map_err(runtime_error)?;
+        }
+
+        let provenance = nodes
+            .into_iter()
+            .map(|(name, literals)| {
+                let literals = literals
+                    .into_iter()
+                    .map(|(var, value)| (bn.get_variable_name(var).clone(), value))
+                    .collect::<HashMap<_, _>>();
+                (name, literals)
+            })
+            .collect::<HashMap<_, _>>();
+        let expanded = BooleanNetwork(expanded).export_to_python(py)?;
+        if include_dnf {
+            (expanded, provenance, dnf).into_py_any(py)
+        } else {
+            (expanded, provenance).into_py_any(py)
+        }
+    }
+
     /// Return *a copy* of the underlying `RegulatoryGraph` for this `BooleanNetwork`.
     #[allow(clippy::wrong_self_convention)]
     pub fn to_graph(self_: PyRef<'_, Self>) -> RegulatoryGraph {
@@ -590,6 +1100,35 @@ impl BooleanNetwork {
         self.as_native().to_sbml(None)
     }
 
+    /// Produce an SMV model (for NuSMV or nuXmv) of the state-transition graph of this
+    /// `BooleanNetwork`.
+    ///
+    /// The `semantics` can be either `"asynchronous"` (default) or `"synchronous"`. In the
+    /// asynchronous semantics, the input variable `choice` selects the network variable that
+    /// is updated, and states without any possible update have a self-loop (as in
+    /// `AsynchronousGraph`).
+    ///
+    /// Logical parameters are encoded as `FROZENVAR` declarations, one for every row of every
+    /// uninterpreted function: `p` or `p__010` for explicit parameters, and `v__implicit__010`
+    /// for the implicit update function of variable `v`. The regulation constraints are
+    /// encoded as an `INIT` condition on these variables.
+    ///
+    /// Returns an error if some name is not a valid SMV identifier (or is a reserved word).
+    /// Properties can be added using `HctlFormula.to_smv_ctl`.
+    #[pyo3(signature = (semantics = "asynchronous"))]
+    pub fn to_smv(&self, semantics: &str) -> PyResult<String> {
+        let synchronous = match semantics {
+            "asynchronous" => false,
+            "synchronous" => true,
+            _ => {
+                return throw_runtime_error(format!(
+                    "Unknown semantics `{semantics}`. Expected `asynchronous` or `synchronous`."
+                ));
+            }
+        };
+        network_to_smv(self.as_native(), synchronous).map_err(runtime_error)
+    }
+
     /// Try to load a `BooleanNetwork` from the contents of a BioModelsAnalyzer `.json` file.
     ///
     /// By default, multivalued models are binarized.
@@ -992,6 +1531,47 @@ impl BooleanNetwork {
         BooleanNetwork(new_bn).export_to_python(py)
     }
 
+    /// Make a copy of this `BooleanNetwork` where every update function is rewritten into
+    /// a canonical disjunctive normal form. Since the result only depends on the semantics
+    /// of each function (and the variable ordering), this can be used to normalize models
+    /// before storing or comparing them.
+    ///
+    /// The `mode` determines the normal form:
+    ///  - `minimal`: A small, irredundant cover by prime implicants (essential prime implicants
+    ///    plus a greedy cover of the remaining valuations, similar to Espresso). The result
+    ///    is not guaranteed to be the smallest DNF, but it is usually very close.
+    ///  - `blake`: The Blake canonical form, i.e. the disjunction of all prime implicants
+    ///    (see `Bdd.prime_implicants`).
+    ///  - `dnf`: The optimized DNF produced by `Bdd.to_dnf`.
+    ///
+    /// Applications of uninterpreted functions are treated as opaque propositions, i.e.
+    /// `f(a) | (f(a) & b)` becomes `f(a)`. The arguments of uninterpreted functions are
+    /// normalized recursively. If `skip_parametrized=True`, functions with uninterpreted
+    /// functions are preserved as they are instead. Missing (implicit) update functions
+    /// are never modified.
+    #[pyo3(signature = (mode = "minimal", skip_parametrized = false))]
+    pub fn canonicalize_functions(
+        &self,
+        py: Python,
+        mode: &str,
+        skip_parametrized: bool,
+    ) -> PyResult<Py<BooleanNetwork>> {
+        let form = CanonicalForm::try_from(mode)?;
+        let mut bn = self.as_native().clone();
+        for var in bn.variables() {
+            let Some(function) = bn.get_update_function(var) else {
+                continue;
+            };
+            if skip_parametrized && !function.collect_parameters().is_empty() {
+                continue;
+            }
+            let function = canonicalize_function(py, &bn, function, form)?;
+            bn.set_update_function(var, Some(function))
+                .map_err(runtime_error)?;
+        }
+        BooleanNetwork(bn).export_to_python(py)
+    }
+
     /// Returns `True` if the given `variable` is an input of the `BooleanNetwork`.
     ///
     /// Input can be either:
@@ -1118,6 +1698,283 @@ impl BooleanNetwork {
     }
 }
 
+/// The policy used by `BooleanNetwork.compose` to merge two conflicting update functions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ConflictPolicy {
+    And,
+    Or,
+    Left,
+    Parameter,
+}
+
+impl TryFrom<&str> for ConflictPolicy {
+    type Error = PyErr;
+
+    fn try_from(value: &str) -> PyResult<Self> {
+        match value {
+            "and" => Ok(ConflictPolicy::And),
+            "or" => Ok(ConflictPolicy::Or),
+            "left" => Ok(ConflictPolicy::Left),
+            "parameter" => Ok(ConflictPolicy::Parameter),
+            _ => throw_type_error("Expected one of `and`/`or`/`left`/`parameter`."),
+        }
+    }
+}
+
+/// The normal form used by `BooleanNetwork.canonicalize_functions`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CanonicalForm {
+    Minimal,
+    Blake,
+    Dnf,
+}
+
+impl TryFrom<&str> for CanonicalForm {
+    type Error = PyErr;
+
+    fn try_from(value: &str) -> PyResult<Self> {
+        match value {
+            "minimal" => Ok(CanonicalForm::Minimal),
+            "blake" => Ok(CanonicalForm::Blake),
+            "dnf" => Ok(CanonicalForm::Dnf),
+            _ => throw_type_error("Expected one of `minimal`/`blake`/`dnf`."),
+        }
+    }
+}
+
+/// Rewrite `function` (of the network `bn`) into the given canonical DNF. The first
+/// `bn.num_vars()` BDD variables represent network variables, the remaining ones represent
+/// (normalized) applications of uninterpreted functions, sorted by parameter name and then
+/// by arguments (such that the result does not depend on the order of operands).
+fn canonicalize_function(
+    py: Python,
+    bn: &biodivine_lib_param_bn::BooleanNetwork,
+    function: &FnUpdate,
+    form: CanonicalForm,
+) -> PyResult<FnUpdate> {
+    fn normalize_arguments(
+        py: Python,
+        bn: &biodivine_lib_param_bn::BooleanNetwork,
+        function: &FnUpdate,
+        form: CanonicalForm,
+    ) -> PyResult<FnUpdate> {
+        Ok(match function {
+            FnUpdate::Const(_) | FnUpdate::Var(_) => function.clone(),
+            FnUpdate::Param(id, args) => {
+                let args = args
+                    .iter()
+                    .map(|it| canonicalize_function(py, bn, it, form))
+                    .collect::<PyResult<Vec<_>>>()?;
+                FnUpdate::mk_param(*id, &args)
+            }
+            FnUpdate::Not(inner) => FnUpdate::mk_not(normalize_arguments(py, bn, inner, form)?),
+            FnUpdate::Binary(op, left, right) => FnUpdate::mk_binary(
+                *op,
+                normalize_arguments(py, bn, left, form)?,
+                normalize_arguments(py, bn, right, form)?,
+            ),
+        })
+    }
+
+    fn to_bdd(
+        function: &FnUpdate,
+        vars: &biodivine_lib_bdd::BddVariableSet,
+        atoms: &[FnUpdate],
+    ) -> biodivine_lib_bdd::Bdd {
+        match function {
+            FnUpdate::Const(value) => {
+                if *value {
+                    vars.mk_true()
+                } else {
+                    vars.mk_false()
+                }
+            }
+            FnUpdate::Var(id) => vars.mk_var(BddVariable::from_index(id.to_index())),
+            FnUpdate::Param(_, _) => {
+                let index = atoms.iter().position(|it| it == function).unwrap();
+                vars.mk_var(BddVariable::from_index(
+                    vars.num_vars() as usize - atoms.len() + index,
+                ))
+            }
+            FnUpdate::Not(inner) => to_bdd(inner, vars, atoms).not(),
+            FnUpdate::Binary(op, left, right) => {
+                let left = to_bdd(left, vars, atoms);
+                let right = to_bdd(right, vars, atoms);
+                match op {
+                    BinaryOp::And => left.and(&right),
+                    BinaryOp::Or => left.or(&right),
+                    BinaryOp::Xor => left.xor(&right),
+                    BinaryOp::Iff => left.iff(&right),
+                    BinaryOp::Imp => left.imp(&right),
+                }
+            }
+        }
+    }
+
+    fn collect_atoms(function: &FnUpdate, atoms: &mut Vec<FnUpdate>) {
+        match function {
+            FnUpdate::Const(_) | FnUpdate::Var(_) => (),
+            FnUpdate::Param(_, _) => {
+                if !atoms.contains(function) {
+                    atoms.push(function.clone());
+                }
+            }
+            FnUpdate::Not(inner) => collect_atoms(inner, atoms),
+            FnUpdate::Binary(_, left, right) => {
+                collect_atoms(left, atoms);
+                collect_atoms(right, atoms);
+            }
+        }
+    }
+
+    let num_vars = bn.num_vars();
+    let function = normalize_arguments(py, bn, function, form)?;
+    let mut atoms = Vec::new();
+    collect_atoms(&function, &mut atoms);
+    atoms.sort_by_cached_key(|atom| {
+        let FnUpdate::Param(id, args) = atom else {
+            unreachable!("Only uninterpreted functions are atoms.");
+        };
+        let args = args.iter().map(|it| it.to_string(bn)).collect::<Vec<_>>();
+        (bn.get_parameter(*id).get_name().clone(), args)
+    });
+    let Ok(num_bdd_vars) = u16::try_from(num_vars + atoms.len()) else {
+        return throw_runtime_error("Too many variables.");
+    };
+    let vars = biodivine_lib_bdd::BddVariableSet::new_anonymous(num_bdd_vars);
+    let bdd = to_bdd(&function, &vars, &atoms);
+
+    let mut clauses = match form {
+        CanonicalForm::Minimal => minimal_prime_cover(py, &bdd, &vars)?,
+        CanonicalForm::Blake => Bdd::native_prime_implicants(py, &bdd, None)?,
+        CanonicalForm::Dnf => bdd._to_optimized_dnf(true, &|_| py.check_signals())?,
+    };
+    clauses.sort_by_cached_key(|clause| {
+        clause
+            .to_values()
+            .into_iter()
+            .map(|(var, value)| (var.to_index(), value))
+            .collect::<Vec<_>>()
+    });
+
+    let clauses = clauses
+        .into_iter()
+        .map(|clause| {
+            let literals = clause
+                .to_values()
+                .into_iter()
+                .map(|(var, value)| {
+                    let index = var.to_index();
+                    let literal = if index < num_vars {
+                        FnUpdate::mk_var(biodivine_lib_param_bn::VariableId::from_index(index))
+                    } else {
+                        atoms[index - num_vars].clone()
+                    };
+                    if value {
+                        literal
+                    } else {
+                        FnUpdate::mk_not(literal)
+                    }
+                })
+                .collect::<Vec<_>>();
+            if literals.is_empty() {
+                FnUpdate::mk_true()
+            } else {
+                FnUpdate::mk_conjunction(&literals)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    Ok(if clauses.is_empty() {
+        FnUpdate::mk_false()
+    } else {
+        FnUpdate::mk_disjunction(&clauses)
+    })
+}
+
+/// Select an irredundant subset of the prime implicants of `bdd` that still covers `bdd`.
+///
+/// First, all essential prime implicants are selected. Then, the prime implicant covering
+/// the most remaining valuations is picked greedily until everything is covered. Finally,
+/// the redundant non-essential implicants are removed.
+fn minimal_prime_cover(
+    py: Python,
+    bdd: &biodivine_lib_bdd::Bdd,
+    vars: &biodivine_lib_bdd::BddVariableSet,
+) -> PyResult<Vec<biodivine_lib_bdd::BddPartialValuation>> {
+    let primes = Bdd::native_prime_implicants(py, bdd, None)?;
+    let cubes = primes
+        .iter()
+        .map(|it| vars.mk_conjunctive_clause(it))
+        .collect::<Vec<_>>();
+
+    // A prime is essential if it covers a valuation that no other prime covers.
+    let mut prefix = vec![vars.mk_false()];
+    for cube in &cubes {
+        prefix.push(prefix.last().unwrap().or(cube));
+    }
+    let mut suffix = vec![vars.mk_false()];
+    for cube in cubes.iter().rev() {
+        suffix.push(suffix.last().unwrap().or(cube));
+    }
+    suffix.reverse();
+    let mut selected = (0..cubes.len())
+        .map(|i| !cubes[i].and_not(&prefix[i].or(&suffix[i + 1])).is_false())
+        .collect::<Vec<_>>();
+    let essential = selected.clone();
+
+    let mut remaining = bdd.clone();
+    for (cube, _) in cubes.iter().zip(&selected).filter(|(_, s)| **s) {
+        remaining = remaining.and_not(cube);
+    }
+    while !remaining.is_false() {
+        py.check_signals()?;
+        let mut best: Option<(usize, f64)> = None;
+        for (i, cube) in cubes.iter().enumerate() {
+            if selected[i] {
+                continue;
+            }
+            let covered = cube.and(&remaining).cardinality();
+            let better = match best {
+                None => true,
+                Some((j, best_covered)) => {
+                    covered > best_covered
+                        || (covered == best_covered
+                            && primes[i].cardinality() < primes[j].cardinality())
+                }
+            };
+            if better {
+                best = Some((i, covered));
+            }
+        }
+        let Some((i, _)) = best else {
+            return throw_runtime_error("Prime implicants do not cover the function.");
+        };
+        selected[i] = true;
+        remaining = remaining.and_not(&cubes[i]);
+    }
+
+    for i in (0..cubes.len()).rev() {
+        if !selected[i] || essential[i] {
+            continue;
+        }
+        let others = cubes
+            .iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i && selected[*j])
+            .fold(vars.mk_false(), |acc, (_, cube)| acc.or(cube));
+        if bdd.imp(&others).is_true() {
+            selected[i] = false;
+        }
+    }
+
+    Ok(primes
+        .into_iter()
+        .zip(selected)
+        .filter_map(|(prime, selected)| selected.then_some(prime))
+        .collect())
+}
+
 fn convert_bma_model(
     model: BmaModel,
     binarize: bool,
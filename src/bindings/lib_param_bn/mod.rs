@@ -4,6 +4,7 @@ use pyo3::{Bound, PyResult};
 pub mod algorithms;
 pub mod boolean_network;
 pub mod model_annotation;
+pub mod network_reduction;
 pub mod parameter_id;
 pub mod regulatory_graph;
 pub mod symbolic;
@@ -20,6 +21,7 @@ pub fn register(module: &Bound<'_, PyModule>) -> PyResult<()> {
     module.add_class::<regulatory_graph::RegulatoryGraph>()?;
     module.add_class::<boolean_network::BooleanNetwork>()?;
     module.add_class::<update_function::UpdateFunction>()?;
+    module.add_class::<network_reduction::NetworkReduction>()?;
     module.add_class::<model_annotation::ModelAnnotationRoot>()?;
     module.add_class::<model_annotation::ModelAnnotation>()?;
     module.add_class::<symbolic::symbolic_context::SymbolicContext>()?;
@@ -0,0 +1,249 @@
+use crate::bindings::lib_param_bn::boolean_network::BooleanNetwork;
+use crate::bindings::lib_param_bn::symbolic::asynchronous_graph::AsynchronousGraph;
+use crate::bindings::lib_param_bn::symbolic::set_vertex::VertexSet;
+use crate::{AsNative, runtime_error, throw_runtime_error, throw_type_error};
+use biodivine_lib_param_bn::FnUpdate;
+use biodivine_lib_param_bn::symbolic_async_graph::GraphVertices;
+use pyo3::prelude::*;
+use std::collections::HashMap;
+
+/// Describes the relationship between a `BooleanNetwork` and its reduced counterpart
+/// produced by `BooleanNetwork.reduce`.
+///
+/// The object remembers which variables were eliminated and why. Using
+/// `NetworkReduction.lift`, you can then translate results computed on the reduced network
+/// (e.g. fixed points or attractors) back to the variables of the original network.
+///
+/// The default reductions (eliminating constants and output variables) preserve
+/// the (asynchronous) attractors of the network. Variable inlining (which must be requested
+/// explicitly) only preserves fixed points and can create or destroy complex attractors.
+/// With inlining, results about complex attractors of the reduced network are thus not
+/// guaranteed to hold for the original network.
+#[pyclass(module = "biodivine_aeon", frozen)]
+pub struct NetworkReduction {
+    original: Py<BooleanNetwork>,
+    reduced: Py<BooleanNetwork>,
+    eliminated: Vec<(String, Elimination)>,
+}
+
+/// The reason why a variable was removed from the network.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Elimination {
+    Constant(bool),
+    Output,
+    Inlined,
+}
+
+/// A single reduction that can be applied by `BooleanNetwork.reduce`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ReductionStep {
+    Constants,
+    Outputs,
+    Inline,
+}
+
+impl TryFrom<&str> for ReductionStep {
+    type Error = PyErr;
+
+    fn try_from(value: &str) -> PyResult<Self> {
+        match value {
+            "constants" => Ok(ReductionStep::Constants),
+            "outputs" => Ok(ReductionStep::Outputs),
+            "inline" => Ok(ReductionStep::Inline),
+            _ => throw_type_error("Expected one of `constants`/`outputs`/`inline`."),
+        }
+    }
+}
+
+#[pymethods]
+impl NetworkReduction {
+    pub fn __str__(&self) -> String {
+        format!(
+            "NetworkReduction(eliminated_variables={})",
+            self.eliminated.len()
+        )
+    }
+
+    pub fn __repr__(&self) -> String {
+        self.__str__()
+    }
+
+    /// The `BooleanNetwork` before the reduction.
+    pub fn original_network(&self) -> Py<BooleanNetwork> {
+        self.original.clone()
+    }
+
+    /// The `BooleanNetwork` after the reduction.
+    pub fn reduced_network(&self) -> Py<BooleanNetwork> {
+        self.reduced.clone()
+    }
+
+    /// The names of all variables that were removed from the original network, in the
+    /// order in which they were removed.
+    pub fn eliminated_variables(&self) -> Vec<String> {
+        self.eliminated
+            .iter()
+            .map(|(name, _)| name.clone())
+            .collect()
+    }
+
+    /// The names of the eliminated variables that have a constant value, together with
+    /// said value. In every attractor of the original network, these variables are fixed
+    /// to the given value.
+    pub fn eliminated_constants(&self) -> HashMap<String, bool> {
+        self.eliminated
+            .iter()
+            .filter_map(|(name, reason)| match reason {
+                Elimination::Constant(value) => Some((name.clone(), *value)),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Translate a `VertexSet` of the reduced network into a `VertexSet` of the original
+    /// network. The `graph` argument must be an `AsynchronousGraph` of the original network,
+    /// and the result uses its encoding.
+    ///
+    /// Eliminated constants are always fixed to their constant value. Other eliminated
+    /// variables are unconstrained by default. With the default reductions, the lifted set
+    /// of an attractor is thus a superset of the corresponding attractor in the original
+    /// network. If the `inline` reduction was applied, this is only guaranteed
+    /// for fixed points.
+    ///
+    /// If `fixed_points=True`, every eliminated variable `x` is additionally required to
+    /// satisfy `x = f_x` (with respect to the update functions of the original network).
+    /// When `set` contains fixed points of the reduced network, the result is then exactly
+    /// the set of corresponding fixed points of the original network. For a parametrized
+    /// network, the result contains a vertex if it satisfies this condition for at
+    /// least one color of the `graph`.
+    #[pyo3(signature = (graph, set, fixed_points = false))]
+    pub fn lift(
+        &self,
+        py: Python,
+        graph: &AsynchronousGraph,
+        set: &VertexSet,
+        fixed_points: bool,
+    ) -> PyResult<VertexSet> {
+        let ctx = graph.symbolic_context();
+        let native_ctx = ctx.get().as_native();
+        if native_ctx.num_state_variables() != self.original.borrow(py).as_native().num_vars() {
+            return throw_runtime_error("The graph does not belong to the original network.");
+        }
+        let set_ctx = set.__ctx__();
+        let Some(mut bdd) =
+            native_ctx.transfer_from(set.as_native().as_bdd(), set_ctx.get().as_native())
+        else {
+            return throw_runtime_error("The set is not compatible with the original network.");
+        };
+
+        for (name, reason) in &self.eliminated {
+            let Some(var) = native_ctx.find_network_variable(name) else {
+                return throw_runtime_error(format!("Unknown network variable `{name}`."));
+            };
+            let var_bdd = native_ctx.mk_state_variable_is_true(var);
+            match reason {
+                Elimination::Constant(true) => bdd = bdd.and(&var_bdd),
+                Elimination::Constant(false) => bdd = bdd.and_not(&var_bdd),
+                Elimination::Output | Elimination::Inlined if fixed_points => {
+                    let update = graph.as_native().get_symbolic_fn_update(var);
+                    bdd = bdd.and(&var_bdd.iff(update));
+                }
+                _ => (),
+            }
+        }
+
+        if fixed_points {
+            let unit = graph.as_native().unit_colored_vertices().as_bdd();
+            bdd = bdd.and(unit).exists(native_ctx.parameter_variables());
+        }
+
+        let native = GraphVertices::new(bdd, native_ctx);
+        Ok(VertexSet::mk_native(ctx, native))
+    }
+}
+
+impl NetworkReduction {
+    /// Repeatedly apply the given reduction `steps` to `network` until no further
+    /// reduction is possible.
+    pub fn reduce(
+        py: Python,
+        network: Py<BooleanNetwork>,
+        steps: &[ReductionStep],
+    ) -> PyResult<NetworkReduction> {
+        let mut bn = network.borrow(py).as_native().clone();
+        let mut eliminated = Vec::new();
+        loop {
+            py.check_signals()?;
+            let mut changed = false;
+
+            if steps.contains(&ReductionStep::Constants) {
+                // Inlining a constant does not change the semantics of the remaining
+                // functions, hence the other constants stay constant.
+                let constants = bn
+                    .constants(true)
+                    .into_iter()
+                    .map(|(var, value)| (bn.get_variable_name(var).clone(), value))
+                    .collect::<Vec<_>>();
+                for (name, value) in constants {
+                    let var = bn.as_graph().find_variable(name.as_str()).unwrap();
+                    bn.set_update_function(var, Some(FnUpdate::Const(value)))
+                        .map_err(runtime_error)?;
+                    bn = eliminate_variable(bn, var, true)?;
+                    eliminated.push((name, Elimination::Constant(value)));
+                    changed = true;
+                }
+            }
+
+            if steps.contains(&ReductionStep::Outputs) {
+                while let Some(var) = bn.variables().find(|var| bn.targets(*var).is_empty()) {
+                    let name = bn.get_variable_name(var).clone();
+                    // The function of an output is irrelevant for the rest of the network.
+                    bn.set_update_function(var, None).map_err(runtime_error)?;
+                    bn = eliminate_variable(bn, var, false)?;
+                    eliminated.push((name, Elimination::Output));
+                    changed = true;
+                }
+            }
+
+            if steps.contains(&ReductionStep::Inline) {
+                while let Some(var) = bn.variables().find(|var| {
+                    let targets = bn.targets(*var);
+                    targets.len() == 1 && targets[0] != *var
+                }) {
+                    let name = bn.get_variable_name(var).clone();
+                    bn = eliminate_variable(bn, var, true)?;
+                    eliminated.push((name, Elimination::Inlined));
+                    changed = true;
+                }
+            }
+
+            if !changed {
+                break;
+            }
+        }
+
+        Ok(NetworkReduction {
+            original: network,
+            reduced: BooleanNetwork::from(bn).export_to_python(py)?,
+            eliminated,
+        })
+    }
+}
+
+/// Inline the given variable into its targets. A self-regulation of the variable is removed
+/// first, which is only valid if the update function of the variable does not depend on it.
+fn eliminate_variable(
+    mut bn: biodivine_lib_param_bn::BooleanNetwork,
+    var: biodivine_lib_param_bn::VariableId,
+    repair_graph: bool,
+) -> PyResult<biodivine_lib_param_bn::BooleanNetwork> {
+    if bn.as_graph().find_regulation(var, var).is_some() {
+        bn.as_graph_mut()
+            .remove_regulation(var, var)
+            .map_err(runtime_error)?;
+    }
+    let Some(bn) = bn.inline_variable(var, repair_graph) else {
+        return throw_runtime_error("Variable has a self-regulation.");
+    };
+    Ok(bn.prune_unused_parameters())
+}
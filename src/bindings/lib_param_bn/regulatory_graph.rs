@@ -14,7 +14,7 @@ use macros::Wrapper;
 use pyo3::basic::CompareOp;
 use pyo3::prelude::*;
 use pyo3::types::PyList;
-use std::collections::HashSet;
+use std::collections::{HashSet, VecDeque};
 
 /// A regulatory graph is a directed graph consisting of network *variables* connected using
 /// *regulations*. Each regulation can be labeled as *essential* (also known as *observable*),
@@ -355,6 +355,35 @@ impl RegulatoryGraph {
         Ok(result)
     }
 
+    /// Create a copy of this `RegulatoryGraph` in which the variables appear in the given `order`.
+    ///
+    /// The `order` must contain every variable of this graph exactly once. The variable names
+    /// and regulations are preserved, but the `VariableId` objects of the new graph follow
+    /// the new ordering. Together with `RegulatoryGraph.suggest_variable_order`, this can be
+    /// used to change the ordering of symbolic variables in a `SymbolicContext`.
+    pub fn reorder_variables(&self, order: Vec<VariableIdType>) -> PyResult<RegulatoryGraph> {
+        let order = self.resolve_permutation(order)?;
+        let names = order
+            .iter()
+            .map(|it| self.as_native().get_variable_name(*it).clone())
+            .collect::<Vec<_>>();
+        let mut result = Self::new(Some(names), None)?;
+        for reg in self.as_native().regulations() {
+            let source = self.as_native().get_variable_name(reg.get_regulator());
+            let target = self.as_native().get_variable_name(reg.get_target());
+            result
+                .as_native_mut()
+                .add_regulation(
+                    source.as_str(),
+                    target.as_str(),
+                    reg.is_observable(),
+                    reg.get_monotonicity(),
+                )
+                .map_err(runtime_error)?;
+        }
+        Ok(result)
+    }
+
     /// Inline a variable into its downstream targets. This also "merges" the essential and sign flags of
     /// the associated regulations in a way that makes sense for the existing constraints (e.g. `+` and `-` becomes
     /// `-`, `-` and `-` becomes `+`; a regulation is essential if both "partial" regulations are essential, etc.).
@@ -599,6 +628,67 @@ impl RegulatoryGraph {
 
         Ok(cycle.map(|c| c.into_iter().map(VariableId::from).collect()))
     }
+
+    /// Compute a variable ordering which places variables that regulate each other close
+    /// together. Such ordering typically results in smaller symbolic representations
+    /// (i.e., smaller `Bdd` objects) than the default ordering of the network variables.
+    ///
+    /// The ordering is computed using the reverse Cuthill-McKee heuristic on the undirected
+    /// version of the regulatory graph: every weakly connected component is explored in
+    /// breadth-first order, starting from a variable with the smallest number of
+    /// regulations, and visiting neighbours with fewer regulations first.
+    ///
+    /// The result only determines the ordering of the network (state) variables. The placement
+    /// of the symbolic variables of uninterpreted functions is chosen by `SymbolicContext`
+    /// and cannot be changed this way. Also, the heuristic is purely static, i.e. it does not
+    /// refine the ordering by sifting on the actual update functions.
+    ///
+    /// Use `RegulatoryGraph.reorder_variables` (or `BooleanNetwork.reorder_variables`)
+    /// to apply the ordering. Results can be then transferred back to a context with
+    /// the original ordering using `SymbolicContext.transfer_from` or
+    /// `AsynchronousGraph.transfer_from`.
+    pub fn suggest_variable_order(&self) -> Vec<VariableId> {
+        let graph = self.as_native();
+        let neighbours = graph
+            .variables()
+            .map(|var| {
+                let mut result = graph.regulators(var);
+                result.extend(graph.targets(var));
+                result.retain(|it| *it != var);
+                result.sort();
+                result.dedup();
+                result
+            })
+            .collect::<Vec<_>>();
+        let degree = |var: &biodivine_lib_param_bn::VariableId| neighbours[var.to_index()].len();
+
+        let mut visited = vec![false; graph.num_vars()];
+        let mut order = Vec::with_capacity(graph.num_vars());
+        let mut candidates = graph.variables().collect::<Vec<_>>();
+        candidates.sort_by_key(|it| (degree(it), *it));
+        for start in candidates {
+            if visited[start.to_index()] {
+                continue;
+            }
+            visited[start.to_index()] = true;
+            let mut queue = VecDeque::from([start]);
+            while let Some(var) = queue.pop_front() {
+                order.push(var);
+                let mut next = neighbours[var.to_index()]
+                    .iter()
+                    .filter(|it| !visited[it.to_index()])
+                    .cloned()
+                    .collect::<Vec<_>>();
+                next.sort_by_key(|it| (degree(it), *it));
+                for it in next {
+                    visited[it.to_index()] = true;
+                    queue.push_back(it);
+                }
+            }
+        }
+
+        order.into_iter().rev().map(VariableId::from).collect()
+    }
 }
 
 impl RegulatoryGraph {
@@ -615,4 +705,20 @@ impl RegulatoryGraph {
 
         VariableIdType::resolve_collection(variables, self.as_native())
     }
+
+    /// Resolve a list of variables which must contain every variable of this graph exactly once.
+    pub fn resolve_permutation(
+        &self,
+        order: Vec<VariableIdType>,
+    ) -> PyResult<Vec<biodivine_lib_param_bn::VariableId>> {
+        let order: Vec<biodivine_lib_param_bn::VariableId> =
+            VariableIdType::resolve_collection(order, self.as_native())?;
+        let unique = order.iter().collect::<HashSet<_>>();
+        if order.len() != self.as_native().num_vars() || unique.len() != order.len() {
+            return throw_runtime_error(
+                "The order must contain every network variable exactly once.",
+            );
+        }
+        Ok(order)
+    }
 }
@@ -16,7 +16,9 @@ use crate::{AsNative, runtime_error, throw_runtime_error, throw_type_error};
 use biodivine_hctl_model_checker::mc_utils::get_extended_symbolic_graph;
 use biodivine_lib_bdd::boolean_expression::BooleanExpression as RsBooleanExpression;
 use biodivine_lib_bdd::{BddValuation, BddVariable};
-use biodivine_lib_param_bn::symbolic_async_graph::{GraphColors, SymbolicAsyncGraph};
+use biodivine_lib_param_bn::symbolic_async_graph::{
+    GraphColoredVertices, GraphColors, GraphVertices, SymbolicAsyncGraph,
+};
 use either::{Left, Right};
 use pyo3::IntoPyObjectExt;
 use pyo3::prelude::*;
@@ -406,12 +408,19 @@ impl AsynchronousGraph {
 
     /// Transfer a symbolic set (`ColorSet`, `VertexSet`, or `ColoredVertexSet`) from a compatible `AsynchronousGraph`
     /// into the encoding of this graph.
+    ///
+    /// The graphs can also be based on networks with a different variable ordering
+    /// (see `BooleanNetwork.reorder_variables` and `SymbolicContext.transfer_from`).
     pub fn transfer_from(
         &self,
         py: Python,
         set: &Bound<'_, PyAny>,
         original_ctx: &AsynchronousGraph,
     ) -> PyResult<Py<PyAny>> {
+        let ctx = self.ctx.get();
+        if ctx.is_reordering_of(original_ctx.ctx.get()) {
+            return self.transfer_reordered(py, set, original_ctx);
+        }
         let set = if let Ok(set) = set.extract::<ColorSet>() {
             self.as_native()
                 .transfer_colors_from(set.as_native(), original_ctx.as_native())
@@ -929,4 +938,45 @@ impl AsynchronousGraph {
         )?;
         Ok(AsynchronousGraph { ctx, native: stg })
     }
+
+    /// Same as `AsynchronousGraph.restrict`, but the result keeps the `SymbolicContext`
+    /// of this graph (including the reference to the original network).
+    pub fn restrict_native(&self, set: &GraphColoredVertices) -> AsynchronousGraph {
+        AsynchronousGraph {
+            ctx: self.ctx.clone(),
+            native: self.native.restrict(set),
+        }
+    }
+
+    /// Transfer a symbolic set from a graph that encodes the same network variables, but
+    /// in a different order (see `AsynchronousGraph.transfer_from`).
+    fn transfer_reordered(
+        &self,
+        py: Python,
+        set: &Bound<'_, PyAny>,
+        original_ctx: &AsynchronousGraph,
+    ) -> PyResult<Py<PyAny>> {
+        let ctx = self.ctx.get();
+        let transfer = |bdd: &biodivine_lib_bdd::Bdd| {
+            let Some(bdd) = ctx.transfer_native(py, bdd, original_ctx.ctx.get()) else {
+                return throw_runtime_error("The two contexts are not compatible.");
+            };
+            Ok(bdd)
+        };
+        if let Ok(set) = set.extract::<ColorSet>() {
+            let bdd = transfer(set.as_native().as_bdd())?;
+            let colors = GraphColors::new(bdd, ctx.as_native());
+            ColorSet::mk_native(self.ctx.clone(), colors).into_py_any(py)
+        } else if let Ok(set) = set.extract::<VertexSet>() {
+            let bdd = transfer(set.as_native().as_bdd())?;
+            let vertices = GraphVertices::new(bdd, ctx.as_native());
+            VertexSet::mk_native(self.ctx.clone(), vertices).into_py_any(py)
+        } else if let Ok(set) = set.extract::<ColoredVertexSet>() {
+            let bdd = transfer(set.as_native().as_bdd())?;
+            let vertices = GraphColoredVertices::new(bdd, ctx.as_native());
+            ColoredVertexSet::mk_native(self.ctx.clone(), vertices).into_py_any(py)
+        } else {
+            throw_type_error("Expected `ColorSet`, `VertexSet`, or `ColoredVertexSet`.")
+        }
+    }
 }
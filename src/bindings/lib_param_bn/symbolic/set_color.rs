@@ -9,7 +9,9 @@ use crate::bindings::lib_param_bn::symbolic::set_colored_space::ColoredSpaceSet;
 use crate::bindings::lib_param_bn::symbolic::set_colored_vertex::ColoredVertexSet;
 use crate::bindings::lib_param_bn::symbolic::set_spaces::SpaceSet;
 use crate::bindings::lib_param_bn::symbolic::set_vertex::VertexSet;
-use crate::bindings::lib_param_bn::symbolic::symbolic_context::SymbolicContext;
+use crate::bindings::lib_param_bn::symbolic::symbolic_context::{
+    SymbolicContext, SymbolicSetKind, decode_symbolic_set, encode_symbolic_set,
+};
 use crate::bindings::pbn_control::{ColoredPerturbationSet, PerturbationSet};
 use biodivine_lib_bdd::random_sampling::UniformValuationSampler;
 use biodivine_lib_bdd::{Bdd as RsBdd, BddPartialValuation};
@@ -24,7 +26,7 @@ use num_bigint::BigUint;
 use pyo3::IntoPyObjectExt;
 use pyo3::basic::CompareOp;
 use pyo3::prelude::*;
-use pyo3::types::PyList;
+use pyo3::types::{PyBytes, PyDict, PyList};
 use rand::SeedableRng;
 use rand::prelude::StdRng;
 
@@ -123,9 +125,34 @@ impl ColorSet {
         self_.clone()
     }
 
-    fn __getnewargs__(&self, py: Python) -> (Py<SymbolicContext>, Bdd) {
-        let bdd = self.to_bdd(py);
-        (self.ctx.clone(), bdd)
+    /// Pickle support: the set is stored using `ColorSet.to_bytes`, hence it can only be unpickled
+    /// if the encoding of its context did not change.
+    fn __reduce__<'a>(
+        &self,
+        py: Python<'a>,
+    ) -> PyResult<(Py<PyAny>, (Py<SymbolicContext>, Bound<'a, PyBytes>))> {
+        let from_bytes = py.get_type::<ColorSet>().getattr("from_bytes")?;
+        Ok((from_bytes.into(), (self.ctx.clone(), self.to_bytes(py))))
+    }
+
+    /// Serialize this `ColorSet` into `bytes` which can be loaded using `ColorSet.from_bytes` (see
+    /// `SymbolicContext.fingerprint` for details).
+    pub fn to_bytes<'a>(&self, py: Python<'a>) -> Bound<'a, PyBytes> {
+        let data = encode_symbolic_set(
+            self.ctx.get().as_native(),
+            SymbolicSetKind::Colors,
+            self.as_native().as_bdd(),
+        );
+        PyBytes::new(py, &data)
+    }
+
+    /// Load a `ColorSet` created by `ColorSet.to_bytes` into the given context. Throws a
+    /// `RuntimeError` if the context is not compatible.
+    #[staticmethod]
+    pub fn from_bytes(ctx: Py<SymbolicContext>, data: &[u8]) -> PyResult<ColorSet> {
+        let bdd = decode_symbolic_set(ctx.get().as_native(), SymbolicSetKind::Colors, data)?;
+        let native = GraphColors::new(bdd, ctx.get().as_native());
+        Ok(ColorSet { ctx, native })
     }
 
     pub fn __hash__(&self) -> u64 {
@@ -147,6 +174,31 @@ impl ColorSet {
         self.as_native().exact_cardinality()
     }
 
+    /// Compute the probability of this set of colors, assuming every symbolic variable
+    /// of the uninterpreted functions (i.e., every entry of every function table) is
+    /// independently `true` with the probability given in `prior`.
+    ///
+    /// The `prior` maps `BddVariable` objects (or their names) to probabilities. Variables
+    /// that are not in `prior` are `true` with probability `1/2`. Hence, without a `prior`,
+    /// the result is the fraction of all possible function interpretations that belong to
+    /// this set. Note that this fraction does not take into account whether a color
+    /// satisfies the constraints of the regulatory graph (use `ColorSet.cardinality` and
+    /// `AsynchronousGraph.mk_unit_colors` to compute the fraction of valid colors).
+    ///
+    /// See `Bdd.weighted_count` for more details (including the `exact` argument).
+    #[pyo3(signature = (prior = None, exact = false))]
+    pub fn probability(
+        &self,
+        py: Python,
+        prior: Option<&Bound<'_, PyDict>>,
+        exact: bool,
+    ) -> PyResult<Py<PyAny>> {
+        let empty = PyDict::new(py);
+        let prior = prior.unwrap_or(&empty);
+        let bdd_vars = self.ctx.get().bdd_variable_set();
+        Bdd::native_weighted_count(py, bdd_vars.get(), self.as_native().as_bdd(), prior, exact)
+    }
+
     /// Set intersection.
     pub fn intersect(&self, other: &ColorSet) -> ColorSet {
         self.mk_derived(self.as_native().intersect(other.as_native()))
@@ -192,6 +244,35 @@ impl ColorSet {
         self.mk_derived(self.as_native().pick_singleton())
     }
 
+    /// Pick the color (function interpretation) from this set that maximizes the linear
+    /// `score`, or `None` if this set is empty.
+    ///
+    /// The `score` maps the symbolic variables of the uninterpreted functions (i.e., entries
+    /// of their function tables, either as `BddVariable` objects or their names) to numbers.
+    /// The score of a color is then the sum of the weights of all table entries that are
+    /// `true` in this color. Entries that are not in `score` have weight `0`.
+    ///
+    /// See `Bdd.valuation_max_weight` for more details.
+    pub fn best_color(&self, score: &Bound<'_, PyDict>) -> PyResult<Option<ColorModel>> {
+        let ctx = self.ctx.get();
+        let bdd_vars = ctx.bdd_variable_set();
+        let bdd = self.as_native().as_bdd();
+        let Some(valuation) = Bdd::native_valuation_max_weight(bdd_vars.get(), bdd, score)? else {
+            return Ok(None);
+        };
+        let mut color = BddPartialValuation::empty();
+        for var in ctx.as_native().parameter_variables() {
+            color.set_value(*var, valuation[*var]);
+        }
+        let (_, implicit, explicit) = Self::read_retained_functions(ctx, None)?;
+        Ok(Some(ColorModel::new_native(
+            self.ctx.clone(),
+            color,
+            implicit,
+            explicit,
+        )))
+    }
+
     /// The number of `Bdd` nodes that are used to represent this set.
     pub fn symbolic_size(&self) -> usize {
         self.as_native().symbolic_size()
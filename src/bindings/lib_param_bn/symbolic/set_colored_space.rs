@@ -14,7 +14,7 @@ use biodivine_lib_param_bn::trap_spaces::NetworkColoredSpaces;
 use num_bigint::BigUint;
 use pyo3::basic::CompareOp;
 use pyo3::prelude::*;
-use pyo3::types::PyList;
+use pyo3::types::{PyBytes, PyList};
 use rand::SeedableRng;
 use rand::prelude::StdRng;
 
@@ -24,7 +24,9 @@ use crate::bindings::lib_param_bn::argument_types::variable_id_type::VariableIdT
 use crate::bindings::lib_param_bn::symbolic::model_space::SpaceModel;
 use crate::bindings::lib_param_bn::symbolic::set_colored_vertex::ColoredVertexSet;
 use crate::bindings::lib_param_bn::symbolic::set_spaces::SpaceSet;
-use crate::bindings::lib_param_bn::symbolic::symbolic_context::SymbolicContext;
+use crate::bindings::lib_param_bn::symbolic::symbolic_context::{
+    SymbolicContext, SymbolicSetKind, decode_symbolic_set, encode_symbolic_set,
+};
 use crate::bindings::lib_param_bn::symbolic::symbolic_space_context::SymbolicSpaceContext;
 use biodivine_lib_bdd::Bdd as RsBdd;
 use biodivine_lib_param_bn::biodivine_std::traits::Set;
@@ -122,9 +124,38 @@ impl ColoredSpaceSet {
         self_.clone()
     }
 
-    fn __getnewargs__(&self, py: Python) -> (Py<SymbolicSpaceContext>, Bdd) {
-        let bdd = self.to_bdd(py);
-        (self.ctx.clone(), bdd)
+    /// Pickle support: the set is stored using `ColoredSpaceSet.to_bytes`, hence it can only be
+    /// unpickled if the encoding of its context did not change.
+    fn __reduce__<'a>(
+        &self,
+        py: Python<'a>,
+    ) -> PyResult<(Py<PyAny>, (Py<SymbolicSpaceContext>, Bound<'a, PyBytes>))> {
+        let from_bytes = py.get_type::<ColoredSpaceSet>().getattr("from_bytes")?;
+        Ok((from_bytes.into(), (self.ctx.clone(), self.to_bytes(py))))
+    }
+
+    /// Serialize this `ColoredSpaceSet` into `bytes` which can be loaded using
+    /// `ColoredSpaceSet.from_bytes` (see `SymbolicContext.fingerprint` for details).
+    pub fn to_bytes<'a>(&self, py: Python<'a>) -> Bound<'a, PyBytes> {
+        let data = encode_symbolic_set(
+            self.ctx.get().as_native().inner_context(),
+            SymbolicSetKind::ColoredSpaces,
+            self.as_native().as_bdd(),
+        );
+        PyBytes::new(py, &data)
+    }
+
+    /// Load a `ColoredSpaceSet` created by `ColoredSpaceSet.to_bytes` into the given context.
+    /// Throws a `RuntimeError` if the context is not compatible.
+    #[staticmethod]
+    pub fn from_bytes(ctx: Py<SymbolicSpaceContext>, data: &[u8]) -> PyResult<ColoredSpaceSet> {
+        let bdd = decode_symbolic_set(
+            ctx.get().as_native().inner_context(),
+            SymbolicSetKind::ColoredSpaces,
+            data,
+        )?;
+        let native = NetworkColoredSpaces::new(bdd, ctx.get().as_native());
+        Ok(ColoredSpaceSet { ctx, native })
     }
 
     fn __hash__(&self) -> u64 {
@@ -6,7 +6,9 @@ use crate::bindings::lib_param_bn::symbolic::model_vertex::VertexModel;
 use crate::bindings::lib_param_bn::symbolic::set_color::ColorSet;
 use crate::bindings::lib_param_bn::symbolic::set_colored_space::ColoredSpaceSet;
 use crate::bindings::lib_param_bn::symbolic::set_vertex::VertexSet;
-use crate::bindings::lib_param_bn::symbolic::symbolic_context::SymbolicContext;
+use crate::bindings::lib_param_bn::symbolic::symbolic_context::{
+    SymbolicContext, SymbolicSetKind, decode_symbolic_set, encode_symbolic_set,
+};
 use crate::bindings::lib_param_bn::symbolic::symbolic_space_context::SymbolicSpaceContext;
 use biodivine_lib_bdd::random_sampling::UniformValuationSampler;
 use biodivine_lib_bdd::{Bdd as RsBdd, BddPartialValuation};
@@ -19,7 +21,7 @@ use num_bigint::BigUint;
 use pyo3::IntoPyObjectExt;
 use pyo3::basic::CompareOp;
 use pyo3::prelude::*;
-use pyo3::types::PyList;
+use pyo3::types::{PyBytes, PyList};
 use rand::SeedableRng;
 use rand::prelude::StdRng;
 use std::collections::hash_map::DefaultHasher;
@@ -123,9 +125,38 @@ impl ColoredVertexSet {
         self_.clone()
     }
 
-    fn __getnewargs__(&self, py: Python) -> (Py<SymbolicContext>, Bdd) {
-        let bdd = self.to_bdd(py);
-        (self.ctx.clone(), bdd)
+    /// Pickle support: the set is stored using `ColoredVertexSet.to_bytes`, hence it can only be
+    /// unpickled if the encoding of its context did not change.
+    fn __reduce__<'a>(
+        &self,
+        py: Python<'a>,
+    ) -> PyResult<(Py<PyAny>, (Py<SymbolicContext>, Bound<'a, PyBytes>))> {
+        let from_bytes = py.get_type::<ColoredVertexSet>().getattr("from_bytes")?;
+        Ok((from_bytes.into(), (self.ctx.clone(), self.to_bytes(py))))
+    }
+
+    /// Serialize this `ColoredVertexSet` into `bytes` which can be loaded using
+    /// `ColoredVertexSet.from_bytes` (see `SymbolicContext.fingerprint` for details).
+    pub fn to_bytes<'a>(&self, py: Python<'a>) -> Bound<'a, PyBytes> {
+        let data = encode_symbolic_set(
+            self.ctx.get().as_native(),
+            SymbolicSetKind::ColoredVertices,
+            self.as_native().as_bdd(),
+        );
+        PyBytes::new(py, &data)
+    }
+
+    /// Load a `ColoredVertexSet` created by `ColoredVertexSet.to_bytes` into the given context.
+    /// Throws a `RuntimeError` if the context is not compatible.
+    #[staticmethod]
+    pub fn from_bytes(ctx: Py<SymbolicContext>, data: &[u8]) -> PyResult<ColoredVertexSet> {
+        let bdd = decode_symbolic_set(
+            ctx.get().as_native(),
+            SymbolicSetKind::ColoredVertices,
+            data,
+        )?;
+        let native = GraphColoredVertices::new(bdd, ctx.get().as_native());
+        Ok(ColoredVertexSet { ctx, native })
     }
 
     pub fn __hash__(&self) -> u64 {
@@ -201,6 +232,35 @@ impl ColoredVertexSet {
         VertexSet::mk_native(self.ctx.clone(), self.as_native().vertices())
     }
 
+    /// Eliminate the given network `variables` from the vertex component of this relation,
+    /// such that the result is *cylindrical* in these variables (i.e., it does not depend
+    /// on their values). The colors are not affected.
+    ///
+    /// By default, the elimination is existential: a vertex-color pair is in the result if
+    /// it is in this set for *some* values of the eliminated variables. With `universal=True`,
+    /// the pair must be in this set for *all* values of the eliminated variables. As in
+    /// `VertexSet.project_out`, the extra symbolic variables of the eliminated network
+    /// variables are eliminated as well.
+    #[pyo3(signature = (variables, universal = false))]
+    pub fn project_out(&self, variables: Vec<VariableIdType>, universal: bool) -> PyResult<Self> {
+        let ctx = self.ctx.get();
+        let eliminated = VertexSet::compute_eliminated_variables(ctx, variables, false)?;
+        let bdd = VertexSet::quantify_native(self.as_native().as_bdd(), &eliminated, universal);
+        Ok(self.mk_derived(GraphColoredVertices::new(bdd, ctx.as_native())))
+    }
+
+    /// Eliminate all network variables *except* for the given `variables` from the vertex
+    /// component of this relation.
+    ///
+    /// See `ColoredVertexSet.project_out` for details.
+    #[pyo3(signature = (variables, universal = false))]
+    pub fn restrict_to(&self, variables: Vec<VariableIdType>, universal: bool) -> PyResult<Self> {
+        let ctx = self.ctx.get();
+        let eliminated = VertexSet::compute_eliminated_variables(ctx, variables, true)?;
+        let bdd = VertexSet::quantify_native(self.as_native().as_bdd(), &eliminated, universal);
+        Ok(self.mk_derived(GraphColoredVertices::new(bdd, ctx.as_native())))
+    }
+
     /// Retain only those vertex-color pairs for which the color is also contained in the given `colors` set.
     pub fn intersect_colors(&self, colors: &ColorSet) -> Self {
         self.mk_derived(self.as_native().intersect_colors(colors.as_native()))
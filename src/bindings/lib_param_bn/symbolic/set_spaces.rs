@@ -13,6 +13,7 @@ use num_bigint::BigUint;
 use pyo3::IntoPyObjectExt;
 use pyo3::basic::CompareOp;
 use pyo3::prelude::*;
+use pyo3::types::PyBytes;
 use rand::SeedableRng;
 use rand::prelude::StdRng;
 
@@ -23,7 +24,9 @@ use crate::bindings::lib_param_bn::symbolic::model_space::SpaceModel;
 use crate::bindings::lib_param_bn::symbolic::set_color::ColorSet;
 use crate::bindings::lib_param_bn::symbolic::set_colored_space::ColoredSpaceSet;
 use crate::bindings::lib_param_bn::symbolic::set_vertex::VertexSet;
-use crate::bindings::lib_param_bn::symbolic::symbolic_context::SymbolicContext;
+use crate::bindings::lib_param_bn::symbolic::symbolic_context::{
+    SymbolicContext, SymbolicSetKind, decode_symbolic_set, encode_symbolic_set,
+};
 use crate::bindings::lib_param_bn::symbolic::symbolic_space_context::SymbolicSpaceContext;
 use crate::bindings::lib_param_bn::variable_id::VariableIdResolvable;
 
@@ -112,9 +115,38 @@ impl SpaceSet {
         self_.clone()
     }
 
-    fn __getnewargs__(&self, py: Python) -> (Py<SymbolicSpaceContext>, Bdd) {
-        let bdd = self.to_bdd(py);
-        (self.ctx.clone(), bdd)
+    /// Pickle support: the set is stored using `SpaceSet.to_bytes`, hence it can only be unpickled
+    /// if the encoding of its context did not change.
+    fn __reduce__<'a>(
+        &self,
+        py: Python<'a>,
+    ) -> PyResult<(Py<PyAny>, (Py<SymbolicSpaceContext>, Bound<'a, PyBytes>))> {
+        let from_bytes = py.get_type::<SpaceSet>().getattr("from_bytes")?;
+        Ok((from_bytes.into(), (self.ctx.clone(), self.to_bytes(py))))
+    }
+
+    /// Serialize this `SpaceSet` into `bytes` which can be loaded using `SpaceSet.from_bytes` (see
+    /// `SymbolicContext.fingerprint` for details).
+    pub fn to_bytes<'a>(&self, py: Python<'a>) -> Bound<'a, PyBytes> {
+        let data = encode_symbolic_set(
+            self.ctx.get().as_native().inner_context(),
+            SymbolicSetKind::Spaces,
+            self.as_native().as_bdd(),
+        );
+        PyBytes::new(py, &data)
+    }
+
+    /// Load a `SpaceSet` created by `SpaceSet.to_bytes` into the given context. Throws a
+    /// `RuntimeError` if the context is not compatible.
+    #[staticmethod]
+    pub fn from_bytes(ctx: Py<SymbolicSpaceContext>, data: &[u8]) -> PyResult<SpaceSet> {
+        let bdd = decode_symbolic_set(
+            ctx.get().as_native().inner_context(),
+            SymbolicSetKind::Spaces,
+            data,
+        )?;
+        let native = NetworkSpaces::new(bdd, ctx.get().as_native());
+        Ok(SpaceSet { ctx, native })
     }
 
     fn __hash__(&self) -> u64 {
@@ -1,5 +1,5 @@
-use std::collections::HashMap;
 use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
 use std::ops::Not;
 
@@ -15,6 +15,7 @@ use num_bigint::BigUint;
 use pyo3::IntoPyObjectExt;
 use pyo3::basic::CompareOp;
 use pyo3::prelude::*;
+use pyo3::types::PyBytes;
 use rand::SeedableRng;
 use rand::prelude::StdRng;
 
@@ -25,7 +26,9 @@ use crate::bindings::lib_param_bn::symbolic::model_vertex::VertexModel;
 use crate::bindings::lib_param_bn::symbolic::set_color::ColorSet;
 use crate::bindings::lib_param_bn::symbolic::set_colored_vertex::ColoredVertexSet;
 use crate::bindings::lib_param_bn::symbolic::set_spaces::SpaceSet;
-use crate::bindings::lib_param_bn::symbolic::symbolic_context::SymbolicContext;
+use crate::bindings::lib_param_bn::symbolic::symbolic_context::{
+    SymbolicContext, SymbolicSetKind, decode_symbolic_set, encode_symbolic_set,
+};
 use crate::bindings::lib_param_bn::symbolic::symbolic_space_context::SymbolicSpaceContext;
 use crate::bindings::lib_param_bn::variable_id::{VariableId, VariableIdResolvable};
 
@@ -114,9 +117,34 @@ impl VertexSet {
         self_.clone()
     }
 
-    fn __getnewargs__(&self, py: Python) -> (Py<SymbolicContext>, Bdd) {
-        let bdd = self.to_bdd(py);
-        (self.ctx.clone(), bdd)
+    /// Pickle support: the set is stored using `VertexSet.to_bytes`, hence it can only be unpickled
+    /// if the encoding of its context did not change.
+    fn __reduce__<'a>(
+        &self,
+        py: Python<'a>,
+    ) -> PyResult<(Py<PyAny>, (Py<SymbolicContext>, Bound<'a, PyBytes>))> {
+        let from_bytes = py.get_type::<VertexSet>().getattr("from_bytes")?;
+        Ok((from_bytes.into(), (self.ctx.clone(), self.to_bytes(py))))
+    }
+
+    /// Serialize this `VertexSet` into `bytes` which can be loaded using `VertexSet.from_bytes`
+    /// (see `SymbolicContext.fingerprint` for details).
+    pub fn to_bytes<'a>(&self, py: Python<'a>) -> Bound<'a, PyBytes> {
+        let data = encode_symbolic_set(
+            self.ctx.get().as_native(),
+            SymbolicSetKind::Vertices,
+            self.as_native().as_bdd(),
+        );
+        PyBytes::new(py, &data)
+    }
+
+    /// Load a `VertexSet` created by `VertexSet.to_bytes` into the given context. Throws a
+    /// `RuntimeError` if the context is not compatible.
+    #[staticmethod]
+    pub fn from_bytes(ctx: Py<SymbolicContext>, data: &[u8]) -> PyResult<VertexSet> {
+        let bdd = decode_symbolic_set(ctx.get().as_native(), SymbolicSetKind::Vertices, data)?;
+        let native = GraphVertices::new(bdd, ctx.get().as_native());
+        Ok(VertexSet { ctx, native })
     }
 
     pub fn __hash__(&self) -> u64 {
@@ -194,6 +222,44 @@ impl VertexSet {
         Bdd::new_raw_2(ctx.bdd_variable_set(), rs_bdd)
     }
 
+    /// Eliminate the given network `variables` from this set, such that the result is
+    /// *cylindrical* in these variables (i.e., it does not depend on their values).
+    ///
+    /// By default, the elimination is existential: a vertex is in the result if it is in
+    /// this set for *some* values of the eliminated variables. With `universal=True`,
+    /// the vertex must be in this set for *all* values of the eliminated variables.
+    ///
+    /// If the context has extra symbolic variables (e.g. in a graph created using
+    /// `AsynchronousGraph.mk_for_model_checking`), the extra variables of each eliminated
+    /// network variable are eliminated too. Extra variables of the remaining network
+    /// variables are not affected.
+    ///
+    /// ```python
+    /// bn = BooleanNetwork(["a", "b", "c"])
+    /// graph = AsynchronousGraph(bn)
+    /// x = graph.mk_subspace_vertices({ "a": True, "b": False })
+    /// assert x.project_out(["b"]) == graph.mk_subspace_vertices({ "a": True })
+    /// assert x.project_out(["b"], universal=True).is_empty()
+    /// ```
+    #[pyo3(signature = (variables, universal = false))]
+    pub fn project_out(&self, variables: Vec<VariableIdType>, universal: bool) -> PyResult<Self> {
+        let ctx = self.ctx.get();
+        let eliminated = Self::compute_eliminated_variables(ctx, variables, false)?;
+        let bdd = Self::quantify_native(self.as_native().as_bdd(), &eliminated, universal);
+        Ok(self.mk_derived(GraphVertices::new(bdd, ctx.as_native())))
+    }
+
+    /// Eliminate all network variables *except* for the given `variables` from this set.
+    ///
+    /// See `VertexSet.project_out` for details.
+    #[pyo3(signature = (variables, universal = false))]
+    pub fn restrict_to(&self, variables: Vec<VariableIdType>, universal: bool) -> PyResult<Self> {
+        let ctx = self.ctx.get();
+        let eliminated = Self::compute_eliminated_variables(ctx, variables, true)?;
+        let bdd = Self::quantify_native(self.as_native().as_bdd(), &eliminated, universal);
+        Ok(self.mk_derived(GraphVertices::new(bdd, ctx.as_native())))
+    }
+
     /// Extend this set of vertices with all the colors from the given set.
     ///
     /// This is essentially a cartesian product with the given `ColorSet`.
@@ -379,6 +445,42 @@ impl VertexSet {
         self.ctx.clone()
     }
 
+    /// Helper function to compute the state variables eliminated by `project_out` (or by
+    /// `restrict_to`, in which case `complement` is set). Shared with `ColoredVertexSet`.
+    /// The extra state variables of every eliminated network variable are eliminated as well.
+    pub fn compute_eliminated_variables(
+        ctx: &SymbolicContext,
+        variables: Vec<VariableIdType>,
+        complement: bool,
+    ) -> PyResult<Vec<biodivine_lib_bdd::BddVariable>> {
+        let native = ctx.as_native();
+        let selected = variables
+            .iter()
+            .map(|it| it.resolve(native))
+            .collect::<PyResult<HashSet<_>>>()?;
+        let mut result = Vec::new();
+        for var in native.network_variables() {
+            if selected.contains(&var) != complement {
+                result.push(native.get_state_variable(var));
+                result.extend(native.extra_state_variables(var).iter().copied());
+            }
+        }
+        Ok(result)
+    }
+
+    /// Existentially (or universally) quantify the given variables in a `Bdd`.
+    pub fn quantify_native(
+        bdd: &RsBdd,
+        variables: &[biodivine_lib_bdd::BddVariable],
+        universal: bool,
+    ) -> RsBdd {
+        if universal {
+            bdd.for_all(variables)
+        } else {
+            bdd.exists(variables)
+        }
+    }
+
     /// Helper function to compute retained variables from an optional list of VariableIdType.
     /// This is shared between `items` and `sample_items` to avoid duplication.
     pub fn compute_retained_variables(
@@ -17,6 +17,7 @@ use pyo3::basic::CompareOp;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
 use std::collections::HashMap;
+use std::io::Cursor;
 
 /// Intuitively, a `SymbolicContext` encodes the entities of a `BooleanNetwork` into a set
 /// of symbolic variables managed by a `BddVariableSet`. Using this representation, we can
@@ -612,6 +613,22 @@ impl SymbolicContext {
         self.bdd_vars.clone()
     }
 
+    /// A 64-bit fingerprint of the symbolic encoding described by this `SymbolicContext`.
+    ///
+    /// The fingerprint covers the names and ordering of all symbolic variables (i.e. network
+    /// variables, extra variables, and the tables of uninterpreted functions). The value is
+    /// stable across processes and library versions.
+    ///
+    /// Symbolic sets (`ColorSet`, `VertexSet`, `ColoredVertexSet`, `SpaceSet` and
+    /// `ColoredSpaceSet`) can be serialized using `to_bytes`. The result contains the type
+    /// of the set, the fingerprint of its context, and the underlying `Bdd`. The matching
+    /// `from_bytes` method only accepts data with the same set type and a context with the
+    /// same fingerprint. Pickling of symbolic sets uses the same format, hence a set can be
+    /// transferred between processes (or stored on disk) together with its context.
+    pub fn fingerprint(&self) -> u64 {
+        context_fingerprint(self.as_native())
+    }
+
     /// This is similar to `BddVariableSet.transfer_from`, but is applied at the level of
     /// symbolic contexts.
     ///
@@ -619,11 +636,13 @@ impl SymbolicContext {
     /// that use similar variables and parameters, as long as the `Bdd` only uses objects that
     /// are present in both contexts and are ordered the same.
     ///
-    pub fn transfer_from(&self, bdd: &Bdd, old_ctx: &SymbolicContext) -> PyResult<Bdd> {
-        let Some(rs_bdd) = self
-            .as_native()
-            .transfer_from(bdd.as_native(), old_ctx.as_native())
-        else {
+    /// If both contexts encode the same network variables, but in a different order (see
+    /// `BooleanNetwork.reorder_variables`), the `Bdd` is rebuilt using the ordering of this
+    /// context. In such case, the symbolic variables of uninterpreted functions are matched
+    /// using their input valuations, and the variable ordering does not need to be compatible.
+    ///
+    pub fn transfer_from(&self, py: Python, bdd: &Bdd, old_ctx: &SymbolicContext) -> PyResult<Bdd> {
+        let Some(rs_bdd) = self.transfer_native(py, bdd.as_native(), old_ctx) else {
             return throw_runtime_error("The contexts are not compatible.");
         };
         Ok(Bdd::new_raw_2(self.bdd_vars.clone(), rs_bdd))
@@ -693,6 +712,34 @@ impl SymbolicContext {
         })
     }
 
+    /// True if this context encodes the same network variables as `other`, but in
+    /// a different order.
+    pub fn is_reordering_of(&self, other: &SymbolicContext) -> bool {
+        let mut names = self.network_variable_names();
+        let mut other_names = other.network_variable_names();
+        if names == other_names {
+            return false;
+        }
+        names.sort();
+        other_names.sort();
+        names == other_names
+    }
+
+    /// Translate a native `Bdd` from `old_ctx` into this context (see
+    /// `SymbolicContext.transfer_from`). Returns `None` if the contexts are not compatible.
+    pub fn transfer_native(
+        &self,
+        py: Python,
+        bdd: &biodivine_lib_bdd::Bdd,
+        old_ctx: &SymbolicContext,
+    ) -> Option<biodivine_lib_bdd::Bdd> {
+        if !self.is_reordering_of(old_ctx) {
+            return self.as_native().transfer_from(bdd, old_ctx.as_native());
+        }
+        let mapping = self.reordered_variable_map(py, old_ctx)?;
+        Bdd::native_permute(bdd, self.as_native().bdd_variable_set(), &mapping)
+    }
+
     /// Get a reference to the stored network, if any.
     pub fn get_network(&self) -> Option<&Py<BooleanNetwork>> {
         self.network.as_ref()
@@ -829,6 +876,84 @@ impl SymbolicContext {
         bn
     }
 
+    /// Map the symbolic variables of `old_ctx` to the equivalent symbolic variables of this
+    /// context, assuming both contexts encode the same network with a different variable order.
+    ///
+    /// Rows of the implicit function tables are matched using the names of the regulators,
+    /// since the order of regulators (i.e. function arguments) follows the variable ordering.
+    fn reordered_variable_map(
+        &self,
+        py: Python,
+        old_ctx: &SymbolicContext,
+    ) -> Option<HashMap<biodivine_lib_bdd::BddVariable, biodivine_lib_bdd::BddVariable>> {
+        let new = self.as_native();
+        let old = old_ctx.as_native();
+        let mut mapping = HashMap::new();
+        for old_var in old.network_variables() {
+            let name = old.get_network_variable_name(old_var);
+            let new_var = new.find_network_variable(name.as_str())?;
+            mapping.insert(
+                old.get_state_variable(old_var),
+                new.get_state_variable(new_var),
+            );
+            let old_extra = old.extra_state_variables(old_var);
+            let new_extra = new.extra_state_variables(new_var);
+            if old_extra.len() != new_extra.len() {
+                return None;
+            }
+            mapping.extend(old_extra.iter().cloned().zip(new_extra.iter().cloned()));
+            match (
+                old.get_implicit_function_table(old_var),
+                new.get_implicit_function_table(new_var),
+            ) {
+                (None, None) => (),
+                (Some(old_table), Some(new_table)) => {
+                    let old_regulators = old_ctx.regulator_names(py, old_var)?;
+                    let new_regulators = self.regulator_names(py, new_var)?;
+                    let new_rows = new_table.into_iter().collect::<HashMap<_, _>>();
+                    for (inputs, old_bdd_var) in old_table {
+                        let valuation =
+                            old_regulators.iter().zip(inputs).collect::<HashMap<_, _>>();
+                        let new_inputs = new_regulators
+                            .iter()
+                            .map(|it| valuation.get(it).copied())
+                            .collect::<Option<Vec<_>>>()?;
+                        mapping.insert(old_bdd_var, *new_rows.get(&new_inputs)?);
+                    }
+                }
+                _ => return None,
+            }
+        }
+        for old_par in old.network_parameters() {
+            let name = old.get_network_parameter_name(old_par);
+            let new_par = new.find_network_parameter(name.as_str())?;
+            let new_table = new.get_explicit_function_table(new_par);
+            let new_rows = new_table.into_iter().collect::<HashMap<_, _>>();
+            for (inputs, old_bdd_var) in old.get_explicit_function_table(old_par) {
+                mapping.insert(old_bdd_var, *new_rows.get(&inputs)?);
+            }
+        }
+        Some(mapping)
+    }
+
+    /// The names of the regulators of the given variable in the stored network, if available.
+    fn regulator_names(
+        &self,
+        py: Python,
+        variable: biodivine_lib_param_bn::VariableId,
+    ) -> Option<Vec<String>> {
+        let network = self.network.as_ref()?.borrow(py);
+        let network = network.as_native();
+        Some(
+            network
+                .as_graph()
+                .regulators(variable)
+                .into_iter()
+                .map(|it| network.get_variable_name(it).clone())
+                .collect(),
+        )
+    }
+
     /// Extract the map assigning each variable the number of "extra variables" used by this
     /// context. Useful when trying to recreate the same context from serialized data.
     fn extra_variables_map(&self) -> PyResult<HashMap<String, u16>> {
@@ -844,3 +969,123 @@ impl SymbolicContext {
         Ok(extra_variables)
     }
 }
+
+/// The type of symbolic set stored in the output of [encode_symbolic_set].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SymbolicSetKind {
+    Colors = 1,
+    Vertices = 2,
+    ColoredVertices = 3,
+    Spaces = 4,
+    ColoredSpaces = 5,
+}
+
+impl SymbolicSetKind {
+    fn from_byte(value: u8) -> Option<SymbolicSetKind> {
+        match value {
+            1 => Some(SymbolicSetKind::Colors),
+            2 => Some(SymbolicSetKind::Vertices),
+            3 => Some(SymbolicSetKind::ColoredVertices),
+            4 => Some(SymbolicSetKind::Spaces),
+            5 => Some(SymbolicSetKind::ColoredSpaces),
+            _ => None,
+        }
+    }
+
+    fn class_name(self) -> &'static str {
+        match self {
+            SymbolicSetKind::Colors => "ColorSet",
+            SymbolicSetKind::Vertices => "VertexSet",
+            SymbolicSetKind::ColoredVertices => "ColoredVertexSet",
+            SymbolicSetKind::Spaces => "SpaceSet",
+            SymbolicSetKind::ColoredSpaces => "ColoredSpaceSet",
+        }
+    }
+}
+
+/// The version of the binary format produced by [encode_symbolic_set].
+const SYMBOLIC_SET_FORMAT: u8 = 1;
+
+/// Compute the fingerprint of a symbolic context (see `SymbolicContext.fingerprint`).
+///
+/// We use 64-bit FNV-1a, because the standard hasher is not guaranteed to be stable
+/// across Rust versions.
+pub fn context_fingerprint(
+    ctx: &biodivine_lib_param_bn::symbolic_async_graph::SymbolicContext,
+) -> u64 {
+    fn write(hash: &mut u64, bytes: &[u8]) {
+        for byte in bytes {
+            *hash ^= u64::from(*byte);
+            *hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+
+    let mut hash = 0xcbf2_9ce4_8422_2325;
+    write(&mut hash, &(ctx.num_state_variables() as u64).to_le_bytes());
+    write(
+        &mut hash,
+        &(ctx.num_extra_state_variables() as u64).to_le_bytes(),
+    );
+    write(
+        &mut hash,
+        &(ctx.num_parameter_variables() as u64).to_le_bytes(),
+    );
+    let bdd_vars = ctx.bdd_variable_set();
+    for var in bdd_vars.variables() {
+        write(&mut hash, bdd_vars.name_of_str(var).as_bytes());
+        write(&mut hash, &[0]);
+    }
+    hash
+}
+
+/// Serialize a `bdd` representing a symbolic set of the given `kind` together with the
+/// fingerprint of its context.
+///
+/// The format is: format version (1 byte), set kind (1 byte), context fingerprint
+/// (8 bytes, little endian), followed by the `Bdd.data_bytes` representation of the set.
+pub fn encode_symbolic_set(
+    ctx: &biodivine_lib_param_bn::symbolic_async_graph::SymbolicContext,
+    kind: SymbolicSetKind,
+    bdd: &biodivine_lib_bdd::Bdd,
+) -> Vec<u8> {
+    let mut result = vec![SYMBOLIC_SET_FORMAT, kind as u8];
+    result.extend(context_fingerprint(ctx).to_le_bytes());
+    result.extend(bdd.to_bytes());
+    result
+}
+
+/// Read a symbolic set of the given `kind` produced by [encode_symbolic_set], checking that
+/// it was created in a context compatible with `ctx`.
+pub fn decode_symbolic_set(
+    ctx: &biodivine_lib_param_bn::symbolic_async_graph::SymbolicContext,
+    kind: SymbolicSetKind,
+    data: &[u8],
+) -> PyResult<biodivine_lib_bdd::Bdd> {
+    if data.len() < 10 || data[0] != SYMBOLIC_SET_FORMAT {
+        return throw_runtime_error("Data does not contain a serialized symbolic set.");
+    }
+    if data[1] != kind as u8 {
+        let found = SymbolicSetKind::from_byte(data[1])
+            .map(|it| it.class_name())
+            .unwrap_or("unknown");
+        return throw_runtime_error(format!(
+            "Expected serialized `{}`, but found `{}`.",
+            kind.class_name(),
+            found
+        ));
+    }
+    let mut fingerprint = [0u8; 8];
+    fingerprint.copy_from_slice(&data[2..10]);
+    if u64::from_le_bytes(fingerprint) != context_fingerprint(ctx) {
+        return throw_runtime_error("The set was serialized using an incompatible context.");
+    }
+    let mut reader = Cursor::new(&data[10..]);
+    let bdd = match biodivine_lib_bdd::Bdd::read_as_bytes(&mut reader) {
+        Ok(bdd) => bdd,
+        Err(e) => return throw_runtime_error(format!("Cannot read symbolic set: {e}")),
+    };
+    if bdd.num_vars() != ctx.bdd_variable_set().num_vars() {
+        return throw_runtime_error("The set was serialized using an incompatible context.");
+    }
+    Ok(bdd)
+}
@@ -1,3 +1,5 @@
+use crate::bindings::lib_bdd::bdd::Bdd;
+use crate::bindings::lib_bdd::bdd_valuation::BddPartialValuation;
 use crate::bindings::lib_bdd::boolean_expression::BooleanExpression;
 use crate::bindings::lib_param_bn::argument_types::VariableOrParameterIdType;
 use crate::bindings::lib_param_bn::argument_types::bool_type::BoolType;
@@ -5,10 +7,12 @@ use crate::bindings::lib_param_bn::argument_types::update_function_type::UpdateF
 use crate::bindings::lib_param_bn::argument_types::variable_id_type::VariableIdType;
 use crate::bindings::lib_param_bn::boolean_network::BooleanNetwork;
 use crate::bindings::lib_param_bn::parameter_id::ParameterId;
+use crate::bindings::lib_param_bn::symbolic::symbolic_context::SymbolicContext;
 use crate::bindings::lib_param_bn::variable_id::{VariableId, VariableIdResolvable};
 use crate::pyo3_utils::richcmp_eq_by_key;
 use crate::{AsNative, runtime_error, throw_runtime_error, throw_type_error};
 use biodivine_lib_bdd::boolean_expression::BooleanExpression as RsExpression;
+use biodivine_lib_bdd::{Bdd as RsBdd, BddPartialValuation as RsBddPartialValuation};
 use biodivine_lib_param_bn::{BinaryOp, FnUpdate};
 use either::Either;
 use pyo3::basic::CompareOp;
@@ -628,6 +632,73 @@ impl UpdateFunction {
         UpdateFunction::new_raw(self.ctx.clone(), Arc::new(transformed))
     }
 
+    /// Compute the prime implicants of this `UpdateFunction` using the symbolic encoding of the
+    /// given `BooleanNetwork` (i.e., the result is compatible with `SymbolicContext(network)`).
+    /// The network must contain all variables and parameters used by this function (with
+    /// matching names and IDs), otherwise a `RuntimeError` is thrown. See also
+    /// `Bdd.prime_implicants`.
+    ///
+    /// Since the prime implicants of a function with uninterpreted functions depend on their
+    /// interpretation, the result is a list of pairs: a `Bdd` (over the parameter variables of
+    /// the context) describing a set of colors, and the list of prime implicants (over the
+    /// state variables) that is shared by all these colors. The color sets are disjoint and
+    /// together cover all colors. For a function without parameters, the result thus contains
+    /// a single pair with a `true` color set.
+    #[pyo3(signature = (network, size_limit = None))]
+    pub fn prime_implicants(
+        &self,
+        py: Python,
+        network: Py<BooleanNetwork>,
+        size_limit: Option<usize>,
+    ) -> PyResult<Vec<(Bdd, Vec<BddPartialValuation>)>> {
+        if !self.is_compatible_with(py, network.borrow(py).as_native()) {
+            return throw_runtime_error("The function is not compatible with the given network.");
+        }
+        let ctx = SymbolicContext::new(py, network, None)?;
+        let native_ctx = ctx.as_native();
+        let bdd_vars = native_ctx.bdd_variable_set();
+        let parameters = native_ctx.parameter_variables();
+        let function = native_ctx.mk_fn_update_true(self.as_native());
+
+        // Split the function on the parameter variables that appear in it until it only
+        // depends on state variables.
+        let mut groups: Vec<(RsBdd, Vec<RsBddPartialValuation>)> = Vec::new();
+        let mut stack = vec![(function, RsBddPartialValuation::empty())];
+        while let Some((function, colors)) = stack.pop() {
+            if let Some(parameter) = parameters
+                .iter()
+                .find(|it| function.support_set_contains(it))
+            {
+                for value in [true, false] {
+                    let mut colors = colors.clone();
+                    colors.set_value(*parameter, value);
+                    stack.push((function.var_restrict(*parameter, value), colors));
+                }
+                continue;
+            }
+
+            let primes = Bdd::native_prime_implicants(py, &function, size_limit)?;
+            let colors = bdd_vars.mk_conjunctive_clause(&colors);
+            if let Some((group, _)) = groups.iter_mut().find(|(_, it)| *it == primes) {
+                *group = group.or(&colors);
+            } else {
+                groups.push((colors, primes));
+            }
+        }
+
+        let bdd_vars = ctx.bdd_variable_set();
+        Ok(groups
+            .into_iter()
+            .map(|(colors, primes)| {
+                let primes = primes
+                    .into_iter()
+                    .map(|it| BddPartialValuation::new_raw(bdd_vars.clone(), it))
+                    .collect();
+                (Bdd::new_raw_2(bdd_vars.clone(), colors), primes)
+            })
+            .collect())
+    }
+
     /// Convert the `UpdateFunction` to a `BooleanExpression`, as long as the function contains no uninterpreted
     /// functions (otherwise throws a `RuntimeError`).
     pub fn as_expression(&self, py: Python) -> PyResult<BooleanExpression> {
@@ -678,4 +749,27 @@ impl UpdateFunction {
             value,
         }
     }
+
+    /// Check that all variables and parameters used by this function exist in the given
+    /// network with the same names (and arities).
+    fn is_compatible_with(
+        &self,
+        py: Python,
+        network: &biodivine_lib_param_bn::BooleanNetwork,
+    ) -> bool {
+        let bn = self.ctx.borrow(py);
+        let bn = bn.as_native();
+        let variables_ok = self.value.collect_arguments().into_iter().all(|var| {
+            var.to_index() < network.num_vars()
+                && bn.get_variable_name(var) == network.get_variable_name(var)
+        });
+        let parameters_ok = self.value.collect_parameters().into_iter().all(|par| {
+            par.to_index() < network.num_parameters() && {
+                let (expected, actual) = (bn.get_parameter(par), network.get_parameter(par));
+                expected.get_name() == actual.get_name()
+                    && expected.get_arity() == actual.get_arity()
+            }
+        });
+        variables_ok && parameters_ok
+    }
 }
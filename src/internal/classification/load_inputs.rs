@@ -9,6 +9,9 @@ use biodivine_lib_param_bn::BooleanNetwork;
 use biodivine_lib_param_bn::symbolic_async_graph::{GraphColors, SymbolicAsyncGraph};
 use zip::ZipArchive;
 
+use crate::internal::classification::write_output::BDD_ARCHIVE_FILE;
+use crate::internal::shared_bdd::read_bdd_archive;
+
 /// Read the contents of a file from a zip archive into a string.
 fn read_zip_file(reader: &mut ZipArchive<File>, file_name: &str) -> String {
     let mut contents = String::new();
@@ -22,7 +25,9 @@ fn read_zip_file(reader: &mut ZipArchive<File>, file_name: &str) -> String {
 /// those produced by the `build_classification_archive` function).
 ///
 /// Return mapping `category name -> color set` and whole model string in aeon format.
-/// Category names are simply taken from BDD-file names in the archive.
+/// Category BDDs are read from the compact binary [BDD_ARCHIVE_FILE] (if present), as well as
+/// from the legacy `bdd_dump_*.txt` text files, in which case the category names are simply
+/// taken from BDD-file names in the archive.
 pub fn load_classification_archive(
     archive_path: String,
 ) -> Result<(HashMap<String, GraphColors>, String), String> {
@@ -44,6 +49,25 @@ pub fn load_classification_archive(
         .map(|it| it.to_string())
         .collect::<Vec<_>>();
 
+    if files.iter().any(|it| it == BDD_ARCHIVE_FILE) {
+        let mut file = archive
+            .by_name(BDD_ARCHIVE_FILE)
+            .map_err(|e| format!("{e:?}"))?;
+        for (category_id, bdd) in read_bdd_archive(&mut file)? {
+            if bdd.num_vars() != graph.symbolic_context().bdd_variable_set().num_vars() {
+                return Err(format!(
+                    "BDD of category `{category_id}` is not compatible with the model."
+                ));
+            }
+            let color_set = GraphColors::new(bdd, graph.symbolic_context());
+            if categories.insert(category_id.clone(), color_set).is_some() {
+                return Err(format!(
+                    "Duplicate category `{category_id}` in the archive."
+                ));
+            }
+        }
+    }
+
     for file in files {
         if !file.starts_with("bdd_dump_") {
             // Only read BDD dumps.
@@ -7,13 +7,26 @@ use std::path::Path;
 
 use biodivine_lib_param_bn::biodivine_std::traits::Set;
 use biodivine_lib_param_bn::symbolic_async_graph::GraphColors;
+use zip::CompressionMethod;
 use zip::write::{SimpleFileOptions, ZipWriter};
 
+use crate::internal::shared_bdd::write_bdd_archive;
+
+/// Name of the archive file which stores all category BDDs in the compact binary format.
+pub const BDD_ARCHIVE_FILE: &str = "bdds.bin";
+
 /// Create classification archive for an arbitrary "map" of `string -> color set`.
+///
+/// If `binary` is set, all BDDs are stored together in one [BDD_ARCHIVE_FILE] using the
+/// compact format from [crate::internal::shared_bdd]. Otherwise, each category is written
+/// as a separate `bdd_dump_{name}.txt` text file (this is the format expected by the original
+/// BN Classifier). If `compress` is not set, the archive entries are stored without compression.
 pub fn build_classification_archive(
     categories: HashMap<String, GraphColors>,
     archive_name: &str,
     original_model_str: &str,
+    binary: bool,
+    compress: bool,
 ) -> Result<(), std::io::Error> {
     let archive_path = Path::new(archive_name);
     // If there are some non-existing dirs in the path, create them.
@@ -25,13 +38,32 @@ pub fn build_classification_archive(
     // Create a zip writer for the desired archive.
     let archive = File::create(archive_path)?;
     let mut zip_writer = ZipWriter::new(archive);
+    let options = if compress {
+        SimpleFileOptions::default()
+    } else {
+        SimpleFileOptions::default().compression_method(CompressionMethod::Stored)
+    };
+
+    if binary {
+        // Sort the categories to make the output deterministic.
+        let mut bdds = categories
+            .iter()
+            .filter(|(_, colors)| !colors.is_empty())
+            .map(|(name, colors)| (name.clone(), colors.as_bdd()))
+            .collect::<Vec<_>>();
+        bdds.sort_by(|(a, _), (b, _)| a.cmp(b));
+        zip_writer
+            .start_file(BDD_ARCHIVE_FILE, options)
+            .map_err(std::io::Error::from)?;
+        write_bdd_archive(&mut zip_writer, &bdds)?;
+    }
 
     for (category_name, category_colors) in categories.iter() {
-        if !category_colors.is_empty() {
+        if !binary && !category_colors.is_empty() {
             // If the BDD is not empty, the results go directly into the zip archive.
             let bdd_file_name = format!("bdd_dump_{category_name}.txt");
             zip_writer
-                .start_file(bdd_file_name.as_str(), SimpleFileOptions::default())
+                .start_file(bdd_file_name.as_str(), options)
                 .map_err(std::io::Error::from)?;
 
             category_colors.as_bdd().write_as_string(&mut zip_writer)?;
@@ -40,7 +72,7 @@ pub fn build_classification_archive(
 
     // Include the original model in the result bundle (we need to load it later).
     zip_writer
-        .start_file("model.aeon", SimpleFileOptions::default())
+        .start_file("model.aeon", options)
         .map_err(std::io::Error::from)?;
     write!(zip_writer, "{original_model_str}")?;
 
@@ -0,0 +1,541 @@
+//! Parsing and printing of Boolean expressions using syntaxes other than the native
+//! lib-bdd syntax (e.g., Python, C, SymPy, or BNet).
+//!
+//! Every syntax is described by its constants, negation operator, binary operators (with
+//! their precedence and associativity), and optionally the `? :` conditional operator
+//! or SymPy-like function calls. Operators that are not available in a particular syntax
+//! are rewritten into equivalent expressions before printing.
+
+use biodivine_lib_bdd::boolean_expression::BooleanExpression;
+
+/// One of the supported Boolean expression syntaxes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExpressionSyntax {
+    /// The native lib-bdd syntax: `true`, `false`, `!`, `&`, `|`, `^`, `=>`, `<=>`, `? :`.
+    Aeon,
+    /// Python syntax: `True`, `False`, `not`, `and`, `or`, `!=` (xor), `==` (iff).
+    Python,
+    /// C syntax: `true`, `false`, `!`, `&&`, `||`, `^`, `!=` (xor), `==` (iff), `? :`.
+    C,
+    /// SymPy syntax: `True`, `False`, `~`, `&`, `|`, `^`, `Implies(a, b)`,
+    /// `Equivalent(a, b)`, `ITE(a, b, c)`.
+    SymPy,
+    /// BNet syntax: `1`, `0`, `!`, `&`, `|`.
+    BNet,
+}
+
+/// A binary Boolean operator.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Operator {
+    And,
+    Or,
+    Xor,
+    Imp,
+    Iff,
+}
+
+/// A binary operator of a syntax: token, operator, precedence (higher binds tighter),
+/// and associativity. When printing, the first entry of each operator is used.
+type BinaryOperator = (&'static str, Operator, u8, bool);
+
+/// A printed expression: text, precedence of the top-level operator (`u8::MAX` if it never
+/// needs parentheses), and the top-level binary operator (if any).
+type Printed = (String, u8, Option<Operator>);
+
+impl TryFrom<&str> for ExpressionSyntax {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "aeon" => Ok(ExpressionSyntax::Aeon),
+            "python" => Ok(ExpressionSyntax::Python),
+            "c" => Ok(ExpressionSyntax::C),
+            "sympy" => Ok(ExpressionSyntax::SymPy),
+            "bnet" => Ok(ExpressionSyntax::BNet),
+            _ => Err(format!(
+                "Unknown expression syntax `{value}`. Expected one of `aeon`, `python`, `c`, `sympy`, or `bnet`."
+            )),
+        }
+    }
+}
+
+impl ExpressionSyntax {
+    /// Parse a Boolean expression written in this syntax.
+    ///
+    /// Whitespace (including line breaks) is only significant as a separator.
+    pub fn parse(self, input: &str) -> Result<BooleanExpression, String> {
+        if self == ExpressionSyntax::Aeon {
+            let normalized = input.split_whitespace().collect::<Vec<_>>().join(" ");
+            return BooleanExpression::try_from(normalized.as_str());
+        }
+
+        let mut parser = Parser {
+            syntax: self,
+            tokens: self.tokenize(input)?,
+            position: 0,
+        };
+        let result = parser.expression(0)?;
+        if let Some(token) = parser.peek() {
+            return Err(format!("Unexpected `{token}`."));
+        }
+        Ok(result)
+    }
+
+    /// Print a Boolean expression using this syntax.
+    ///
+    /// If `minimal_parentheses` is set, only the parentheses required by the precedence and
+    /// associativity of the operators are used. Otherwise, every binary operator is
+    /// parenthesized. If `line_width` is given, the result is broken into lines of at most
+    /// this many characters (where possible).
+    pub fn print(
+        self,
+        expression: &BooleanExpression,
+        minimal_parentheses: bool,
+        line_width: Option<usize>,
+    ) -> String {
+        let text = if self == ExpressionSyntax::Aeon && !minimal_parentheses {
+            expression.to_string()
+        } else {
+            let expression = self.desugar(expression);
+            self.write(&expression, minimal_parentheses).0
+        };
+        match line_width {
+            Some(width) => wrap_lines(&text, width),
+            None => text,
+        }
+    }
+
+    /// The constants of this syntax. When printing, the first entry of each value is used.
+    fn constants(self) -> &'static [(&'static str, bool)] {
+        match self {
+            ExpressionSyntax::Aeon | ExpressionSyntax::C => {
+                &[("true", true), ("false", false), ("1", true), ("0", false)]
+            }
+            ExpressionSyntax::Python | ExpressionSyntax::SymPy => {
+                &[("True", true), ("False", false)]
+            }
+            ExpressionSyntax::BNet => {
+                &[("1", true), ("0", false), ("true", true), ("false", false)]
+            }
+        }
+    }
+
+    /// The negation operator and its precedence.
+    fn negation(self) -> (&'static str, u8) {
+        match self {
+            ExpressionSyntax::Python => ("not", 3),
+            ExpressionSyntax::SymPy => ("~", 10),
+            _ => ("!", 10),
+        }
+    }
+
+    /// The binary operators of this syntax.
+    ///
+    /// In the native syntax, all binary operators have the same precedence, such that
+    /// different operators are always separated by parentheses.
+    fn binary_operators(self) -> &'static [BinaryOperator] {
+        match self {
+            ExpressionSyntax::Aeon => &[
+                ("&", Operator::And, 1, true),
+                ("|", Operator::Or, 1, true),
+                ("^", Operator::Xor, 1, true),
+                ("=>", Operator::Imp, 1, false),
+                ("<=>", Operator::Iff, 1, true),
+            ],
+            ExpressionSyntax::Python => &[
+                ("and", Operator::And, 2, true),
+                ("or", Operator::Or, 1, true),
+                ("!=", Operator::Xor, 4, false),
+                ("==", Operator::Iff, 4, false),
+            ],
+            ExpressionSyntax::C => &[
+                ("&&", Operator::And, 4, true),
+                ("||", Operator::Or, 3, true),
+                ("^", Operator::Xor, 5, true),
+                ("!=", Operator::Xor, 7, false),
+                ("==", Operator::Iff, 7, false),
+            ],
+            ExpressionSyntax::SymPy => &[
+                ("&", Operator::And, 3, true),
+                ("|", Operator::Or, 1, true),
+                ("^", Operator::Xor, 2, true),
+            ],
+            ExpressionSyntax::BNet => {
+                &[("&", Operator::And, 2, true), ("|", Operator::Or, 1, true)]
+            }
+        }
+    }
+
+    /// The precedence of the `? :` conditional operator, if this syntax supports it.
+    ///
+    /// Similar to binary operators, the native conditional operator always separates
+    /// its compound operands using parentheses.
+    fn conditional(self) -> Option<u8> {
+        match self {
+            ExpressionSyntax::Aeon => Some(1),
+            ExpressionSyntax::C => Some(2),
+            _ => None,
+        }
+    }
+
+    /// True if this syntax uses SymPy-like function calls (`Implies`, `Equivalent`, `ITE`).
+    fn has_functions(self) -> bool {
+        self == ExpressionSyntax::SymPy
+    }
+
+    fn find_operator(self, operator: Operator) -> Option<BinaryOperator> {
+        self.binary_operators()
+            .iter()
+            .find(|it| it.1 == operator)
+            .copied()
+    }
+
+    /// Rewrite all operators that cannot be printed in this syntax.
+    fn desugar(self, expression: &BooleanExpression) -> BooleanExpression {
+        use BooleanExpression::*;
+        let binary = |operator: Operator, left: &BooleanExpression, right: &BooleanExpression| {
+            let left = self.desugar(left);
+            let right = self.desugar(right);
+            if self.has_functions() || self.find_operator(operator).is_some() {
+                return operator.apply(left, right);
+            }
+            let not = |it: &BooleanExpression| Not(Box::new(it.clone()));
+            let (x, y) = match operator {
+                Operator::Imp => return Operator::Or.apply(not(&left), right),
+                Operator::Iff => (
+                    Operator::And.apply(left.clone(), right.clone()),
+                    Operator::And.apply(not(&left), not(&right)),
+                ),
+                Operator::Xor => (
+                    Operator::And.apply(left.clone(), not(&right)),
+                    Operator::And.apply(not(&left), right.clone()),
+                ),
+                Operator::And | Operator::Or => unreachable!("Supported by every syntax."),
+            };
+            Operator::Or.apply(x, y)
+        };
+        match expression {
+            Const(_) | Variable(_) => expression.clone(),
+            Not(inner) => Not(Box::new(self.desugar(inner))),
+            And(l, r) => binary(Operator::And, l, r),
+            Or(l, r) => binary(Operator::Or, l, r),
+            Xor(l, r) => binary(Operator::Xor, l, r),
+            Imp(l, r) => binary(Operator::Imp, l, r),
+            Iff(l, r) => binary(Operator::Iff, l, r),
+            Cond(test, then, other) => {
+                let test = self.desugar(test);
+                let then = self.desugar(then);
+                let other = self.desugar(other);
+                if self.conditional().is_some() || self.has_functions() {
+                    Cond(Box::new(test), Box::new(then), Box::new(other))
+                } else {
+                    Operator::Or.apply(
+                        Operator::And.apply(test.clone(), then),
+                        Operator::And.apply(Not(Box::new(test)), other),
+                    )
+                }
+            }
+        }
+    }
+
+    /// Print an expression that only uses operators supported by this syntax.
+    fn write(self, expression: &BooleanExpression, minimal: bool) -> Printed {
+        use BooleanExpression::*;
+        match expression {
+            Const(value) => {
+                let (name, _) = self.constants().iter().find(|it| it.1 == *value).unwrap();
+                (name.to_string(), u8::MAX, None)
+            }
+            Variable(name) => (name.clone(), u8::MAX, None),
+            Not(inner) => {
+                let (token, precedence) = self.negation();
+                let (text, inner_precedence, _) = self.write(inner, minimal);
+                let text = if inner_precedence < precedence {
+                    format!("({text})")
+                } else {
+                    text
+                };
+                let separator = if is_name(token) { " " } else { "" };
+                (format!("{token}{separator}{text}"), precedence, None)
+            }
+            And(l, r) => self.write_binary(Operator::And, l, r, minimal),
+            Or(l, r) => self.write_binary(Operator::Or, l, r, minimal),
+            Xor(l, r) => self.write_binary(Operator::Xor, l, r, minimal),
+            Imp(l, r) => self.write_binary(Operator::Imp, l, r, minimal),
+            Iff(l, r) => self.write_binary(Operator::Iff, l, r, minimal),
+            Cond(test, then, other) => {
+                let test = self.write(test, minimal);
+                let then = self.write(then, minimal);
+                let other = self.write(other, minimal);
+                let Some(precedence) = self.conditional() else {
+                    let text = format!("ITE({}, {}, {})", test.0, then.0, other.0);
+                    return (text, u8::MAX, None);
+                };
+                let wrap = |printed: Printed| {
+                    if printed.1 <= precedence {
+                        format!("({})", printed.0)
+                    } else {
+                        printed.0
+                    }
+                };
+                let text = format!("{} ? {} : {}", wrap(test), wrap(then), wrap(other));
+                if minimal {
+                    (text, precedence, None)
+                } else {
+                    (format!("({text})"), u8::MAX, None)
+                }
+            }
+        }
+    }
+
+    fn write_binary(
+        self,
+        operator: Operator,
+        left: &BooleanExpression,
+        right: &BooleanExpression,
+        minimal: bool,
+    ) -> Printed {
+        let left = self.write(left, minimal);
+        let right = self.write(right, minimal);
+        let Some((token, precedence, associative)) = self
+            .find_operator(operator)
+            .map(|(token, _, precedence, associative)| (token, precedence, associative))
+        else {
+            let name = if operator == Operator::Imp {
+                "Implies"
+            } else {
+                "Equivalent"
+            };
+            return (format!("{name}({}, {})", left.0, right.0), u8::MAX, None);
+        };
+        let wrap = |printed: Printed| {
+            let same_operator = printed.2 == Some(operator) && associative;
+            if printed.1 < precedence || (printed.1 == precedence && !same_operator) {
+                format!("({})", printed.0)
+            } else {
+                printed.0
+            }
+        };
+        let text = format!("{} {token} {}", wrap(left), wrap(right));
+        if minimal {
+            (text, precedence, Some(operator))
+        } else {
+            (format!("({text})"), u8::MAX, None)
+        }
+    }
+
+    /// Split `input` into names, operators, parentheses, and commas.
+    fn tokenize(self, input: &str) -> Result<Vec<String>, String> {
+        let mut symbols: Vec<&str> = self
+            .binary_operators()
+            .iter()
+            .map(|it| it.0)
+            .chain([self.negation().0, "(", ")", ","])
+            .filter(|it| !is_name(it))
+            .collect();
+        if self.conditional().is_some() {
+            symbols.extend(["?", ":"]);
+        }
+        // Longer symbols first, such that e.g. `!=` is not read as `!`.
+        symbols.sort_by_key(|it| std::cmp::Reverse(it.len()));
+
+        let mut tokens = Vec::new();
+        let mut rest = input.trim_start();
+        while let Some(first) = rest.chars().next() {
+            if let Some(symbol) = symbols.iter().find(|it| rest.starts_with(*it)) {
+                tokens.push(symbol.to_string());
+                rest = &rest[symbol.len()..];
+            } else {
+                let length = rest.find(|c: char| !is_name_char(c)).unwrap_or(rest.len());
+                if length == 0 {
+                    return Err(format!("Unexpected character `{first}`."));
+                }
+                tokens.push(rest[..length].to_string());
+                rest = &rest[length..];
+            }
+            rest = rest.trim_start();
+        }
+        Ok(tokens)
+    }
+}
+
+impl Operator {
+    fn apply(self, left: BooleanExpression, right: BooleanExpression) -> BooleanExpression {
+        let (left, right) = (Box::new(left), Box::new(right));
+        match self {
+            Operator::And => BooleanExpression::And(left, right),
+            Operator::Or => BooleanExpression::Or(left, right),
+            Operator::Xor => BooleanExpression::Xor(left, right),
+            Operator::Imp => BooleanExpression::Imp(left, right),
+            Operator::Iff => BooleanExpression::Iff(left, right),
+        }
+    }
+}
+
+/// A precedence climbing parser for the non-native syntaxes.
+struct Parser {
+    syntax: ExpressionSyntax,
+    tokens: Vec<String>,
+    position: usize,
+}
+
+impl Parser {
+    fn peek(&self) -> Option<&str> {
+        self.tokens.get(self.position).map(|it| it.as_str())
+    }
+
+    fn advance(&mut self) -> Option<String> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    fn expect(&mut self, expected: &str) -> Result<(), String> {
+        match self.advance() {
+            Some(token) if token == expected => Ok(()),
+            Some(token) => Err(format!("Expected `{expected}`, found `{token}`.")),
+            None => Err(format!("Expected `{expected}`, found end of input.")),
+        }
+    }
+
+    /// Parse an expression whose operators all have at least the given precedence.
+    fn expression(&mut self, min_precedence: u8) -> Result<BooleanExpression, String> {
+        let mut left = self.operand()?;
+        let mut non_associative: Option<u8> = None;
+        while let Some(token) = self.peek().map(|it| it.to_string()) {
+            if token == "?" {
+                let Some(precedence) = self.syntax.conditional() else {
+                    break;
+                };
+                if precedence < min_precedence {
+                    break;
+                }
+                self.position += 1;
+                let then = self.expression(0)?;
+                self.expect(":")?;
+                let other = self.expression(precedence)?;
+                left = BooleanExpression::Cond(Box::new(left), Box::new(then), Box::new(other));
+                continue;
+            }
+            let Some(&(_, operator, precedence, associative)) = self
+                .syntax
+                .binary_operators()
+                .iter()
+                .find(|it| it.0 == token)
+            else {
+                break;
+            };
+            if precedence < min_precedence {
+                break;
+            }
+            if non_associative == Some(precedence) {
+                return Err(format!(
+                    "Operator `{token}` cannot be chained without parentheses."
+                ));
+            }
+            self.position += 1;
+            let right = self.expression(precedence + 1)?;
+            left = operator.apply(left, right);
+            non_associative = (!associative).then_some(precedence);
+        }
+        Ok(left)
+    }
+
+    /// Parse a constant, variable, negation, parenthesized expression, or function call.
+    fn operand(&mut self) -> Result<BooleanExpression, String> {
+        let Some(token) = self.advance() else {
+            return Err("Unexpected end of input.".to_string());
+        };
+        let (negation, precedence) = self.syntax.negation();
+        if token == negation {
+            return Ok(BooleanExpression::Not(Box::new(
+                self.expression(precedence)?,
+            )));
+        }
+        if token == "(" {
+            let inner = self.expression(0)?;
+            self.expect(")")?;
+            return Ok(inner);
+        }
+        if let Some((_, value)) = self.syntax.constants().iter().find(|it| it.0 == token) {
+            return Ok(BooleanExpression::Const(*value));
+        }
+        let is_operator = self
+            .syntax
+            .binary_operators()
+            .iter()
+            .any(|it| it.0 == token);
+        if !is_name(&token) || is_operator {
+            return Err(format!("Unexpected `{token}`."));
+        }
+        if self.syntax.has_functions() && self.peek() == Some("(") {
+            return self.function(&token);
+        }
+        Ok(BooleanExpression::Variable(token))
+    }
+
+    /// Parse the arguments of a SymPy-like function call.
+    fn function(&mut self, name: &str) -> Result<BooleanExpression, String> {
+        self.expect("(")?;
+        let mut args = vec![self.expression(0)?];
+        while self.peek() == Some(",") {
+            self.position += 1;
+            args.push(self.expression(0)?);
+        }
+        self.expect(")")?;
+
+        let fold = |operator: Operator, args: Vec<BooleanExpression>| {
+            args.into_iter()
+                .reduce(|left, right| operator.apply(left, right))
+                .unwrap()
+        };
+        match name {
+            "And" => return Ok(fold(Operator::And, args)),
+            "Or" => return Ok(fold(Operator::Or, args)),
+            "Xor" => return Ok(fold(Operator::Xor, args)),
+            _ => (),
+        }
+
+        let count = args.len();
+        let mut args = args.into_iter().map(Box::new);
+        let mut arg = || args.next().unwrap();
+        match (name, count) {
+            ("Not", 1) => Ok(BooleanExpression::Not(arg())),
+            ("Implies", 2) => Ok(BooleanExpression::Imp(arg(), arg())),
+            ("Equivalent", 2) => Ok(BooleanExpression::Iff(arg(), arg())),
+            ("ITE", 3) => Ok(BooleanExpression::Cond(arg(), arg(), arg())),
+            ("Not" | "Implies" | "Equivalent" | "ITE", _) => Err(format!(
+                "Function `{name}` cannot be applied to {count} argument(s)."
+            )),
+            _ => Err(format!("Unknown function `{name}`.")),
+        }
+    }
+}
+
+fn is_name_char(c: char) -> bool {
+    c.is_alphanumeric() || matches!(c, '_' | '{' | '}' | '.')
+}
+
+fn is_name(token: &str) -> bool {
+    !token.is_empty() && token.chars().all(is_name_char)
+}
+
+/// Break `text` into lines of at most `width` characters. Lines are only broken at spaces,
+/// hence a line can be longer if it contains a single long token.
+fn wrap_lines(text: &str, width: usize) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut line_length = 0;
+    for word in text.split(' ') {
+        let word_length = word.chars().count();
+        if line_length > 0 && line_length + 1 + word_length > width {
+            result.push('\n');
+            line_length = 0;
+        } else if line_length > 0 {
+            result.push(' ');
+            line_length += 1;
+        }
+        result.push_str(word);
+        line_length += word_length;
+    }
+    result
+}
@@ -1,3 +1,7 @@
 pub mod algorithms;
 pub mod classification;
+pub mod expression_syntax;
 pub mod scc;
+pub mod shared_bdd;
+pub mod smv;
+pub mod temporal_syntax;
@@ -0,0 +1,361 @@
+//! A node table that stores multiple BDDs with shared structure, together with its compact
+//! binary encoding.
+//!
+//! All BDDs are stored in a single node table, such that nodes which appear in multiple BDDs
+//! are only stored (and written) once. In the binary format, every integer is written as an
+//! unsigned LEB128 varint, and node links are stored relative to the index of the node that
+//! references them (these differences tend to be small even for very large tables). The
+//! encoded data can then be further compressed (e.g., by an enclosing zip archive).
+//!
+//! The layout of the encoded data is as follows:
+//!
+//!  - Magic bytes `AEONBDD` followed by a single format version byte.
+//!  - Number of BDD variables.
+//!  - Number of non-terminal nodes in the shared table.
+//!  - For every non-terminal node `i` (starting at `i = 2`; `0` and `1` are the terminal
+//!    nodes): `var`, `i - low`, `i - high`.
+//!  - Number of stored BDDs.
+//!  - For every BDD: name length, UTF-8 name bytes, index of the root node.
+
+use std::collections::{HashMap, HashSet};
+use std::io::{Read, Write};
+
+use biodivine_lib_bdd::{Bdd, BddNode, BddPointer, BddVariable};
+
+const MAGIC: &[u8; 7] = b"AEONBDD";
+const VERSION: u8 = 1;
+
+/// A BDD node in a [SharedBddTable]: decision variable, low link, and high link.
+type SharedNode = (u16, usize, usize);
+
+/// A table of BDD nodes shared by multiple BDDs over the same number of variables.
+///
+/// Each BDD in the table is identified by the index of its root node. Nodes are stored
+/// such that the children always precede their parents. Indices `0` and `1` are
+/// reserved for the terminal nodes.
+#[derive(Clone)]
+pub struct SharedBddTable {
+    num_vars: u16,
+    nodes: Vec<SharedNode>,
+    node_index: HashMap<SharedNode, usize>,
+}
+
+impl SharedBddTable {
+    /// Create a new table that only contains the two terminal nodes.
+    pub fn new(num_vars: u16) -> SharedBddTable {
+        SharedBddTable {
+            num_vars,
+            nodes: vec![(num_vars, 0, 0), (num_vars, 1, 1)],
+            node_index: HashMap::new(),
+        }
+    }
+
+    /// The number of variables of all BDDs in this table.
+    pub fn num_vars(&self) -> u16 {
+        self.num_vars
+    }
+
+    /// The number of nodes in this table (including the two terminal nodes).
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Insert the given [Bdd] into the table and return the index of its root node.
+    ///
+    /// The BDD must use the same number of variables as the table.
+    pub fn insert(&mut self, bdd: &Bdd) -> usize {
+        assert_eq!(bdd.num_vars(), self.num_vars);
+        // Lib-bdd stores nodes such that the children always precede their parents,
+        // hence the translation of links is always known when a node is processed.
+        let mut translation: Vec<usize> = Vec::with_capacity(bdd.size());
+        for pointer in bdd.pointers() {
+            if pointer.is_zero() {
+                translation.push(0);
+                continue;
+            }
+            if pointer.is_one() {
+                translation.push(1);
+                continue;
+            }
+            let var = u16::try_from(bdd.var_of(pointer).to_index()).unwrap();
+            let low = translation[bdd.low_link_of(pointer).to_index()];
+            let high = translation[bdd.high_link_of(pointer).to_index()];
+            translation.push(self.insert_node((var, low, high)));
+        }
+        translation[bdd.root_pointer().to_index()]
+    }
+
+    /// Build a standalone [Bdd] which consists of all nodes of this table that are
+    /// reachable from `root`.
+    pub fn extract(&self, root: usize) -> Bdd {
+        let num_vars = self.num_vars;
+        if root == 0 {
+            return Bdd::from_nodes(&[BddNode::mk_zero(num_vars)]).unwrap();
+        }
+
+        // Assign new indices to the reachable nodes in post-order, such that the children
+        // precede their parents and the root is the last node (as required by lib-bdd).
+        let mut translation: HashMap<usize, usize> = HashMap::from([(0, 0), (1, 1)]);
+        let mut data = vec![BddNode::mk_zero(num_vars), BddNode::mk_one(num_vars)];
+        let mut stack: Vec<usize> = vec![root];
+        while let Some(&top) = stack.last() {
+            if translation.contains_key(&top) {
+                stack.pop();
+                continue;
+            }
+            let (var, low, high) = self.nodes[top];
+            let mut expanded = false;
+            for child in [high, low] {
+                if !translation.contains_key(&child) {
+                    stack.push(child);
+                    expanded = true;
+                }
+            }
+            if !expanded {
+                stack.pop();
+                translation.insert(top, data.len());
+                data.push(BddNode::mk_node(
+                    BddVariable::from_index(usize::from(var)),
+                    BddPointer::from_index(translation[&low]),
+                    BddPointer::from_index(translation[&high]),
+                ));
+            }
+        }
+
+        // The table only contains valid, reduced nodes, hence the result is a valid BDD.
+        Bdd::from_nodes(&data).unwrap()
+    }
+
+    /// The number of nodes of the [Bdd] with the given `root` (including terminal nodes),
+    /// i.e., the size of the result of [SharedBddTable::extract].
+    pub fn bdd_size(&self, root: usize) -> usize {
+        if root == 0 {
+            1
+        } else {
+            self.reachable_count(&[root])
+        }
+    }
+
+    /// The number of nodes reachable from the given `roots` (including the two terminal nodes).
+    pub fn reachable_count(&self, roots: &[usize]) -> usize {
+        self.reachable_nodes(roots).len() + 2
+    }
+
+    /// Create a copy of this table which only contains nodes reachable from the given `roots`.
+    /// Returns the new table and the translated root indices.
+    pub fn compact(&self, roots: &[usize]) -> (SharedBddTable, Vec<usize>) {
+        let reachable = self.reachable_nodes(roots);
+
+        // Since children precede parents, processing nodes in the increasing order
+        // preserves this property in the new table.
+        let mut result = SharedBddTable::new(self.num_vars);
+        let mut translation: Vec<usize> = vec![0, 1];
+        for (node, (var, low, high)) in self.nodes.iter().enumerate().skip(2) {
+            if reachable.contains(&node) {
+                translation.push(result.insert_node((*var, translation[*low], translation[*high])));
+            } else {
+                translation.push(usize::MAX);
+            }
+        }
+        let roots = roots.iter().map(|it| translation[*it]).collect();
+        (result, roots)
+    }
+
+    /// Write this table and the given named roots into `output` using the binary format.
+    pub fn write<W: Write>(
+        &self,
+        output: &mut W,
+        roots: &[(String, usize)],
+    ) -> Result<(), std::io::Error> {
+        output.write_all(MAGIC)?;
+        output.write_all(&[VERSION])?;
+        write_varint(output, u64::from(self.num_vars))?;
+        write_varint(output, (self.nodes.len() - 2) as u64)?;
+        for (id, (var, low, high)) in self.nodes.iter().enumerate().skip(2) {
+            write_varint(output, u64::from(*var))?;
+            write_varint(output, (id - low) as u64)?;
+            write_varint(output, (id - high) as u64)?;
+        }
+        write_varint(output, roots.len() as u64)?;
+        for (name, root) in roots {
+            write_string(output, name)?;
+            write_varint(output, *root as u64)?;
+        }
+        Ok(())
+    }
+
+    /// Read a table and its named roots previously written using [SharedBddTable::write].
+    pub fn read<R: Read>(input: &mut R) -> Result<(SharedBddTable, Vec<(String, usize)>), String> {
+        let mut header = [0u8; 8];
+        input
+            .read_exact(&mut header)
+            .map_err(|e| format!("Cannot read BDD archive header: {e}"))?;
+        if &header[..7] != MAGIC {
+            return Err("Invalid BDD archive: wrong magic bytes.".to_string());
+        }
+        if header[7] != VERSION {
+            return Err(format!(
+                "Unsupported BDD archive version {} (expected {VERSION}).",
+                header[7]
+            ));
+        }
+
+        let num_vars = read_varint(input)?;
+        let num_vars =
+            u16::try_from(num_vars).map_err(|_| format!("Invalid variable count {num_vars}."))?;
+        let node_count = read_varint(input)? as usize;
+
+        let mut table = SharedBddTable::new(num_vars);
+        for _ in 0..node_count {
+            let id = table.nodes.len();
+            let var = read_varint(input)?;
+            let low_delta = read_varint(input)? as usize;
+            let high_delta = read_varint(input)? as usize;
+            if var >= u64::from(num_vars) {
+                return Err(format!(
+                    "Invalid BDD archive: node {id} uses variable {var}."
+                ));
+            }
+            if low_delta == 0
+                || low_delta > id
+                || high_delta == 0
+                || high_delta > id
+                || low_delta == high_delta
+            {
+                return Err(format!("Invalid BDD archive: node {id} has invalid links."));
+            }
+            let node = (var as u16, id - low_delta, id - high_delta);
+            // Terminal nodes use `num_vars` as their variable, hence they pass this check.
+            if table.nodes[node.1].0 <= node.0 || table.nodes[node.2].0 <= node.0 {
+                return Err(format!(
+                    "Invalid BDD archive: node {id} violates the variable ordering."
+                ));
+            }
+            if table.node_index.insert(node, id).is_some() {
+                return Err(format!("Invalid BDD archive: node {id} is duplicated."));
+            }
+            table.nodes.push(node);
+        }
+
+        let root_count = read_varint(input)? as usize;
+        let mut roots = Vec::with_capacity(root_count.min(1 << 16));
+        for _ in 0..root_count {
+            let name = read_string(input)?;
+            let root = read_varint(input)? as usize;
+            if root >= table.nodes.len() {
+                return Err(format!(
+                    "Invalid BDD archive: root of `{name}` does not exist."
+                ));
+            }
+            roots.push((name, root));
+        }
+
+        Ok((table, roots))
+    }
+
+    /// Compute the indices of all non-terminal nodes reachable from the given `roots`.
+    fn reachable_nodes(&self, roots: &[usize]) -> HashSet<usize> {
+        let mut reachable = HashSet::new();
+        let mut stack = roots.to_vec();
+        while let Some(node) = stack.pop() {
+            if node > 1 && reachable.insert(node) {
+                let (_, low, high) = self.nodes[node];
+                stack.push(low);
+                stack.push(high);
+            }
+        }
+        reachable
+    }
+
+    /// Find or create the given node and return its index.
+    fn insert_node(&mut self, node: SharedNode) -> usize {
+        *self.node_index.entry(node).or_insert_with(|| {
+            self.nodes.push(node);
+            self.nodes.len() - 1
+        })
+    }
+}
+
+/// Write the given named BDDs into `output` using the shared-node binary format.
+///
+/// All BDDs must use the same number of variables.
+pub fn write_bdd_archive<W: Write>(
+    output: &mut W,
+    bdds: &[(String, &Bdd)],
+) -> Result<(), std::io::Error> {
+    let num_vars = bdds.first().map(|(_, bdd)| bdd.num_vars()).unwrap_or(0);
+    if bdds.iter().any(|(_, bdd)| bdd.num_vars() != num_vars) {
+        return Err(std::io::Error::other(
+            "All BDDs in an archive must use the same number of variables.",
+        ));
+    }
+
+    let mut table = SharedBddTable::new(num_vars);
+    let roots = bdds
+        .iter()
+        .map(|(name, bdd)| (name.clone(), table.insert(bdd)))
+        .collect::<Vec<_>>();
+    table.write(output, &roots)
+}
+
+/// Read a collection of named BDDs previously written using [write_bdd_archive].
+pub fn read_bdd_archive<R: Read>(input: &mut R) -> Result<Vec<(String, Bdd)>, String> {
+    let (table, roots) = SharedBddTable::read(input)?;
+    Ok(roots
+        .into_iter()
+        .map(|(name, root)| (name, table.extract(root)))
+        .collect())
+}
+
+/// Write an unsigned LEB128 integer.
+pub fn write_varint<W: Write>(output: &mut W, mut value: u64) -> Result<(), std::io::Error> {
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            return output.write_all(&[byte]);
+        }
+        output.write_all(&[byte | 0x80])?;
+    }
+}
+
+/// Read an unsigned LEB128 integer.
+pub fn read_varint<R: Read>(input: &mut R) -> Result<u64, String> {
+    let mut result = 0u64;
+    let mut shift = 0;
+    loop {
+        let mut byte = [0u8; 1];
+        input
+            .read_exact(&mut byte)
+            .map_err(|e| format!("Cannot read BDD archive: {e}"))?;
+        if shift >= 64 {
+            return Err("Invalid BDD archive: integer overflow.".to_string());
+        }
+        result |= u64::from(byte[0] & 0x7f) << shift;
+        if byte[0] & 0x80 == 0 {
+            return Ok(result);
+        }
+        shift += 7;
+    }
+}
+
+/// Write a length-prefixed UTF-8 string.
+pub fn write_string<W: Write>(output: &mut W, value: &str) -> Result<(), std::io::Error> {
+    write_varint(output, value.len() as u64)?;
+    output.write_all(value.as_bytes())
+}
+
+/// Read a length-prefixed UTF-8 string.
+pub fn read_string<R: Read>(input: &mut R) -> Result<String, String> {
+    let len = read_varint(input)? as usize;
+    let mut bytes = Vec::new();
+    input
+        .by_ref()
+        .take(len as u64)
+        .read_to_end(&mut bytes)
+        .map_err(|e| format!("Cannot read BDD archive: {e}"))?;
+    if bytes.len() != len {
+        return Err("Invalid BDD archive: unexpected end of data.".to_string());
+    }
+    String::from_utf8(bytes).map_err(|e| format!("Invalid BDD archive string: {e}"))
+}
@@ -0,0 +1,334 @@
+//! Export of Boolean networks and CTL formulas into the input language of NuSMV/nuXmv.
+//!
+//! Network variables become boolean `VAR` declarations. Every row of every (explicit or
+//! implicit) uninterpreted function becomes a boolean `FROZENVAR`, such that each initial
+//! valuation of the frozen variables describes one network color. The regulation
+//! constraints of the network are encoded as an `INIT` constraint on these variables.
+//!
+//! Naming of the frozen variables:
+//!  - `p` for an explicit parameter `p` of arity zero;
+//!  - `p__010` for the row `(0, 1, 0)` of an explicit parameter `p`;
+//!  - `v__implicit__010` for the row `(0, 1, 0)` of the implicit update function of `v`.
+
+use biodivine_hctl_model_checker::preprocessing::hctl_tree::{HctlTreeNode, NodeType};
+use biodivine_hctl_model_checker::preprocessing::operator_enums::{Atomic, BinaryOp, UnaryOp};
+use biodivine_lib_bdd::{Bdd, BddVariable};
+use biodivine_lib_param_bn::BooleanNetwork;
+use biodivine_lib_param_bn::symbolic_async_graph::SymbolicAsyncGraph;
+use std::collections::{HashMap, HashSet};
+
+/// Identifiers that have a special meaning in the SMV language and cannot be used as names.
+const RESERVED_WORDS: &[&str] = &[
+    "MODULE",
+    "VAR",
+    "IVAR",
+    "FROZENVAR",
+    "DEFINE",
+    "ASSIGN",
+    "INIT",
+    "TRANS",
+    "INVAR",
+    "SPEC",
+    "CTLSPEC",
+    "LTLSPEC",
+    "FAIRNESS",
+    "init",
+    "next",
+    "case",
+    "esac",
+    "TRUE",
+    "FALSE",
+    "boolean",
+    "xor",
+    "xnor",
+    "mod",
+    "self",
+    "in",
+    "union",
+    "A",
+    "E",
+    "F",
+    "G",
+    "X",
+    "U",
+    "V",
+    "W",
+    "Y",
+    "Z",
+    "H",
+    "O",
+    "S",
+    "T",
+    "EX",
+    "AX",
+    "EF",
+    "AF",
+    "EG",
+    "AG",
+    "EU",
+    "AU",
+];
+
+/// Name of the input variable that selects the updated network variable in the
+/// asynchronous semantics.
+const CHOICE_VARIABLE: &str = "choice";
+
+/// Translate a Boolean network into an SMV model.
+///
+/// With `synchronous = false`, a single variable (selected by the input variable `choice`)
+/// is updated in every step, and only variables that actually change their value can be
+/// selected. States where no variable can change have a self-loop. With `synchronous = true`,
+/// all variables are updated at once.
+pub fn network_to_smv(network: &BooleanNetwork, synchronous: bool) -> Result<String, String> {
+    if network.num_vars() == 0 {
+        return Err("Cannot export an empty network to SMV.".to_string());
+    }
+    let graph = SymbolicAsyncGraph::new(network)?;
+    let ctx = graph.symbolic_context();
+
+    let mut names: HashMap<BddVariable, String> = HashMap::new();
+    let mut state_names = Vec::new();
+    for var in network.variables() {
+        let name = network.get_variable_name(var).clone();
+        names.insert(ctx.get_state_variable(var), name.clone());
+        state_names.push(name);
+    }
+    let mut frozen_names = Vec::new();
+    for par in network.parameters() {
+        let name = network.get_parameter(par).get_name();
+        for (row, bdd_var) in ctx.get_explicit_function_table(par) {
+            let row_name = mk_row_name(name, &row);
+            names.insert(bdd_var, row_name.clone());
+            frozen_names.push(row_name);
+        }
+    }
+    for var in network.variables() {
+        let Some(table) = ctx.get_implicit_function_table(var) else {
+            continue;
+        };
+        let name = format!("{}__implicit", network.get_variable_name(var));
+        for (row, bdd_var) in table {
+            let row_name = mk_row_name(&name, &row);
+            names.insert(bdd_var, row_name.clone());
+            frozen_names.push(row_name);
+        }
+    }
+
+    let mut defined_names = Vec::new();
+    for name in &state_names {
+        defined_names.push(format!("{name}__update"));
+        if !synchronous {
+            defined_names.push(format!("{name}__enabled"));
+        }
+    }
+
+    let mut used = HashSet::new();
+    if !synchronous {
+        used.insert(CHOICE_VARIABLE.to_string());
+    }
+    for name in state_names
+        .iter()
+        .chain(&frozen_names)
+        .chain(&defined_names)
+    {
+        check_identifier(name)?;
+        if !used.insert(name.clone()) {
+            return Err(format!(
+                "Name `{name}` is used more than once in the SMV model."
+            ));
+        }
+    }
+
+    let semantics = if synchronous {
+        "synchronous"
+    } else {
+        "asynchronous"
+    };
+    let mut result = format!("-- Boolean network exported using {semantics} semantics.\n");
+    result.push_str("MODULE main\n");
+    result.push_str("VAR\n");
+    for name in &state_names {
+        result.push_str(&format!("    {name} : boolean;\n"));
+    }
+    if !frozen_names.is_empty() {
+        result.push_str("FROZENVAR\n");
+        for name in &frozen_names {
+            result.push_str(&format!("    {name} : boolean;\n"));
+        }
+    }
+    if !synchronous {
+        result.push_str("IVAR\n");
+        result.push_str(&format!(
+            "    {CHOICE_VARIABLE} : 0..{};\n",
+            state_names.len() - 1
+        ));
+    }
+
+    result.push_str("DEFINE\n");
+    for (var, name) in network.variables().zip(&state_names) {
+        let update = bdd_to_smv(graph.get_symbolic_fn_update(var), &names);
+        result.push_str(&format!("    {name}__update := {update};\n"));
+        if !synchronous {
+            result.push_str(&format!(
+                "    {name}__enabled := {name}__update != {name};\n"
+            ));
+        }
+    }
+
+    let colors = graph.unit_colors().as_bdd();
+    if !colors.is_true() {
+        result.push_str(&format!("INIT\n    {};\n", bdd_to_smv(colors, &names)));
+    }
+
+    result.push_str("ASSIGN\n");
+    for (i, name) in state_names.iter().enumerate() {
+        if synchronous {
+            result.push_str(&format!("    next({name}) := {name}__update;\n"));
+        } else {
+            result.push_str(&format!(
+                "    next({name}) := case {CHOICE_VARIABLE} = {i} : {name}__update; TRUE : {name}; esac;\n"
+            ));
+        }
+    }
+
+    if !synchronous {
+        let enabled = state_names
+            .iter()
+            .map(|name| format!("{name}__enabled"))
+            .collect::<Vec<_>>();
+        let steps = enabled
+            .iter()
+            .enumerate()
+            .map(|(i, name)| format!("({CHOICE_VARIABLE} = {i} & {name})"))
+            .collect::<Vec<_>>();
+        result.push_str("TRANS\n");
+        result.push_str(&format!(
+            "    {} | !({});\n",
+            steps.join(" | "),
+            enabled.join(" | ")
+        ));
+    }
+
+    Ok(result)
+}
+
+/// Translate the CTL fragment of HCTL into an SMV `CTLSPEC` expression.
+///
+/// Formulas with hybrid operators, state variables, or extended propositions are rejected.
+/// The weak until operators are rewritten using `EU` and `EG`, since they are not
+/// part of the SMV syntax.
+pub fn formula_to_smv_ctl(formula: &HctlTreeNode) -> Result<String, String> {
+    Ok(match &formula.node_type {
+        NodeType::Terminal(Atomic::True) => "TRUE".to_string(),
+        NodeType::Terminal(Atomic::False) => "FALSE".to_string(),
+        NodeType::Terminal(Atomic::Prop(name)) => {
+            check_identifier(name)?;
+            name.clone()
+        }
+        NodeType::Terminal(Atomic::Var(name)) => {
+            return Err(format!(
+                "State variable `{{{name}}}` is not supported in CTL."
+            ));
+        }
+        NodeType::Terminal(Atomic::WildCardProp(name)) => {
+            return Err(format!(
+                "Extended proposition `%{name}%` is not supported in CTL."
+            ));
+        }
+        NodeType::Unary(op, a) => {
+            let a = formula_to_smv_ctl(a)?;
+            match op {
+                UnaryOp::Not => format!("!{a}"),
+                UnaryOp::EX => format!("EX {a}"),
+                UnaryOp::AX => format!("AX {a}"),
+                UnaryOp::EF => format!("EF {a}"),
+                UnaryOp::AF => format!("AF {a}"),
+                UnaryOp::EG => format!("EG {a}"),
+                UnaryOp::AG => format!("AG {a}"),
+            }
+        }
+        NodeType::Binary(op, a, b) => {
+            let a = formula_to_smv_ctl(a)?;
+            let b = formula_to_smv_ctl(b)?;
+            match op {
+                BinaryOp::And => format!("({a} & {b})"),
+                BinaryOp::Or => format!("({a} | {b})"),
+                BinaryOp::Xor => format!("({a} xor {b})"),
+                BinaryOp::Imp => format!("({a} -> {b})"),
+                BinaryOp::Iff => format!("({a} <-> {b})"),
+                BinaryOp::EU => format!("E [ {a} U {b} ]"),
+                BinaryOp::AU => format!("A [ {a} U {b} ]"),
+                // E[a W b] = E[a U b] | EG a
+                BinaryOp::EW => format!("(E [ {a} U {b} ] | EG {a})"),
+                // A[a W b] = !E[!b U (!a & !b)]
+                BinaryOp::AW => format!("!E [ !{b} U (!{a} & !{b}) ]"),
+            }
+        }
+        NodeType::Hybrid(..) => {
+            return Err(format!(
+                "Hybrid operators are not supported in CTL (found in `{formula}`)."
+            ));
+        }
+    })
+}
+
+fn mk_row_name(name: &str, row: &[bool]) -> String {
+    if row.is_empty() {
+        return name.to_string();
+    }
+    let bits = row
+        .iter()
+        .map(|it| if *it { '1' } else { '0' })
+        .collect::<String>();
+    format!("{name}__{bits}")
+}
+
+/// Check that the name is a valid SMV identifier which is not a reserved word.
+fn check_identifier(name: &str) -> Result<(), String> {
+    let mut chars = name.chars();
+    let valid_start = chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_');
+    let valid_rest = chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '$' | '#' | '-'));
+    if !valid_start || !valid_rest {
+        return Err(format!("Name `{name}` is not a valid SMV identifier."));
+    }
+    if RESERVED_WORDS.contains(&name) {
+        return Err(format!("Name `{name}` is a reserved word in SMV."));
+    }
+    Ok(())
+}
+
+/// Print a BDD as an SMV expression in disjunctive normal form.
+fn bdd_to_smv(bdd: &Bdd, names: &HashMap<BddVariable, String>) -> String {
+    if bdd.is_false() {
+        return "FALSE".to_string();
+    }
+    if bdd.is_true() {
+        return "TRUE".to_string();
+    }
+    let clauses = bdd
+        .to_dnf()
+        .into_iter()
+        .map(|clause| {
+            let literals = clause
+                .to_values()
+                .into_iter()
+                .map(|(var, value)| {
+                    let name = &names[&var];
+                    if value {
+                        name.clone()
+                    } else {
+                        format!("!{name}")
+                    }
+                })
+                .collect::<Vec<_>>();
+            if literals.len() == 1 {
+                literals.into_iter().next().unwrap()
+            } else {
+                format!("({})", literals.join(" & "))
+            }
+        })
+        .collect::<Vec<_>>();
+    clauses.join(" | ")
+}
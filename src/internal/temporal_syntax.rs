@@ -0,0 +1,588 @@
+//! Extensions of the HCTL syntax that are translated into plain HCTL formulas, such that
+//! they can be checked by the HCTL model checker without any further changes:
+//!
+//!  - Bounded temporal operators `EF[<=k]`, `AF[<=k]`, `EG[<=k]`, and `AG[<=k]`, which are
+//!    unrolled into `k` nested `EX`/`AX` operators.
+//!  - A fragment of LTL (`X`, `F`, `G`, `U`, `W`) where the formula is expected to hold on
+//!    all paths. Every formula of the fragment has an equivalent (H)CTL formula.
+
+use biodivine_hctl_model_checker::preprocessing::hctl_tree::HctlTreeNode;
+use biodivine_hctl_model_checker::preprocessing::operator_enums::{BinaryOp, HybridOp, UnaryOp};
+
+const BOUNDED_OPERATORS: [&str; 4] = ["EF", "AF", "EG", "AG"];
+const UNARY_OPERATORS: [&str; 6] = ["EX", "AX", "EF", "AF", "EG", "AG"];
+
+/// The largest supported bound of a bounded operator. The unrolled formula contains one copy
+/// of the operand for every step, hence larger bounds are not practical anyway.
+pub const MAX_BOUND: usize = 1000;
+
+/// The largest supported length of a formula after all bounded operators are unrolled.
+/// Without this limit, nested bounded operators could produce formulas of size `k^n`.
+const MAX_EXPANDED_LENGTH: usize = 1_000_000;
+
+/// Build a plain HCTL formula equivalent to the bounded operator `op[<=bound] inner`.
+///
+/// The `op` must be one of `EF`, `AF`, `EG`, or `AG`. Here, `EF[<=k] phi` means that `phi`
+/// can be reached in at most `k` steps, while `AG[<=k] phi` means that `phi` holds during
+/// the first `k` steps of every path. The `bound` cannot exceed [MAX_BOUND], and the
+/// unrolled formula cannot exceed `MAX_EXPANDED_LENGTH` characters.
+pub fn mk_bounded_operator(
+    op: &UnaryOp,
+    inner: &HctlTreeNode,
+    bound: usize,
+) -> Result<HctlTreeNode, String> {
+    let (is_exist, is_future) = match op {
+        UnaryOp::EF => (true, true),
+        UnaryOp::AF => (false, true),
+        UnaryOp::EG => (true, false),
+        UnaryOp::AG => (false, false),
+        _ => return Err("Only `EF`, `AF`, `EG`, and `AG` support bounds.".to_string()),
+    };
+    check_bound(bound)?;
+    let inner_length = inner.to_string().len();
+    if inner_length.saturating_mul(bound.saturating_add(1)) > MAX_EXPANDED_LENGTH {
+        return Err(expansion_too_large());
+    }
+    let mut result = inner.clone();
+    for _ in 0..bound {
+        let next_op = if is_exist { UnaryOp::EX } else { UnaryOp::AX };
+        let bool_op = if is_future {
+            BinaryOp::Or
+        } else {
+            BinaryOp::And
+        };
+        let next = HctlTreeNode::mk_unary(result, next_op);
+        result = HctlTreeNode::mk_binary(inner.clone(), next, bool_op);
+    }
+    Ok(result)
+}
+
+/// Rewrite all bounded operators (e.g. `EF[<=3] phi`) in the given HCTL formula string into
+/// plain HCTL (see [mk_bounded_operator]).
+///
+/// The operand of a bounded operator follows the same rules as the operand of other unary
+/// operators, i.e. it is a proposition, a parenthesized formula, or another unary
+/// operator application. A hybrid operator extends to the end of the enclosing parentheses.
+pub fn expand_bounded_operators(formula: &str) -> Result<String, String> {
+    let mut formula = formula.to_string();
+    // Always expand the last operator first, such that its operand is already plain HCTL.
+    while let Some(bracket) = formula.rfind('[') {
+        let (start, op) = bounded_operator_start(&formula, bracket)?;
+        let (bound, operand_start) = bounded_operator_bound(&formula, bracket)?;
+        let operand_end = operand_end(&formula, operand_start)?;
+        let operand = formula[operand_start..operand_end].trim();
+        let (bool_op, next_op) = match op {
+            "EF" => ("|", "EX"),
+            "AF" => ("|", "AX"),
+            "EG" => ("&", "EX"),
+            _ => ("&", "AX"),
+        };
+        let copy_length = operand.len() + bool_op.len() + next_op.len() + 8;
+        let expanded_length = formula.len() - (operand_end - start)
+            + copy_length.saturating_mul(bound.saturating_add(1));
+        if expanded_length > MAX_EXPANDED_LENGTH {
+            return Err(expansion_too_large());
+        }
+        let mut expanded = format!("({operand})");
+        for _ in 0..bound {
+            expanded = format!("(({operand}) {bool_op} {next_op} {expanded})");
+        }
+        formula.replace_range(start..operand_end, &expanded);
+    }
+    Ok(formula)
+}
+
+/// Find the operator preceding the `[` at the given position. Returns the start of the
+/// operator and its name.
+fn bounded_operator_start(formula: &str, bracket: usize) -> Result<(usize, &'static str), String> {
+    let prefix = formula[..bracket].trim_end();
+    for op in BOUNDED_OPERATORS {
+        if let Some(rest) = prefix.strip_suffix(op)
+            && !rest.ends_with(is_identifier_char)
+        {
+            return Ok((rest.len(), op));
+        }
+    }
+    Err(format!(
+        "Invalid bounded operator at position {bracket}: expected one of {}.",
+        BOUNDED_OPERATORS.join(", ")
+    ))
+}
+
+/// Parse the `[<=k]` bound starting at the given position. Returns the bound and
+/// the position after the closing bracket.
+fn bounded_operator_bound(formula: &str, bracket: usize) -> Result<(usize, usize), String> {
+    let invalid = || format!("Invalid bound at position {bracket}: expected `[<=k]`.");
+    let Some(close) = formula[bracket..].find(']').map(|it| it + bracket) else {
+        return Err(invalid());
+    };
+    let bound = formula[(bracket + 1)..close].trim();
+    let Some(bound) = bound.strip_prefix("<=") else {
+        return Err(invalid());
+    };
+    let bound = bound.trim();
+    if bound.is_empty() || !bound.chars().all(|c| c.is_ascii_digit()) {
+        return Err(invalid());
+    }
+    let bound = bound.parse::<usize>().unwrap_or(usize::MAX);
+    check_bound(bound)?;
+    Ok((bound, close + 1))
+}
+
+fn check_bound(bound: usize) -> Result<(), String> {
+    if bound > MAX_BOUND {
+        Err(format!(
+            "Bound `{bound}` is too large: at most {MAX_BOUND} steps are supported."
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+fn expansion_too_large() -> String {
+    format!(
+        "Bounded operators cannot be unrolled: the formula would exceed {MAX_EXPANDED_LENGTH} characters."
+    )
+}
+
+/// Find the end of the operand of a unary operator that starts at the given position.
+fn operand_end(formula: &str, start: usize) -> Result<usize, String> {
+    let bytes = formula.as_bytes();
+    let mut i = start;
+    loop {
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+            i += 1;
+        }
+        if i >= bytes.len() {
+            return Err(format!(
+                "Missing operand of a bounded operator at position {start}."
+            ));
+        }
+        let rest = &formula[i..];
+        if rest.starts_with('~') {
+            i += 1;
+        } else if rest.starts_with('(') {
+            return match matching_parenthesis(formula, i) {
+                Some(end) => Ok(end + 1),
+                None => Err(format!("Unmatched parenthesis at position {i}.")),
+            };
+        } else if is_hybrid_operator(rest) {
+            // Hybrid operators extend as far to the right as possible.
+            return Ok(group_end(formula, i));
+        } else if let Some(name) = rest.strip_prefix('{') {
+            return match name.find('}') {
+                Some(end) => Ok(i + end + 2),
+                None => Err(format!("Unmatched `{{` at position {i}.")),
+            };
+        } else if let Some(name) = rest.strip_prefix('%') {
+            return match name.find('%') {
+                Some(end) => Ok(i + end + 2),
+                None => Err(format!("Unmatched `%` at position {i}.")),
+            };
+        } else {
+            let length = rest
+                .find(|c: char| !is_identifier_char(c))
+                .unwrap_or(rest.len());
+            if length == 0 {
+                return Err(format!("Unexpected character at position {i}."));
+            }
+            if !UNARY_OPERATORS.contains(&&rest[..length]) {
+                return Ok(i + length);
+            }
+            i += length;
+        }
+    }
+}
+
+/// Check if the string starts with a hybrid operator (`!{x}`, `3{x}`, `V{x}`, or `@{x}`).
+fn is_hybrid_operator(value: &str) -> bool {
+    let mut chars = value.chars();
+    matches!(chars.next(), Some('!' | '3' | 'V' | '@'))
+        && chars.as_str().trim_start().starts_with('{')
+}
+
+/// Find the parenthesis that closes the one at the given position.
+fn matching_parenthesis(formula: &str, open: usize) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, c) in formula[open..].char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + i);
+                }
+            }
+            _ => (),
+        }
+    }
+    None
+}
+
+/// Find the end of the parenthesized group that contains the given position.
+fn group_end(formula: &str, start: usize) -> usize {
+    let mut depth = 0usize;
+    for (i, c) in formula[start..].char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' if depth == 0 => return start + i,
+            ')' => depth -= 1,
+            _ => (),
+        }
+    }
+    formula.len()
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// A formula of the supported LTL fragment.
+#[derive(Clone)]
+enum Ltl {
+    /// A state formula (no temporal operators).
+    State(HctlTreeNode),
+    Not(Box<Ltl>),
+    And(Box<Ltl>, Box<Ltl>),
+    Or(Box<Ltl>, Box<Ltl>),
+    Imp(Box<Ltl>, Box<Ltl>),
+    Iff(Box<Ltl>, Box<Ltl>),
+    Xor(Box<Ltl>, Box<Ltl>),
+    Next(Box<Ltl>),
+    Future(Box<Ltl>),
+    Globally(Box<Ltl>),
+    Until(Box<Ltl>, Box<Ltl>),
+    WeakUntil(Box<Ltl>, Box<Ltl>),
+}
+
+/// Parse an LTL formula and translate it into an equivalent HCTL formula which holds in
+/// a state if and only if the LTL formula holds on all paths starting in this state.
+///
+/// The syntax uses the same propositions and Boolean operators as HCTL (`~`, `&`, `|`, `^`,
+/// `=>`, `<=>`, `true`, `false`, `%prop%`), together with temporal operators `X`, `F`, `G`
+/// (unary) and `U`, `W` (binary). Binary temporal operators bind tighter than Boolean
+/// operators, and unary operators bind tighter than binary ones. A proposition can be also
+/// written in double quotes (e.g. `"X"`), which is necessary for propositions named
+/// `X`, `F`, `G`, `U`, `W`, `true`, or `false`.
+///
+/// Only a fragment of LTL is supported. After negations are pushed to propositions,
+/// the formula must only use:
+///  - `X`, `G`, and `&` with arbitrary arguments;
+///  - `|` where at least one of the arguments has no temporal operators;
+///  - `F`, `U`, and `W` on arguments without temporal operators, plus `F G p` where `p` has
+///    no temporal operators (this one is translated using a single quantified
+///    state variable).
+pub fn parse_ltl(formula: &str) -> Result<HctlTreeNode, String> {
+    let tokens = tokenize_ltl(formula)?;
+    let mut parser = LtlParser {
+        tokens: &tokens,
+        position: 0,
+    };
+    let ltl = parser.parse_iff()?;
+    if parser.position != tokens.len() {
+        return Err(format!(
+            "Unexpected token `{}` in LTL formula.",
+            tokens[parser.position]
+        ));
+    }
+    to_universal_hctl(&push_negations(&ltl, false)?)
+}
+
+fn tokenize_ltl(formula: &str) -> Result<Vec<String>, String> {
+    let mut tokens = Vec::new();
+    let mut rest = formula.trim_start();
+    while !rest.is_empty() {
+        let length = if let Some(op) = ["<=>", "=>", "(", ")", "~", "!", "&", "|", "^"]
+            .into_iter()
+            .find(|op| rest.starts_with(op))
+        {
+            op.len()
+        } else if let Some(name) = rest.strip_prefix('%') {
+            match name.find('%') {
+                Some(end) => end + 2,
+                None => return Err("Unmatched `%` in LTL formula.".to_string()),
+            }
+        } else if let Some(name) = rest.strip_prefix('"') {
+            match name.find('"') {
+                Some(end) if end > 0 && name[..end].chars().all(is_identifier_char) => end + 2,
+                Some(_) => return Err("Invalid quoted proposition in LTL formula.".to_string()),
+                None => return Err("Unmatched `\"` in LTL formula.".to_string()),
+            }
+        } else {
+            let length = rest
+                .find(|c: char| !is_identifier_char(c))
+                .unwrap_or(rest.len());
+            if length == 0 {
+                let c = rest.chars().next().unwrap();
+                return Err(format!("Unexpected character `{c}` in LTL formula."));
+            }
+            length
+        };
+        tokens.push(rest[..length].to_string());
+        rest = rest[length..].trim_start();
+    }
+    Ok(tokens)
+}
+
+/// A simple recursive descent parser for LTL formulas.
+struct LtlParser<'a> {
+    tokens: &'a [String],
+    position: usize,
+}
+
+impl LtlParser<'_> {
+    fn peek(&self) -> Option<&str> {
+        self.tokens.get(self.position).map(|it| it.as_str())
+    }
+
+    fn consume(&mut self, token: &str) -> bool {
+        if self.peek() == Some(token) {
+            self.position += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn parse_iff(&mut self) -> Result<Ltl, String> {
+        let mut result = self.parse_imp()?;
+        while self.consume("<=>") {
+            result = Ltl::Iff(Box::new(result), Box::new(self.parse_imp()?));
+        }
+        Ok(result)
+    }
+
+    fn parse_imp(&mut self) -> Result<Ltl, String> {
+        let left = self.parse_or()?;
+        if self.consume("=>") {
+            // Implication is right-associative.
+            Ok(Ltl::Imp(Box::new(left), Box::new(self.parse_imp()?)))
+        } else {
+            Ok(left)
+        }
+    }
+
+    fn parse_or(&mut self) -> Result<Ltl, String> {
+        let mut result = self.parse_xor()?;
+        while self.consume("|") {
+            result = Ltl::Or(Box::new(result), Box::new(self.parse_xor()?));
+        }
+        Ok(result)
+    }
+
+    fn parse_xor(&mut self) -> Result<Ltl, String> {
+        let mut result = self.parse_and()?;
+        while self.consume("^") {
+            result = Ltl::Xor(Box::new(result), Box::new(self.parse_and()?));
+        }
+        Ok(result)
+    }
+
+    fn parse_and(&mut self) -> Result<Ltl, String> {
+        let mut result = self.parse_until()?;
+        while self.consume("&") {
+            result = Ltl::And(Box::new(result), Box::new(self.parse_until()?));
+        }
+        Ok(result)
+    }
+
+    fn parse_until(&mut self) -> Result<Ltl, String> {
+        let left = self.parse_unary()?;
+        // Temporal binary operators are right-associative.
+        if self.consume("U") {
+            Ok(Ltl::Until(Box::new(left), Box::new(self.parse_until()?)))
+        } else if self.consume("W") {
+            Ok(Ltl::WeakUntil(
+                Box::new(left),
+                Box::new(self.parse_until()?),
+            ))
+        } else {
+            Ok(left)
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<Ltl, String> {
+        let Some(token) = self.peek() else {
+            return Err("Unexpected end of LTL formula.".to_string());
+        };
+        let token = token.to_string();
+        self.position += 1;
+        match token.as_str() {
+            "~" | "!" => Ok(Ltl::Not(Box::new(self.parse_unary()?))),
+            "X" => Ok(Ltl::Next(Box::new(self.parse_unary()?))),
+            "F" => Ok(Ltl::Future(Box::new(self.parse_unary()?))),
+            "G" => Ok(Ltl::Globally(Box::new(self.parse_unary()?))),
+            "(" => {
+                let result = self.parse_iff()?;
+                if !self.consume(")") {
+                    return Err("Expected `)` in LTL formula.".to_string());
+                }
+                Ok(result)
+            }
+            "true" => Ok(Ltl::State(HctlTreeNode::mk_constant(true))),
+            "false" => Ok(Ltl::State(HctlTreeNode::mk_constant(false))),
+            ")" | "&" | "|" | "^" | "=>" | "<=>" | "U" | "W" => {
+                Err(format!("Unexpected token `{token}` in LTL formula."))
+            }
+            _ => {
+                if let Some(name) = token.strip_prefix('%') {
+                    let name = name.strip_suffix('%').unwrap_or(name);
+                    Ok(Ltl::State(HctlTreeNode::mk_wild_card(name)))
+                } else if let Some(name) = token.strip_prefix('"') {
+                    let name = name.strip_suffix('"').unwrap_or(name);
+                    Ok(Ltl::State(HctlTreeNode::mk_proposition(name)))
+                } else {
+                    Ok(Ltl::State(HctlTreeNode::mk_proposition(token.as_str())))
+                }
+            }
+        }
+    }
+}
+
+/// Convert the formula into negation normal form (negations only appear in state formulas).
+fn push_negations(formula: &Ltl, negate: bool) -> Result<Ltl, String> {
+    let positive = |it: &Ltl| push_negations(it, false).map(Box::new);
+    let negative = |it: &Ltl| push_negations(it, true).map(Box::new);
+    let result = match (formula, negate) {
+        (Ltl::State(it), false) => Ltl::State(it.clone()),
+        (Ltl::State(it), true) => Ltl::State(HctlTreeNode::mk_unary(it.clone(), UnaryOp::Not)),
+        (Ltl::Not(it), _) => push_negations(it, !negate)?,
+        (Ltl::And(a, b), false) => Ltl::And(positive(a)?, positive(b)?),
+        (Ltl::And(a, b), true) => Ltl::Or(negative(a)?, negative(b)?),
+        (Ltl::Or(a, b), false) => Ltl::Or(positive(a)?, positive(b)?),
+        (Ltl::Or(a, b), true) => Ltl::And(negative(a)?, negative(b)?),
+        (Ltl::Imp(a, b), _) => {
+            let disjunction = Ltl::Or(Box::new(Ltl::Not(a.clone())), b.clone());
+            push_negations(&disjunction, negate)?
+        }
+        (Ltl::Iff(a, b) | Ltl::Xor(a, b), _) => {
+            let (Some(a), Some(b)) = (
+                as_state(&push_negations(a, false)?),
+                as_state(&push_negations(b, false)?),
+            ) else {
+                return Err("Operators `<=>` and `^` are only supported between formulas without temporal operators.".to_string());
+            };
+            let op = if matches!(formula, Ltl::Iff(_, _)) != negate {
+                BinaryOp::Iff
+            } else {
+                BinaryOp::Xor
+            };
+            Ltl::State(HctlTreeNode::mk_binary(a, b, op))
+        }
+        (Ltl::Next(it), _) => Ltl::Next(Box::new(push_negations(it, negate)?)),
+        (Ltl::Future(it), false) => Ltl::Future(positive(it)?),
+        (Ltl::Future(it), true) => Ltl::Globally(negative(it)?),
+        (Ltl::Globally(it), false) => Ltl::Globally(positive(it)?),
+        (Ltl::Globally(it), true) => Ltl::Future(negative(it)?),
+        (Ltl::Until(a, b), false) => Ltl::Until(positive(a)?, positive(b)?),
+        (Ltl::WeakUntil(a, b), false) => Ltl::WeakUntil(positive(a)?, positive(b)?),
+        (Ltl::Until(a, b), true) => {
+            // !(a U b) = !b W (!a & !b)
+            let neither = Ltl::And(negative(a)?, negative(b)?);
+            Ltl::WeakUntil(negative(b)?, Box::new(neither))
+        }
+        (Ltl::WeakUntil(a, b), true) => {
+            // !(a W b) = !b U (!a & !b)
+            let neither = Ltl::And(negative(a)?, negative(b)?);
+            Ltl::Until(negative(b)?, Box::new(neither))
+        }
+    };
+    Ok(result)
+}
+
+/// If the formula has no temporal operators, convert it to the equivalent HCTL formula.
+fn as_state(formula: &Ltl) -> Option<HctlTreeNode> {
+    match formula {
+        Ltl::State(it) => Some(it.clone()),
+        Ltl::And(a, b) => Some(HctlTreeNode::mk_binary(
+            as_state(a)?,
+            as_state(b)?,
+            BinaryOp::And,
+        )),
+        Ltl::Or(a, b) => Some(HctlTreeNode::mk_binary(
+            as_state(a)?,
+            as_state(b)?,
+            BinaryOp::Or,
+        )),
+        _ => None,
+    }
+}
+
+/// Translate an LTL formula in negation normal form into an HCTL formula that holds iff
+/// the LTL formula holds on all paths.
+fn to_universal_hctl(formula: &Ltl) -> Result<HctlTreeNode, String> {
+    if let Some(state) = as_state(formula) {
+        return Ok(state);
+    }
+    let unsupported = |op: &str| {
+        Err(format!(
+            "Unsupported LTL formula: {op} is only supported for arguments without temporal operators."
+        ))
+    };
+    match formula {
+        Ltl::And(a, b) => Ok(HctlTreeNode::mk_binary(
+            to_universal_hctl(a)?,
+            to_universal_hctl(b)?,
+            BinaryOp::And,
+        )),
+        Ltl::Or(a, b) => {
+            if let Some(a) = as_state(a) {
+                Ok(HctlTreeNode::mk_binary(
+                    a,
+                    to_universal_hctl(b)?,
+                    BinaryOp::Or,
+                ))
+            } else if let Some(b) = as_state(b) {
+                Ok(HctlTreeNode::mk_binary(
+                    to_universal_hctl(a)?,
+                    b,
+                    BinaryOp::Or,
+                ))
+            } else {
+                Err("Unsupported LTL formula: `|` requires at least one argument without temporal operators.".to_string())
+            }
+        }
+        Ltl::Next(it) => Ok(HctlTreeNode::mk_unary(to_universal_hctl(it)?, UnaryOp::AX)),
+        Ltl::Globally(it) => Ok(HctlTreeNode::mk_unary(to_universal_hctl(it)?, UnaryOp::AG)),
+        Ltl::Future(it) => {
+            if let Some(it) = as_state(it) {
+                return Ok(HctlTreeNode::mk_unary(it, UnaryOp::AF));
+            }
+            let Ltl::Globally(inner) = it.as_ref() else {
+                return unsupported("`F`");
+            };
+            let Some(inner) = as_state(inner) else {
+                return unsupported("`F G`");
+            };
+            // A path violates `F G p` iff it visits `~p` infinitely often, i.e. iff
+            // a state where `~p` holds is reachable and lies on a cycle:
+            // `~EF (!{x}: (~p & EX EF {x}))`.
+            let cycle = HctlTreeNode::mk_unary(
+                HctlTreeNode::mk_unary(HctlTreeNode::mk_variable("x"), UnaryOp::EF),
+                UnaryOp::EX,
+            );
+            let violation = HctlTreeNode::mk_binary(
+                HctlTreeNode::mk_unary(inner, UnaryOp::Not),
+                cycle,
+                BinaryOp::And,
+            );
+            let bind = HctlTreeNode::mk_hybrid(violation, "x", None, HybridOp::Bind);
+            let reachable = HctlTreeNode::mk_unary(bind, UnaryOp::EF);
+            Ok(HctlTreeNode::mk_unary(reachable, UnaryOp::Not))
+        }
+        Ltl::Until(a, b) | Ltl::WeakUntil(a, b) => {
+            let op = if matches!(formula, Ltl::Until(_, _)) {
+                BinaryOp::AU
+            } else {
+                BinaryOp::AW
+            };
+            match (as_state(a), as_state(b)) {
+                (Some(a), Some(b)) => Ok(HctlTreeNode::mk_binary(a, b, op)),
+                _ => unsupported("`U` and `W`"),
+            }
+        }
+        // State formulas are handled above and other operators are removed
+        // by `push_negations`.
+        _ => unreachable!(),
+    }
+}
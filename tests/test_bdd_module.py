@@ -303,6 +303,18 @@ def test_bdd():
     assert cnf[1] == BddPartialValuation(ctx, {'a': True, 'b': False, 'c': False})
     assert cnf[2] == BddPartialValuation(ctx, {'a': False, 'b': True})
     assert ctx.mk_cnf(cnf) == ctx.mk_dnf(dnf)
+    # Prime implicants are unique, sorted, and include the consensus clauses.
+    assert bdd_x.prime_implicants() == dnf[::-1]
+    primes = ctx.eval_expression("(a & b) | (!a & c)").prime_implicants()
+    assert primes == [
+        BddPartialValuation(ctx, {'a': False, 'c': True}),
+        BddPartialValuation(ctx, {'a': True, 'b': True}),
+        BddPartialValuation(ctx, {'b': True, 'c': True}),
+    ]
+    assert bdd_true.prime_implicants() == [BddPartialValuation(ctx, {})]
+    assert bdd_false.prime_implicants() == []
+    with pytest.raises(InterruptedError):
+        ctx.eval_expression("(a & b) | (!a & c)").prime_implicants(size_limit=2)
 
     # Iterators
     bdd_a = ctx.mk_literal("a", True)
@@ -465,6 +477,153 @@ def test_bdd():
         assert naive_sample in bdd_x.valuation_iterator()
 
 
+def test_bdd_structure():
+    ctx = BddVariableSet(["a", "b", "c", "d"])
+    bdd = ctx.eval_expression("(a & b) | (c & !d)")
+
+    profile = bdd.width_profile()
+    assert len(profile) == 4
+    assert sum(profile) == bdd.node_count() - 2
+    assert profile == [bdd.node_count_per_variable().get(BddVariable(i), 0) for i in range(4)]
+
+    counts = bdd.node_path_counts()
+    assert len(counts) == bdd.node_count()
+    assert counts[bdd.root().__index__()] == 1
+    assert counts[1] == bdd.clause_cardinality()
+
+    stats = bdd.statistics()
+    assert stats["variable_count"] == 4
+    assert stats["support_size"] == 4
+    assert stats["node_count"] == bdd.node_count()
+    assert stats["decision_node_count"] == bdd.node_count() - 2
+    assert stats["max_width"] == max(profile)
+    assert stats["depth"] == 4
+    assert stats["path_count"] == bdd.clause_cardinality()
+    assert stats["cardinality"] == bdd.cardinality()
+
+    stats = ctx.mk_false().statistics()
+    assert stats["node_count"] == 1
+    assert stats["decision_node_count"] == 0
+    assert stats["depth"] == 0
+    assert stats["path_count"] == 0
+
+    graphml = bdd.to_graphml()
+    assert graphml.startswith("<?xml")
+    assert graphml.count("<node ") == bdd.node_count() - 1
+    assert graphml.count("<edge ") < 2 * (bdd.node_count() - 2)
+    full = bdd.to_graphml(zero_pruned=False)
+    assert full.count("<node ") == bdd.node_count()
+    assert full.count("<edge ") == 2 * (bdd.node_count() - 2)
+    assert "<data key=\"label\">a</data>" in full
+
+    svg = bdd.to_svg()
+    assert svg.startswith("<svg")
+    assert svg.count("<circle ") == bdd.node_count() - 2
+    assert svg.count("<rect ") == 1
+    assert ctx.mk_false().to_svg().count("<rect ") == 1
+
+
+def test_bdd_weighted_count():
+    from fractions import Fraction
+
+    ctx = BddVariableSet(["a", "b", "c"])
+    a_or_b = ctx.eval_expression("a | b")
+    assert a_or_b.weighted_count({}) == 0.75
+    assert a_or_b.weighted_count({}, exact=True) == Fraction(3, 4)
+    assert a_or_b.weighted_count({"a": Fraction(1, 3)}, exact=True) == Fraction(2, 3)
+    assert a_or_b.weighted_count({"a": 0.0, "b": 1.0}) == 1.0
+    assert a_or_b.weighted_count({"a": 0.0, "b": 0.0}) == 0.0
+
+    # Uniform weights correspond to the relative cardinality.
+    bdd = ctx.eval_expression("(a & !b) | (c <=> b)")
+    assert bdd.weighted_count({}, exact=True) == Fraction(bdd.cardinality(), 2 ** 3)
+
+    assert ctx.mk_false().weighted_count({}) == 0.0
+    assert ctx.mk_true().weighted_count({"a": 0.25}) == 1.0
+
+    with pytest.raises(RuntimeError):
+        a_or_b.weighted_count({"a": 1.5})
+    with pytest.raises(RuntimeError):
+        a_or_b.weighted_count({"a": -0.5})
+
+
+def test_bdd_valuation_max_weight():
+    ctx = BddVariableSet(["a", "b", "c"])
+    bdd = ctx.eval_expression("(a & !c) | (!a & c)")
+    assert bdd.valuation_max_weight({"a": 1, "c": 2}) == BddValuation(ctx, [False, False, True])
+    assert bdd.valuation_max_weight({"a": 2, "c": 1}) == BddValuation(ctx, [True, False, False])
+    # Free variables follow the sign of their weight.
+    assert bdd.valuation_max_weight({"a": 2, "b": 0.5}) == BddValuation(ctx, [True, True, False])
+    assert bdd.valuation_max_weight({"b": -1.0, "c": 1}) == BddValuation(ctx, [False, False, True])
+
+    # Unit weights are equivalent to the most positive valuation.
+    bdd = ctx.eval_expression("(a => !b) & (b | !c)")
+    unit = {var: 1 for var in ctx.variable_ids()}
+    negative_unit = {var: -1 for var in ctx.variable_ids()}
+    assert bdd.valuation_max_weight(unit) == bdd.valuation_most_positive()
+    assert bdd.valuation_max_weight(negative_unit) == bdd.valuation_most_negative()
+    assert bdd(bdd.valuation_max_weight(unit))
+
+    with pytest.raises(RuntimeError):
+        ctx.mk_false().valuation_max_weight({})
+
+
+def test_bdd_store():
+    ctx = BddVariableSet(["a", "b", "c", "d"])
+    bdds = {
+        "x": ctx.eval_expression("(a & b) | (c & d)"),
+        "y": ctx.eval_expression("(a | b) & (c & d)"),
+        "z": ctx.eval_expression("c & d"),
+        "f": ctx.mk_false(),
+        "t": ctx.mk_true(),
+    }
+
+    store = BddStore(ctx, bdds)
+    assert len(store) == 5
+    assert store.names() == ["f", "t", "x", "y", "z"]
+    assert store.to_dict() == bdds
+    assert store["f"].is_false() and store["t"].is_true()
+    assert store.unshared_node_count() == sum(b.node_count() for b in bdds.values())
+    assert store.node_count() < store.unshared_node_count()
+    assert store.__ctx__() == ctx
+    assert "w" not in store
+    with pytest.raises(IndexError):
+        assert store["w"]
+
+    # Overwriting and removing values releases unused nodes.
+    size = store.node_count()
+    store["x"] = ctx.eval_expression("a & b & c & !d")
+    store["x"] = bdds["x"]
+    assert store.node_count() == size
+    assert store.unshared_node_count() == sum(b.node_count() for b in bdds.values())
+    del store["y"]
+    del store["z"]
+    assert store.node_count() < size
+    assert store["x"] == bdds["x"]
+    with pytest.raises(IndexError):
+        del store["y"]
+
+    data = store.to_bytes()
+    copy = BddStore.from_bytes(ctx, data)
+    assert copy.to_dict() == store.to_dict()
+    assert copy.node_count() == store.node_count()
+    assert pickle.loads(pickle.dumps(store)).to_dict() == store.to_dict()
+
+    with pytest.raises(RuntimeError):
+        BddStore.from_bytes(BddVariableSet(3), data)
+    with pytest.raises(RuntimeError):
+        store["w"] = BddVariableSet(3).mk_true()
+    # Variable sets with the same size but different variables are also incompatible.
+    other_ctx = BddVariableSet(["a", "b", "c", "e"])
+    with pytest.raises(RuntimeError):
+        BddStore.from_bytes(other_ctx, data)
+    with pytest.raises(RuntimeError):
+        store["w"] = other_ctx.mk_true()
+    assert BddStore.from_bytes(BddVariableSet(["a", "b", "c", "d"]), data).to_dict() == store.to_dict()
+    with pytest.raises(RuntimeError):
+        BddStore.from_bytes(ctx, data[:-3])
+
+
 def test_boolean_expression():
     a = BooleanExpression("a")
     b = BooleanExpression("b")
@@ -538,3 +697,100 @@ def test_boolean_expression():
     assert BooleanExpression("a ? b : c").as_binary() is None
 
     assert expr.support_set() == {"a", "b", "c"}
+
+
+def test_boolean_expression_syntax():
+    expected = BooleanExpression("(a & !b) | c")
+    assert BooleanExpression.parse("(a & !b) | c") == expected
+    assert BooleanExpression.parse("a and not b or c", "python") == expected
+    assert BooleanExpression.parse("a && !b || c", "c") == expected
+    assert BooleanExpression.parse("a & ~b | c", "sympy") == expected
+    assert BooleanExpression.parse("a & !b | c", "bnet") == expected
+
+    assert BooleanExpression.parse("not a == b", "python") == BooleanExpression("!(a <=> b)")
+    assert BooleanExpression.parse("a != True", "python") == BooleanExpression("a ^ true")
+    assert BooleanExpression.parse("a || b ? !c : 1", "c") == BooleanExpression("(a | b) ? !c : true")
+    assert BooleanExpression.parse("Implies(a, b)", "sympy") == BooleanExpression("a => b")
+    assert BooleanExpression.parse("Equivalent(a, b)", "sympy") == BooleanExpression("a <=> b")
+    assert BooleanExpression.parse("ITE(a, b, c)", "sympy") == BooleanExpression("a ? b : c")
+    assert BooleanExpression.parse("And(a, b, Not(c))", "sympy") == BooleanExpression("(a & b) & !c")
+    assert BooleanExpression.parse("a &\n  !b", "bnet") == BooleanExpression("a & !b")
+
+    for invalid, syntax in [("a == b == c", "python"), ("a and", "python"), ("a & !", "bnet"),
+                            ("Foo(a)", "sympy"), ("ITE(a, b)", "sympy"), ("a & b", "c")]:
+        with pytest.raises(RuntimeError):
+            BooleanExpression.parse(invalid, syntax)
+    with pytest.raises(RuntimeError):
+        BooleanExpression.parse("a", "java")  # type: ignore
+
+    expr = BooleanExpression("(a & b) | (b & !c)")
+    assert expr.to_string() == str(expr)
+    assert expr.to_string(minimal_parentheses=True) == "(a & b) | (b & !c)"
+    assert expr.to_string("python") == "((a and b) or (b and not c))"
+    assert expr.to_string("python", minimal_parentheses=True) == "a and b or b and not c"
+    assert expr.to_string("c", minimal_parentheses=True) == "a && b || b && !c"
+    assert expr.to_string("sympy", minimal_parentheses=True) == "a & b | b & ~c"
+    assert expr.to_string("bnet", minimal_parentheses=True) == "a & b | b & !c"
+    assert BooleanExpression("a => b").to_string("python", minimal_parentheses=True) == "not a or b"
+    assert BooleanExpression("a => b").to_string("sympy") == "Implies(a, b)"
+    assert BooleanExpression("!(a <=> b)").to_string("python", minimal_parentheses=True) == "not a == b"
+
+    # Printing and parsing preserves the semantics of the expression in every syntax.
+    ctx = BddVariableSet(["a", "b", "c", "d"])
+    expressions = [
+        "(a & b) | (b & !c)",
+        "a => (b <=> (c ^ d))",
+        "((a | b) ? !(b & c) : true) & d",
+        "(!a <=> (b <=> c)) ^ !(d => false)",
+    ]
+    for syntax in ["aeon", "python", "c", "sympy", "bnet"]:
+        for e in expressions:
+            e = BooleanExpression(e)
+            for minimal in [False, True]:
+                for width in [None, 10]:
+                    text = e.to_string(syntax, minimal_parentheses=minimal, line_width=width)
+                    if width is not None:
+                        assert all(len(line) <= width for line in text.split("\n") if " " in line)
+                    parsed = BooleanExpression.parse(text, syntax)
+                    assert ctx.eval_expression(parsed) == ctx.eval_expression(e)
+
+
+def test_boolean_expression_algebra():
+    expr = BooleanExpression("(a & b) | (c ^ a)")
+
+    bdd = expr.to_bdd()
+    assert bdd.__ctx__().variable_names() == ["a", "b", "c"]
+    assert bdd == bdd.__ctx__().eval_expression(expr)
+    ctx = BddVariableSet(["c", "b", "a", "d"])
+    assert expr.to_bdd(ctx) == ctx.eval_expression(expr)
+    with pytest.raises(RuntimeError):
+        expr.to_bdd(BddVariableSet(["a", "b"]))
+    assert BooleanExpression("true").to_bdd().is_true()
+
+    assert BooleanExpression("a => b").is_equivalent("!a | b")
+    assert BooleanExpression("a => b").is_equivalent(BooleanExpression("!b => !a"))
+    assert not BooleanExpression("a => b").is_equivalent("b => a")
+    assert BooleanExpression("a | !a").is_equivalent("b | !b")
+    assert not BooleanExpression("a").is_equivalent("a & b")
+
+    assert expr.substitute("b", "c | !a") == BooleanExpression("(a & (c | !a)) | (c ^ a)")
+    assert expr.substitute("x", "false") == expr
+    assert expr.substitute("a", "true").is_equivalent("b | !c")
+
+    assert expr.evaluate({"a": True}) == BooleanExpression("b | !c")
+    assert expr.evaluate({"a": 0}) == BooleanExpression("c")
+    assert expr.evaluate({"a": False, "c": True}).as_const()
+    assert expr.evaluate({}) == expr
+    for a in [False, True]:
+        for b in [False, True]:
+            for c in [False, True]:
+                assert expr.evaluate({"a": a, "b": b, "c": c}).as_const() == expr(a=a, b=b, c=c)
+
+    assert BooleanExpression("a & (a | b)").simplify() == BooleanExpression("a")
+    assert BooleanExpression("(a & b) | (a & !b) | !a").simplify() == BooleanExpression("true")
+    assert BooleanExpression("!!a & true").simplify() == BooleanExpression("a")
+    assert BooleanExpression("(a & false) | (b => false)").simplify() == BooleanExpression("!b")
+    assert BooleanExpression("a ? b : b").simplify() == BooleanExpression("b")
+    for e in ["(a & b) | (b & !c)", "(a => b) <=> (c ^ a)", "(a ? b : c) & !(b | c)"]:
+        e = BooleanExpression(e)
+        assert e.simplify().is_equivalent(e)
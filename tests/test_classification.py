@@ -69,6 +69,15 @@ def test_archive_input_output():
     assert l_cls == cls
     assert str(l_ann) == str(ann)
 
+    # The legacy text format (and uncompressed archives) can still be loaded.
+    for binary in [True, False]:
+        for compress in [True, False]:
+            Classification.save_classification("classification.test.zip", bn, cls, ann, binary=binary, compress=compress)
+            (l_bn, l_cls, l_ann) = Classification.load_classification("classification.test.zip")
+            assert l_bn == bn
+            assert l_cls == cls
+            assert str(l_ann) == str(ann)
+
     os.remove("classification.test.zip")
 
 
@@ -191,3 +191,454 @@ def test_model_checker():
     b = ModelChecking.verify(stg, basin, {"fix": f, "phenotype": p})
 
     assert b == stg.mk_unit_colored_vertices()
+
+
+def test_model_checker_explain():
+    # A single cycle 00 -> 10 -> 11 -> 01 -> 00 (the state is written as `ab`).
+    bn = BooleanNetwork.from_aeon("a -> b\nb -| a\n$a: !b\n$b: a")
+    graph = AsynchronousGraph(bn)
+    color = graph.mk_unit_colors()
+
+    def states(result):
+        return [(s["a"], s["b"]) for (s, _) in result["path"]]
+
+    r = ModelChecking.explain(graph, "EF (a & b)", {"a": 0, "b": 0}, color)
+    assert r is not None
+    assert r["holds"] and r["kind"] == "witness" and r["loop_start"] is None
+    assert states(r) == [(False, False), (True, False), (True, True)]
+    assert [f.is_exist_future() for (_, f) in r["path"]] == [True, True, False]
+    assert r["path"][-1][1].is_and()
+
+    # Negated existential formula that fails has a counterexample.
+    r = ModelChecking.explain(graph, HctlFormula("~EF (a & b)"), {"a": 0, "b": 0}, color)
+    assert r is not None
+    assert not r["holds"] and r["kind"] == "counterexample"
+    assert states(r) == [(False, False), (True, False), (True, True)]
+
+    # Universal formula that fails has a counterexample.
+    r = ModelChecking.explain(graph, "AX b", {"a": 0, "b": 0}, color)
+    assert r is not None
+    assert not r["holds"] and r["kind"] == "counterexample"
+    assert states(r) == [(False, False), (True, False)]
+    assert r["path"][1][1].is_not()
+
+    r = ModelChecking.explain(graph, "AG ~(a & b)", {"a": 1, "b": 0}, color)
+    assert r is not None
+    assert states(r) == [(True, False), (True, True)]
+
+    # Globally properties are explained using a lasso.
+    r = ModelChecking.explain(graph, "EG true", {"a": 0, "b": 0}, color)
+    assert r is not None
+    assert r["holds"] and r["loop_start"] == 0
+    assert states(r) == [(False, False), (True, False), (True, True), (False, True)]
+
+    r = ModelChecking.explain(graph, "a AU b", {"a": 1, "b": 0}, color)
+    assert r is None
+    r = ModelChecking.explain(graph, "b AU a", {"a": 0, "b": 1}, color)
+    assert r is not None
+    assert not r["holds"] and r["kind"] == "counterexample"
+    assert states(r) == [(False, True), (False, False)]
+
+    # Holding universal properties and failing existential properties cannot be explained.
+    assert ModelChecking.explain(graph, "AF (a & b)", {"a": 0, "b": 0}, color) is None
+    assert ModelChecking.explain(graph, "EX (a & b)", {"a": 0, "b": 0}, color) is None
+
+    # Colors are transferred into the model checking encoding.
+    mc_graph = AsynchronousGraph.mk_for_model_checking(bn, 1)
+    r = ModelChecking.explain(mc_graph, "EF (a & b)", {"a": 0, "b": 0}, color)
+    assert r is not None and len(r["path"]) == 3
+
+    with pytest.raises(RuntimeError):
+        ModelChecking.explain(graph, "EF a", {"a": 0}, color)
+    with pytest.raises(RuntimeError):
+        ModelChecking.explain(graph, "a & EF b", {"a": 0, "b": 0}, color)
+
+
+def test_model_checker_fairness():
+    # Variable `b` oscillates, while `a` can be updated (once) if it is `0`.
+    bn = BooleanNetwork.from_aeon("b -| b\n$a: true\n$b: !b")
+    graph = AsynchronousGraph(bn)
+    unit = graph.mk_unit_colored_vertices()
+    a_true = graph.mk_subspace({"a": 1})
+    a_false = graph.mk_subspace({"a": 0})
+
+    # Without fairness, `b` can oscillate forever while `a` is never updated.
+    assert ModelChecking.verify(graph, "AF a") == a_true
+    assert ModelChecking.verify(graph, "AF a", fairness={"weak": ["a"]}) == unit
+    assert ModelChecking.verify(graph, "AF a", fairness={"strong": ["a"]}) == unit
+    assert ModelChecking.verify(graph, "a AU b", fairness={"weak": ["a"]}) == ModelChecking.verify(graph, "a AU b")
+    assert ModelChecking.verify(graph, "EG ~a", fairness={"weak": ["a"]}).is_empty()
+
+    # Without any requirements, the results are the same as without fairness.
+    for formula in ["AF a", "EG ~a", "AG (a | b)", "a EU b", "AX b", "~b AW a"]:
+        assert ModelChecking.verify(graph, formula, fairness={}) == ModelChecking.verify(graph, formula)
+
+    # Only paths that stay in `a=0` forever are fair, hence all `A` operators hold trivially for `a=1`.
+    config = {"infinitely_often": [a_false]}
+    assert ModelChecking.verify(graph, "EG true", fairness=config) == a_false
+    assert ModelChecking.verify(graph, "AG false", fairness=config) == a_true
+    assert ModelChecking.verify(graph, ["EX true", "AF a"], fairness=config) == [a_false, a_true]
+
+    # No path is fair.
+    config = {"weak": ["a"], "infinitely_often": [a_false]}
+    assert ModelChecking.verify(graph, "EF true", fairness=config).is_empty()
+
+    # Extended propositions are supported.
+    result = ModelChecking.verify(graph, "EF %p%", {"p": a_true}, fairness={"weak": ["a"]})
+    assert result == unit
+
+    # The self-loop of a sink state is fair for `weak`/`strong` requirements, but not
+    # for an `infinitely_often` set which does not contain the sink.
+    sink_bn = BooleanNetwork.from_aeon("$a: true")
+    sink_graph = AsynchronousGraph(sink_bn)
+    sink = sink_graph.mk_subspace({"a": 1})
+    sink_unit = sink_graph.mk_unit_colored_vertices()
+    assert ModelChecking.verify(sink_graph, "EG a", fairness={"weak": ["a"]}) == sink
+    assert ModelChecking.verify(sink_graph, "EG a", fairness={"strong": ["a"]}) == sink
+    assert ModelChecking.verify(sink_graph, "EG a", fairness={"infinitely_often": [sink]}) == sink
+    config = {"infinitely_often": [sink_graph.mk_subspace({"a": 0})]}
+    assert ModelChecking.verify(sink_graph, "EF true", fairness=config).is_empty()
+    assert ModelChecking.verify(sink_graph, "AG false", fairness=config) == sink_unit
+
+    with pytest.raises(RuntimeError):
+        ModelChecking.verify(graph, "!{x}: AX {x}", fairness={"weak": ["a"]})
+    with pytest.raises(IndexError):
+        ModelChecking.verify(graph, "AF a", fairness={"weak": ["c"]})
+
+
+def test_bounded_and_ltl_formulas():
+    a = HctlFormula("a")
+    b = HctlFormula("b")
+
+    assert HctlFormula("EF[<=0] a") == a
+    assert HctlFormula("EF[<=2] a") == HctlFormula("a | EX (a | EX a)")
+    assert HctlFormula("AG [<= 1] a & b") == HctlFormula("(a & AX a) & b")
+    assert HctlFormula("EG[<=1] a") == HctlFormula.mk_temporal("exist_global", a, bound=1)
+    assert HctlFormula("AF[<=1] a") == HctlFormula.mk_temporal("all_future", a, bound=1)
+    assert HctlFormula("EF[<=1] AG[<=1] a") == HctlFormula("(a & AX a) | EX (a & AX a)")
+
+    with pytest.raises(RuntimeError):
+        HctlFormula("EX[<=1] a")
+    with pytest.raises(RuntimeError):
+        HctlFormula("EF[<1] a")
+    with pytest.raises(TypeError):
+        HctlFormula.mk_temporal("exist_next", a, bound=1)
+    with pytest.raises(TypeError):
+        HctlFormula.mk_temporal("exist_until", a, b, bound=1)
+
+    # Invalid bounds, and bounds whose unrolling would be too large.
+    for invalid in ["EF[<=] a", "EF[<=-1] a", "EF[<=+1] a", "EF[<=1.5] a", "EF[<= 1 2] a"]:
+        with pytest.raises(RuntimeError):
+            HctlFormula(invalid)
+    assert HctlFormula("EF[<=100] a") == HctlFormula.mk_temporal("exist_future", a, bound=100)
+    with pytest.raises(RuntimeError):
+        HctlFormula("EF[<=1001] a")
+    with pytest.raises(RuntimeError):
+        HctlFormula("EF[<=99999999999999999999999] a")
+    with pytest.raises(RuntimeError):
+        HctlFormula("EF[<=1000] EF[<=1000] EF[<=1000] a")
+    with pytest.raises(TypeError):
+        HctlFormula.mk_temporal("exist_future", a, bound=1001)
+
+    assert HctlFormula.from_ltl("G F a") == HctlFormula("AG AF a")
+    assert HctlFormula.from_ltl("G (a => X b)") == HctlFormula("AG (~a | AX b)")
+    assert HctlFormula.from_ltl("~F a") == HctlFormula("AG ~a")
+    assert HctlFormula.from_ltl("a U b & G a") == HctlFormula("(a AU b) & AG a")
+    assert HctlFormula.from_ltl("F G a").used_state_variables() == {"x"}
+
+    with pytest.raises(RuntimeError):
+        HctlFormula.from_ltl("F X a")
+    with pytest.raises(RuntimeError):
+        HctlFormula.from_ltl("G a | G b")
+    with pytest.raises(RuntimeError):
+        HctlFormula.from_ltl("a U")
+
+    # Propositions that clash with LTL keywords must be quoted.
+    assert HctlFormula.from_ltl('G "F"') == HctlFormula("AG F")
+    assert HctlFormula.from_ltl('"X" U b') == HctlFormula("X AU b")
+    with pytest.raises(RuntimeError):
+        HctlFormula.from_ltl("G F")
+    with pytest.raises(RuntimeError):
+        HctlFormula.from_ltl('G "a')
+
+    # A single cycle 00 -> 10 -> 11 -> 01 -> 00 (the state is written as `ab`).
+    bn = BooleanNetwork.from_aeon("a -> b\nb -| a\n$a: !b\n$b: a")
+    graph = AsynchronousGraph.mk_for_model_checking(bn, 1)
+
+    assert ModelChecking.verify(graph, "EF (a & b)").cardinality() == 4
+    assert ModelChecking.verify(graph, "EF[<=2] (a & b)").cardinality() == 3
+    assert ModelChecking.verify(graph, "AG[<=1] a") == graph.mk_subspace({"a": 1, "b": 0})
+
+    assert ModelChecking.verify(graph, HctlFormula.from_ltl("G F a")) == graph.mk_unit_colored_vertices()
+    assert ModelChecking.verify(graph, HctlFormula.from_ltl("F G a")).is_empty()
+
+    # Variable `b` oscillates, while `a` can be updated (once) if it is `0`.
+    bn = BooleanNetwork.from_aeon("b -| b\n$a: true\n$b: !b")
+    graph = AsynchronousGraph.mk_for_model_checking(bn, HctlFormula.from_ltl("F G a"))
+    result = ModelChecking.verify(graph, HctlFormula.from_ltl("F G a"))
+    assert result == graph.mk_subspace({"a": 1})
+
+
+def test_model_checking_session():
+    bn = BooleanNetwork.from_aeon("a -> b\nb -| a\n$a: !b\n$b: a")
+    graph = AsynchronousGraph.mk_for_model_checking(bn, 2)
+    session = ModelCheckingSession(graph)
+    assert session.statistics() == {"cached_formulas": 0, "hits": 0, "misses": 0}
+
+    formulas = ["AG EF (a & b)", "!{x}: AX EF {x}", "3{x}: @{x}: ~a & EX {x}"]
+    for formula in formulas:
+        assert session.verify(formula) == ModelChecking.verify(graph, formula)
+    assert session.statistics()["hits"] == 0
+
+    # Equivalent formulas (modulo variable names) are resolved using the cache.
+    misses = session.statistics()["misses"]
+    assert session.verify("!{y}: AX EF {y}") == ModelChecking.verify(graph, "!{x}: AX EF {x}")
+    assert session.statistics()["misses"] == misses
+    assert session.statistics()["hits"] == 1
+
+    # Cached sub-formulas are reused in new formulas.
+    result = session.verify(["EF (a & b) | AG EF (a & b)", "EX a"])
+    expected = ModelChecking.verify(graph, ["EF (a & b) | AG EF (a & b)", "EX a"])
+    assert result == expected
+    assert session.statistics()["hits"] >= 3
+
+    # Boolean combinations of cached sub-formulas are not cached, and do not invoke
+    # the model checker again.
+    stats = session.statistics()
+    formula = "(EF (a & b) ^ ~AG EF (a & b)) <=> (EX a => AG EF (a & b))"
+    assert session.verify(formula) == ModelChecking.verify(graph, formula)
+    assert session.statistics()["cached_formulas"] == stats["cached_formulas"]
+    assert session.statistics()["misses"] == stats["misses"]
+
+    # Extended propositions are never cached.
+    cached = session.statistics()["cached_formulas"]
+    sub = {"s": graph.mk_subspace({"a": 1})}
+    assert session.verify("EF %s%", sub) == ModelChecking.verify(graph, "EF %s%", sub)
+    assert session.statistics()["cached_formulas"] == cached
+    assert session.verify("AG EF %s% & EF (a & b)", sub) == ModelChecking.verify(graph, "AG EF %s% & EF (a & b)", sub)
+
+    session.clear()
+    assert session.statistics() == {"cached_formulas": 0, "hits": 0, "misses": 0}
+    assert str(session) == "ModelCheckingSession(cached = 0, hits = 0, misses = 0)"
+
+
+def test_builtin_propositions():
+    # A toggle switch with two fixed points (`10` and `01`).
+    bn = BooleanNetwork.from_aeon("a -| b\nb -| a\n$a: !b\n$b: !a")
+    graph = AsynchronousGraph.mk_for_model_checking(bn, 1)
+    fixed_points = graph.mk_subspace({"a": 1, "b": 0}).union(graph.mk_subspace({"a": 0, "b": 1}))
+
+    assert ModelChecking.verify(graph, "%fixed_point%") == fixed_points
+    assert ModelChecking.verify(graph, "%attractor%") == fixed_points
+    assert ModelChecking.verify(graph, "%trap_space:minimal%") == fixed_points
+    assert ModelChecking.verify(graph, "AF %attractor%") == graph.mk_unit_colored_vertices()
+    assert ModelChecking.verify(graph, "%basin:0%").cardinality() == 3
+    assert ModelChecking.verify(graph, "%basin:0% & %basin:1%") == graph.mk_subspace({"a": 0, "b": 0}).union(graph.mk_subspace({"a": 1, "b": 1}))
+
+    # Parametrized built-in propositions cannot clash with user propositions.
+    assert HctlFormula("EF %basin:1%") != HctlFormula("EF %basin__1%")
+    assert "%basin:1%" in str(HctlFormula("EF %basin:1%"))
+    assert HctlFormula("EF %basin:1%").used_extended_properties() == {"basin:1"}
+    assert ModelChecking.verify(graph, "%basin__1%", {"basin__1": fixed_points}) == fixed_points
+    with pytest.raises(RuntimeError):
+        ModelChecking.verify(graph, "%basin__1%")
+    sub = {"__basin__0": graph.mk_empty_colored_vertices(), "__x": fixed_points}
+    assert ModelChecking.verify(graph, "%__basin__0%", sub).is_empty()
+    assert ModelChecking.verify(graph, "%__x% & %basin:0%", sub).cardinality() == 1
+    session = ModelCheckingSession(graph)
+    assert session.verify("EF %fixed_point%") == graph.mk_unit_colored_vertices()
+
+    # User-provided values take precedence.
+    empty = graph.mk_empty_colored_vertices()
+    assert ModelChecking.verify(graph, "%attractor%", {"attractor": empty}).is_empty()
+
+    with pytest.raises(RuntimeError):
+        ModelChecking.verify(graph, "%basin:2%")
+    with pytest.raises(RuntimeError):
+        ModelChecking.verify(graph, "%trap_space:foo%")
+
+    # Built-in propositions follow the dynamics of a restricted graph. Here, the cycle
+    # `00 -> 10 -> 11 -> 01 -> 00` is restricted to `a = 1`, hence `11` becomes a sink.
+    bn = BooleanNetwork.from_aeon("a -> b\nb -| a\n$a: !b\n$b: a")
+    for graph in [AsynchronousGraph(bn), AsynchronousGraph.mk_for_model_checking(bn, 1)]:
+        assert ModelChecking.verify(graph, "%fixed_point%").is_empty()
+        assert ModelChecking.verify(graph, "%attractor%") == graph.mk_unit_colored_vertices()
+        restricted = graph.restrict(graph.mk_subspace({"a": 1}))
+        sink = graph.mk_subspace({"a": 1, "b": 1})
+        assert ModelChecking.verify(restricted, "%fixed_point%") == sink
+        assert ModelChecking.verify(restricted, "%attractor%") == sink
+        assert ModelChecking.verify(restricted, "%basin:0%") == graph.mk_subspace({"a": 1})
+
+
+def test_formula_validation():
+    bn = BooleanNetwork.from_aeon("a -| b\nb -| a\n$a: !b\n$b: !a")
+    graph = AsynchronousGraph.mk_for_model_checking(bn, 1)
+
+    assert HctlFormula("!{x}: AX {x}").validate(graph) == []
+    assert HctlFormula("EF %attractor% & AG %basin:0%").validate(graph) == []
+    assert HctlFormula("EF %p%").validate(graph, {"p": graph.mk_unit_colored_vertices()}) == []
+
+    diagnostics = HctlFormula("!{x}: (c & %p% & @{y}: EX {x})").validate(graph)
+    assert [d["kind"] for d in diagnostics] == [
+        "unknown_variable",
+        "unresolved_proposition",
+        "unbound_state_variable",
+        "missing_extra_variables",
+    ]
+    assert diagnostics[0]["formula"] == HctlFormula("c")
+    assert [d["severity"] for d in diagnostics] == ["error", "error", "error", "warning"]
+
+    diagnostics = HctlFormula("!{x}: EX !{x}: AX {x}").validate(graph.symbolic_context())
+    assert [d["kind"] for d in diagnostics] == ["shadowed_state_variable"]
+    assert diagnostics[0]["severity"] == "warning"
+
+    # Missing extra variables are only a warning, since `verify` adds them automatically.
+    formula = HctlFormula("!{x}: EX !{y}: AX ({x} & {y})")
+    diagnostics = formula.validate(graph)
+    assert [d["kind"] for d in diagnostics] == ["missing_extra_variables"]
+    assert diagnostics[0]["severity"] == "warning"
+    assert ModelChecking.verify(graph, formula).is_subset(graph.mk_unit_colored_vertices())
+
+    with pytest.raises(TypeError):
+        HctlFormula("a").validate(bn)
+
+
+def test_model_checking_graph_preparation():
+    bn = BooleanNetwork.from_aeon("a -| b\nb -| a\n$a: !b\n$b: !a")
+    graph = AsynchronousGraph(bn)
+    mc_graph = AsynchronousGraph.mk_for_model_checking(bn, 2)
+    formulas = ["!{x}: AX {x}", "3{x}: 3{y}: (@{x}: ~{y} & AX {x}) & (@{y}: AX {y})", "EF a"]
+
+    expected = [graph.transfer_from(it, mc_graph) for it in ModelChecking.verify(mc_graph, formulas)]
+    assert ModelChecking.verify(graph, formulas) == expected
+    assert ModelChecking.verify(bn, formulas) == expected
+    assert ModelChecking.verify(graph, "!{x}: AX {x}").is_subset(graph.mk_unit_colored_vertices())
+
+    # Substitution and fairness sets use the encoding of the given graph.
+    fixed = graph.mk_subspace({"a": 1, "b": 0})
+    assert ModelChecking.verify(graph, "!{x}: %s% & AX {x}", {"s": fixed}) == fixed
+
+    # Restricted graphs keep their restriction (`11` can only move to `10`).
+    restricted = graph.restrict(graph.mk_subspace({"a": 1}))
+    assert ModelChecking.verify(restricted, "!{x}: AX {x}") == fixed
+    assert ModelChecking.verify(restricted, "!{x}: AX ~{x}") == graph.mk_subspace({"a": 1, "b": 1})
+
+    with pytest.raises(TypeError):
+        ModelChecking.verify(graph.symbolic_context(), "EF a")
+
+
+def test_smv_export():
+    bn = BooleanNetwork.from_aeon("a -> b\nb -| a\n$a: !b\n$b: a")
+    model = bn.to_smv()
+    assert "MODULE main" in model
+    assert "    a : boolean;" in model
+    assert "    choice : 0..1;" in model
+    assert "    a__update := !b;" in model
+    assert "    next(b) := case choice = 1 : b__update; TRUE : b; esac;" in model
+    assert "FROZENVAR" not in model
+
+    model = bn.to_smv("synchronous")
+    assert "IVAR" not in model
+    assert "    next(a) := a__update;" in model
+
+    bn = BooleanNetwork.from_aeon("a -> b\nb -| a\n$a: f(b)")
+    model = bn.to_smv()
+    for name in ["f__0", "f__1", "b__implicit__0", "b__implicit__1"]:
+        assert f"    {name} : boolean;" in model
+    assert "INIT" in model
+
+    with pytest.raises(RuntimeError):
+        bn.to_smv("semi-synchronous")
+    with pytest.raises(RuntimeError):
+        BooleanNetwork(["A", "b"]).to_smv()
+
+    assert HctlFormula("AG (a => EF ~b)").to_smv_ctl() == "AG (a -> EF !b)"
+    assert HctlFormula("(a ^ b) AU (a <=> b)").to_smv_ctl() == "A [ (a xor b) U (a <-> b) ]"
+    assert HctlFormula("a EW b").to_smv_ctl() == "(E [ a U b ] | EG a)"
+    assert HctlFormula("a AW b").to_smv_ctl() == "!E [ !b U (!a & !b) ]"
+    with pytest.raises(RuntimeError):
+        HctlFormula("!{x}: AX {x}").to_smv_ctl()
+    with pytest.raises(RuntimeError):
+        HctlFormula("EF %p%").to_smv_ctl()
+
+
+def test_property_templates():
+    toggle = BooleanNetwork.from_aeon("a -| b\nb -| a\n$a: !b\n$b: !a")
+    cycle = BooleanNetwork.from_aeon("a -> b\nb -| a\n$a: !b\n$b: a")
+    toggle_all = AsynchronousGraph(toggle).mk_unit_colored_vertices()
+    cycle_all = AsynchronousGraph(cycle).mk_unit_colored_vertices()
+
+    assert PropertyTemplates.oscillates("a") == HctlFormula("3{x}: @{x}: ((AG EF {x}) & (EF a) & (EF ~a))")
+    assert PropertyTemplates.multistable(2) == HctlFormula(
+        "3{x}: 3{y}: ((@{x}: ((AG EF {x}) & ~(EF {y}))) & (@{y}: (AG EF {y})))"
+    )
+    assert PropertyTemplates.bistable("a & ~b", "~a & b") == PropertyTemplates.bistable(
+        {"a": True, "b": False}, {"b": 1, "a": 0}
+    )
+    # Template variables never capture the state variables of a phenotype.
+    assert PropertyTemplates.reaches_stable_state("!{x}: AX {x}").used_state_variables() == {"x", "y"}
+
+    bistable = PropertyTemplates.bistable({"a": True, "b": False}, {"a": False, "b": True})
+    assert ModelChecking.verify(toggle, bistable) == toggle_all
+    assert ModelChecking.verify(cycle, bistable).is_empty()
+
+    assert ModelChecking.verify(toggle, PropertyTemplates.reaches_stable_state()) == toggle_all
+    assert ModelChecking.verify(toggle, PropertyTemplates.reaches_stable_state("a & b")).is_empty()
+    assert ModelChecking.verify(cycle, PropertyTemplates.reaches_stable_state()).is_empty()
+
+    assert ModelChecking.verify(cycle, PropertyTemplates.oscillates("a")) == cycle_all
+    assert ModelChecking.verify(toggle, PropertyTemplates.oscillates("a")).is_empty()
+
+    irreversible = PropertyTemplates.irreversible("~a & ~b", "a & ~b")
+    assert ModelChecking.verify(toggle, irreversible) == toggle_all
+    assert ModelChecking.verify(cycle, irreversible).is_empty()
+
+    assert ModelChecking.verify(toggle, PropertyTemplates.multistable(2)) == toggle_all
+    assert ModelChecking.verify(toggle, PropertyTemplates.multistable(3)).is_empty()
+    assert ModelChecking.verify(cycle, PropertyTemplates.multistable(1)) == cycle_all
+
+    with pytest.raises(RuntimeError):
+        PropertyTemplates.multistable(0)
+    with pytest.raises(RuntimeError):
+        PropertyTemplates.oscillates("a & b")
+    with pytest.raises(TypeError):
+        PropertyTemplates.bistable(1, "a")
+    with pytest.raises(RuntimeError):
+        PropertyTemplates.reaches_stable_state("%attractor% & a")
+    with pytest.raises(RuntimeError):
+        PropertyTemplates.reaches_stable_state({"a b": True})
+
+
+def test_parameter_synthesis():
+    bn = BooleanNetwork.from_aeon("a ->? b\nb -| a\n$a: !b")
+    graph = AsynchronousGraph(bn)
+
+    def status(result):
+        return [it["status"] for it in result["functions"]["b"]]
+
+    # Only `b = a` admits the cycle through `a & b`.
+    result = ModelChecking.synthesize(bn, "AG EF (a & b)")
+    assert result["colors"].cardinality() == 1
+    assert status(result) == ["forbidden", "fixed"]
+    assert "b(1) = 1 (fixed)" in result["summary"]
+
+    # State `11` exists in every color, but is not the only state.
+    existential = ModelChecking.synthesize(graph, "a & b", mode="existential")
+    assert existential["colors"] == graph.mk_unit_colors()
+    assert status(existential) == ["free", "free"]
+    universal = ModelChecking.synthesize(graph, ["a & b"])
+    assert universal["colors"].is_empty()
+    assert universal["functions"] == {}
+
+    # From `11`, the state `00` is unreachable only when `b` is constant `true`.
+    initial = graph.mk_subspace({"a": 1, "b": 1})
+    result = ModelChecking.synthesize(graph, "EF (~a & ~b)", initial_states=initial)
+    assert result["colors"].cardinality() == 2
+    assert status(result) == ["forbidden", "free"]
+
+    # Properties that need quantified variables also work with the plain graph.
+    result = ModelChecking.synthesize(graph, PropertyTemplates.reaches_stable_state())
+    assert result["colors"].cardinality() == 2
+
+    with pytest.raises(RuntimeError):
+        ModelChecking.synthesize(graph, "a", mode="sometimes")
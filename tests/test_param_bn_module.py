@@ -30,6 +30,57 @@ def test_drop_2():
             assert dropped.variable_count() == len(scc)
 
 
+def test_compose():
+    left = BooleanNetwork(regulations=["a -> b", "b -| a"], functions={"a": "!b", "b": "a"})
+    right = BooleanNetwork(regulations=["c -> b", "b -> c"], functions={"b": "c", "c": "b"})
+
+    composed = left.compose(right)
+    assert composed.variable_names() == ["a", "b", "c"]
+    assert str(composed.get_update_function("b")) == "a & c"
+    assert str(composed.get_update_function("c")) == "b"
+    assert composed.find_regulation("a", "b")['sign'] == '+'
+    assert not composed.find_regulation("a", "b")['essential']
+    assert composed.find_regulation("b", "c")['essential']
+
+    assert str(left.compose(right, conflict="or").get_update_function("b")) == "a | c"
+
+    composed = left.compose(right, conflict="left")
+    assert str(composed.get_update_function("b")) == "a"
+    assert composed.find_regulation("c", "b") is None
+    assert composed.find_regulation("a", "b")['essential']
+
+    # A regulation that is shared by both networks keeps its sign with `left`.
+    negative = BooleanNetwork(regulations=["a -| b"], functions={"b": "!a"})
+    composed = left.compose(negative, conflict="left")
+    assert str(composed.get_update_function("b")) == "a"
+    assert composed.find_regulation("a", "b")['sign'] == '+'
+    assert composed.find_regulation("a", "b")['essential']
+    assert left.compose(negative).find_regulation("a", "b")['sign'] is None
+
+    composed = left.compose(right, conflict="parameter")
+    assert composed.explicit_parameter_names() == ["f_b"]
+    assert str(composed.get_update_function("b")) == "f_b(a, c)"
+
+    composed = left.compose(right, repair_graph=True)
+    assert composed.find_regulation("a", "b")['essential']
+
+    module = BooleanNetwork(regulations=["x -> y"], functions={"y": "x"})
+    composed = left.compose(module, mapping={"x": "a"})
+    assert composed.variable_names() == ["a", "b", "y"]
+    assert str(composed.get_update_function("y")) == "a"
+    assert str(composed.get_update_function("a")) == "!b"
+
+    with pytest.raises(RuntimeError):
+        left.compose(module, mapping={"x": "a", "y": "a"})
+    with pytest.raises(TypeError):
+        left.compose(right, conflict="xor")
+
+    p_left = BooleanNetwork(["a"], [], [("p", 0)], ["p"])
+    p_right = BooleanNetwork(["b"], [], [("p", 1)], [None])
+    with pytest.raises(RuntimeError):
+        p_left.compose(p_right)
+
+
 def test_variable_id():
     a = VariableId(0)
     b = VariableId(1)
@@ -555,6 +606,28 @@ def test_update_function():
     assert UpdateFunction(bn1, "a ^ b").to_and_or_normal_form() == UpdateFunction(bn1, "(a | b) & !(a & b)")
     assert UpdateFunction(bn1, "a <=> b").to_and_or_normal_form() == UpdateFunction(bn1, "(a & b) | (!a & !b)")
 
+    bn3 = BooleanNetwork(["a", "b", "c"], ["a -? c", "b -> c", "c -> c"], [("f", 1)], [None, None, "(a & b) | (!a & c)"])
+    ctx = SymbolicContext(bn3)
+    bdd_vars = ctx.bdd_variable_set()
+    [(colors, primes)] = bn3.get_update_function("c").prime_implicants(bn3)
+    assert colors.is_true()
+    assert primes == [
+        BddPartialValuation(bdd_vars, {'a': False, 'c': True}),
+        BddPartialValuation(bdd_vars, {'a': True, 'b': True}),
+        BddPartialValuation(bdd_vars, {'b': True, 'c': True}),
+    ]
+    # A parametrized function has different prime implicants for each interpretation of `f`.
+    groups = UpdateFunction(bn3, "a | f(b)").prime_implicants(bn3)
+    assert len(groups) == 4
+    assert sum(colors.cardinality() for colors, _ in groups) == 2 ** bdd_vars.variable_count()
+    assert [BddPartialValuation(bdd_vars, {})] in [primes for _, primes in groups]
+    assert [BddPartialValuation(bdd_vars, {'a': True})] in [primes for _, primes in groups]
+    # The network must contain all variables and parameters of the function.
+    with pytest.raises(RuntimeError):
+        UpdateFunction(bn3, "a | f(b)").prime_implicants(BooleanNetwork(["a", "b", "c"], parameters=[("f", 2)]))
+    with pytest.raises(RuntimeError):
+        UpdateFunction(bn3, "a | f(b)").prime_implicants(BooleanNetwork(["a", "b"]))
+
 
 def test_model_annotation():
     ann = ModelAnnotation()
@@ -1553,3 +1626,351 @@ def test_perturbation_set_pickle():
     
     colored_singleton = colored_unpickled.pick_singleton()
     assert colored_singleton.cardinality() == 1
+
+
+def test_network_reduction():
+    bn = BooleanNetwork.from_aeon("""
+    c -> a
+    e -> a
+    a -> b
+    b -> c
+    c -> d
+    $a: c & e
+    $b: a
+    $c: b
+    $d: c
+    $e: true
+    """)
+    # By default, only attractor-preserving reductions are used.
+    reduced, reduction = bn.reduce()
+    assert reduced.variable_names() == ["a", "b", "c"]
+    assert reduction.eliminated_constants() == {"e": True}
+    assert set(reduction.eliminated_variables()) == {"d", "e"}
+
+    graph = AsynchronousGraph(bn)
+    reduced_fixed_points = FixedPoints.symbolic_vertices(AsynchronousGraph(reduced))
+    assert reduced_fixed_points.cardinality() == 2
+    assert reduction.lift(graph, reduced_fixed_points).cardinality() == 4
+
+    reduced, reduction = bn.reduce(["constants", "outputs", "inline"])
+    assert reduced.variable_names() == ["c"]
+    assert reduction.reduced_network() == reduced
+    assert reduction.original_network() == bn
+    assert reduction.eliminated_constants() == {"e": True}
+    assert set(reduction.eliminated_variables()) == {"a", "b", "d", "e"}
+
+    reduced_graph = AsynchronousGraph(reduced)
+    reduced_fixed_points = FixedPoints.symbolic_vertices(reduced_graph)
+    assert reduced_fixed_points.cardinality() == 2
+
+    lifted = reduction.lift(graph, reduced_fixed_points, fixed_points=True)
+    assert lifted == FixedPoints.symbolic_vertices(graph)
+
+    # Without the fixed-point requirement, only the constants are fixed.
+    lifted = reduction.lift(graph, reduced_fixed_points)
+    assert lifted.cardinality() == 16
+
+    # Only outputs are removed.
+    reduced, reduction = bn.reduce(["outputs"])
+    assert reduced.variable_names() == ["a", "b", "c", "e"]
+    assert reduction.eliminated_constants() == {}
+
+    with pytest.raises(TypeError):
+        bn.reduce(["unknown"])
+
+
+def test_expanded_network():
+    bn = BooleanNetwork.from_aeon("""
+    b -> a
+    a -> b
+    c -| b
+    b -| c
+    $a: b
+    $b: a & !c
+    $c: !b
+    """)
+    expanded, provenance = bn.to_expanded_network()
+    assert expanded.variable_names() == ["a", "b", "c", "not_a", "not_b", "not_c", "and_a_not_c"]
+    assert provenance["a"] == {"a": True}
+    assert provenance["not_b"] == {"b": False}
+    assert provenance["and_a_not_c"] == {"a": True, "c": False}
+
+    assert str(expanded.get_update_function("a")) == "b"
+    assert str(expanded.get_update_function("not_a")) == "not_b"
+    assert str(expanded.get_update_function("b")) == "and_a_not_c"
+    assert str(expanded.get_update_function("and_a_not_c")) == "a & not_c"
+    assert expanded.predecessors("not_b") == {expanded.find_variable("not_a"), expanded.find_variable("c")}
+    assert expanded.check_regulation_constraints() == []
+
+    expanded, provenance, dnf = bn.to_expanded_network(include_dnf=True)
+    assert dnf["b"] == [{"a": True, "c": False}]
+    assert sorted(dnf["not_b"], key=lambda it: sorted(it.items())) == [{"a": False}, {"c": True}]
+
+    # All prime implicants are used, including ones that are redundant in a minimal DNF.
+    bn = BooleanNetwork.from_aeon("""
+    a -? c
+    b -> c
+    c -> c
+    $a: true
+    $b: true
+    $c: (a & b) | (!a & c)
+    """)
+    expanded, _, dnf = bn.to_expanded_network(include_dnf=True)
+    assert sorted(dnf["c"], key=lambda it: sorted(it.items())) == [
+        {"a": False, "c": True},
+        {"a": True, "b": True},
+        {"b": True, "c": True},
+    ]
+    assert "and_b_c" in expanded.variable_names()
+
+    # Conjunctions with the same generated name (`and_x_y_z`) are still different nodes.
+    bn = BooleanNetwork.from_aeon("""
+    x -> t
+    y_z -> t
+    x_y -> t
+    z -> t
+    $t: (x & y_z) | (x_y & z)
+    $x: true
+    $y_z: true
+    $x_y: true
+    $z: true
+    """)
+    expanded, provenance = bn.to_expanded_network()
+    assert {"and_x_y_z", "and_x_y_z_2"} <= set(expanded.variable_names())
+    assert sorted([provenance["and_x_y_z"], provenance["and_x_y_z_2"]], key=lambda it: sorted(it)) == [
+        {"x": True, "y_z": True},
+        {"x_y": True, "z": True},
+    ]
+    assert len(expanded.predecessors("t")) == 2
+
+    # Constant functions stay constant.
+    bn = BooleanNetwork.from_aeon("$a: true")
+    expanded, _ = bn.to_expanded_network()
+    assert str(expanded.get_update_function("a")) == "true"
+    assert str(expanded.get_update_function("not_a")) == "false"
+
+    # Parametrized networks are not supported.
+    bn = BooleanNetwork.from_aeon("a -> b\n$b: a")
+    with pytest.raises(RuntimeError):
+        bn.to_expanded_network()
+
+
+def test_canonicalize_functions():
+    bn = BooleanNetwork.from_aeon("""
+    a -> c
+    b -? c
+    c -> c
+    c -> a
+    $a: c
+    $c: (a & b) | (!a & c) | (b & c)
+    """)
+    ctx = SymbolicContext(bn)
+    f_c = ctx.mk_update_function(bn.get_update_function("c"))
+
+    minimal = bn.canonicalize_functions()
+    assert str(minimal.get_update_function("a")) == "c"
+    assert str(minimal.get_update_function("c")) == "(!a & c) | (a & b)"
+
+    for mode in ["minimal", "blake", "dnf"]:
+        canonical = bn.canonicalize_functions(mode)
+        canonical_fn = UpdateFunction(bn, canonical.get_update_function("c"))
+        assert ctx.mk_update_function(canonical_fn) == f_c
+        # Canonicalization is idempotent.
+        assert canonical.canonicalize_functions(mode) == canonical
+
+    blake = bn.canonicalize_functions("blake")
+    assert len(blake.get_update_function("c").support_variables()) == 3
+    assert "b & c" in str(blake.get_update_function("c"))
+
+    # Uninterpreted functions are treated as opaque propositions.
+    bn = BooleanNetwork(["a", "b"], ["a -> b", "b -> b"], [("f", 1)], [None, "f(a | (a & b)) | (f(a | (a & b)) & b)"])
+    assert str(bn.canonicalize_functions().get_update_function("b")) == "f(a)"
+    assert bn.canonicalize_functions(skip_parametrized=True) == bn
+    assert bn.canonicalize_functions().get_update_function("a") is None
+
+    # The result does not depend on the order of operands with uninterpreted functions.
+    for fn_1, fn_2 in [("f(a) | g(b)", "g(b) | f(a)"), ("f(b) & !g(a | b)", "!g(b | a) & f(b)")]:
+        bn_1 = BooleanNetwork(["a", "b", "c"], ["a -? c", "b -? c"], [("g", 1), ("f", 1)], [None, None, fn_1])
+        bn_2 = BooleanNetwork(["a", "b", "c"], ["a -? c", "b -? c"], [("g", 1), ("f", 1)], [None, None, fn_2])
+        for mode in ["minimal", "blake", "dnf"]:
+            assert bn_1.canonicalize_functions(mode) == bn_2.canonicalize_functions(mode)
+
+    with pytest.raises(TypeError):
+        bn.canonicalize_functions("unknown")
+
+
+def test_symbolic_set_bytes():
+    bn = BooleanNetwork(
+        regulations=["a -> b", "b -| c", "a -? a"],
+        functions=["a", "a", "!b"]
+    )
+    graph = AsynchronousGraph(bn)
+    ctx = graph.symbolic_context()
+    space_ctx = SymbolicSpaceContext(bn)
+
+    # Fingerprints are deterministic and distinguish different encodings.
+    assert ctx.fingerprint() == SymbolicContext(bn).fingerprint()
+    assert ctx.fingerprint() != space_ctx.fingerprint()
+
+    vertices = graph.mk_subspace_vertices({"a": 1})
+    assert VertexSet.from_bytes(ctx, vertices.to_bytes()) == vertices
+    colors = graph.mk_unit_colors()
+    assert ColorSet.from_bytes(ctx, colors.to_bytes()) == colors
+    colored_vertices = graph.mk_subspace({"b": 0})
+    assert ColoredVertexSet.from_bytes(ctx, colored_vertices.to_bytes()) == colored_vertices
+    spaces = space_ctx.mk_unit_spaces()
+    assert SpaceSet.from_bytes(space_ctx, spaces.to_bytes()) == spaces
+    colored_spaces = space_ctx.mk_unit_colored_spaces()
+    assert ColoredSpaceSet.from_bytes(space_ctx, colored_spaces.to_bytes()) == colored_spaces
+
+    # Loading into a fresh (but equivalent) context works.
+    loaded = VertexSet.from_bytes(SymbolicContext(bn), vertices.to_bytes())
+    assert loaded.cardinality() == vertices.cardinality()
+
+    # Incompatible contexts, wrong set types and corrupted data are rejected.
+    other = SymbolicContext(BooleanNetwork(["x", "y", "z"], [], [], ["true", "true", "true"]))
+    with pytest.raises(RuntimeError):
+        VertexSet.from_bytes(other, vertices.to_bytes())
+    with pytest.raises(RuntimeError):
+        ColorSet.from_bytes(ctx, vertices.to_bytes())
+    with pytest.raises(RuntimeError):
+        VertexSet.from_bytes(ctx, b"invalid")
+
+    # Pickling uses the same context-checked format.
+    for item in [vertices, colors, colored_vertices, spaces, colored_spaces]:
+        cls, (item_ctx, data) = item.__reduce__()
+        assert data == item.to_bytes()
+        assert item_ctx.fingerprint() == item.__ctx__().fingerprint()
+        assert pickle.loads(pickle.dumps(item)) == item
+    cls, (_, data) = vertices.__reduce__()
+    with pytest.raises(RuntimeError):
+        cls(other, data)
+
+
+def test_variable_reordering():
+    bn = BooleanNetwork.from_file("./tests/model-myeloid-3-unknown.aeon")
+    order = bn.suggest_variable_order()
+    assert sorted(order) == bn.variables()
+
+    reordered = bn.reorder_variables(order)
+    assert reordered.variable_names() == [bn.get_variable_name(v) for v in order]
+    assert reordered.regulation_count() == bn.regulation_count()
+    assert reordered.implicit_parameter_count() == bn.implicit_parameter_count()
+    for name in bn.variable_names():
+        assert str(reordered.get_update_function(name)) == str(bn.get_update_function(name))
+    assert bn.to_graph().reorder_variables(order).variable_names() == reordered.variable_names()
+
+    with pytest.raises(RuntimeError):
+        bn.reorder_variables(order[1:])
+    with pytest.raises(RuntimeError):
+        bn.reorder_variables([order[0]] + order[:-1])
+
+    # Results computed with the new ordering can be transferred back to the original graph.
+    graph = AsynchronousGraph(bn)
+    graph_r = AsynchronousGraph(reordered)
+    fixed_points = FixedPoints.symbolic(graph)
+    fixed_points_r = FixedPoints.symbolic(graph_r)
+    assert graph.transfer_from(fixed_points_r, graph_r) == fixed_points
+    assert graph_r.transfer_from(fixed_points, graph) == fixed_points_r
+    assert graph.transfer_from(fixed_points_r.colors(), graph_r) == fixed_points.colors()
+    assert graph.transfer_from(fixed_points_r.vertices(), graph_r) == fixed_points.vertices()
+
+    ctx = graph.symbolic_context()
+    ctx_r = graph_r.symbolic_context()
+    assert ctx.transfer_from(fixed_points_r.to_bdd(), ctx_r) == fixed_points.to_bdd()
+
+    # Explicit parameters are also supported.
+    bn = BooleanNetwork.from_aeon("""
+        a -> c
+        b -| c
+        c -> a
+        $a: c
+        $b: true
+        $c: f(a, b)
+    """)
+    reordered = bn.reorder_variables(["c", "b", "a"])
+    graph = AsynchronousGraph(bn)
+    graph_r = AsynchronousGraph(reordered)
+    fixed_points = FixedPoints.symbolic(graph)
+    fixed_points_r = FixedPoints.symbolic(graph_r)
+    assert graph.transfer_from(fixed_points_r, graph_r) == fixed_points
+
+
+def test_color_probability():
+    from fractions import Fraction
+
+    bn = BooleanNetwork.from_file("./tests/model-myeloid-3-unknown.aeon")
+    graph = AsynchronousGraph(bn)
+    ctx = graph.symbolic_context()
+    unit = graph.mk_unit_colors()
+
+    parameter_count = len(ctx.functions_bdd_variables_list())
+    expected = Fraction(unit.cardinality(), 2 ** parameter_count)
+    assert unit.probability(exact=True) == expected
+    assert abs(unit.probability() - float(expected)) < 1e-9
+    assert graph.mk_empty_colors().probability() == 0.0
+
+    # Fixing the prior of one table entry to `1` excludes all colors where it is `0`.
+    var = ctx.functions_bdd_variables_list()[0]
+    positive = ColorSet(ctx, unit.to_bdd().r_select({var: True}))
+    assert unit.probability({var: 1.0}, exact=True) == positive.probability({var: 1.0}, exact=True)
+
+
+def test_best_color():
+    bn = BooleanNetwork.from_file("./tests/model-myeloid-3-unknown.aeon")
+    graph = AsynchronousGraph(bn)
+    ctx = graph.symbolic_context()
+    unit = graph.mk_unit_colors()
+    parameters = ctx.functions_bdd_variables_list()
+
+    assert graph.mk_empty_colors().best_color({}) is None
+
+    best = unit.best_color({var: 1 for var in parameters})
+    assert best is not None
+    assert best.to_symbolic().is_subset(unit)
+    most_positive = unit.to_bdd().valuation_most_positive()
+    assert sum(best.to_valuation().values()) == sum(most_positive[var] for var in parameters)
+
+    worst = unit.best_color({var: -1 for var in parameters})
+    assert worst is not None
+    most_negative = unit.to_bdd().valuation_most_negative()
+    assert sum(worst.to_valuation().values()) == sum(most_negative[var] for var in parameters)
+
+
+def test_set_projection():
+    bn = BooleanNetwork.from_file("./tests/model-myeloid-3-unknown.aeon")
+    graph = AsynchronousGraph(bn)
+    names = bn.variable_names()
+    x = graph.mk_subspace_vertices({names[0]: True, names[1]: False})
+
+    assert x.project_out([names[1]]) == graph.mk_subspace_vertices({names[0]: True})
+    assert x.project_out([names[1]], universal=True).is_empty()
+    assert x.project_out([]) == x
+    assert x.project_out(names) == graph.mk_unit_vertices()
+    assert x.restrict_to([names[0]]) == graph.mk_subspace_vertices({names[0]: True})
+    assert x.restrict_to(names) == x
+    assert x.restrict_to([bn.find_variable(names[0])]) == x.project_out(names[1:])
+
+    fixed_points = FixedPoints.symbolic(graph)
+    projected = fixed_points.project_out(names[1:])
+    assert fixed_points.is_subset(projected)
+    assert projected.colors() == fixed_points.colors()
+    assert projected.vertices() == fixed_points.vertices().project_out(names[1:])
+    assert projected == fixed_points.restrict_to([names[0]])
+    # Universal projection only keeps pairs that are independent of the eliminated variables.
+    universal = fixed_points.project_out(names[1:], universal=True)
+    assert universal.is_subset(fixed_points)
+    assert fixed_points.project_out(names, universal=True).is_empty()
+    cylinder = graph.mk_unit_colored_vertices().intersect_vertices(graph.mk_subspace_vertices({names[0]: True}))
+    assert cylinder.project_out(names[1:], universal=True) == cylinder
+
+    # Extra symbolic variables of eliminated network variables are eliminated as well.
+    bn = BooleanNetwork(["a", "b"], ["a -> b", "b -> a"], [], ["b", "a"])
+    graph = AsynchronousGraph.mk_for_model_checking(bn, 1)
+    ctx = graph.symbolic_context()
+    a_and_extra_b = ctx.mk_network_variable("a") & ctx.mk_extra_bdd_variable("b", 0)
+    x = ColoredVertexSet(ctx, a_and_extra_b)
+    assert x.project_out(["b"]) == graph.mk_subspace({"a": True})
+    assert x.restrict_to(["a"]) == graph.mk_subspace({"a": True})
+    assert x.project_out(["a"]).to_bdd() == ctx.mk_extra_bdd_variable("b", 0)
+    assert x.vertices().project_out(["b"]) == graph.mk_subspace_vertices({"a": True})